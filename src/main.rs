@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
 #![allow(unused_assignments)]
 
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 use fake::{
-    faker::{address::en::*, company::en::*, internet::en::*, name::raw::*, phone_number::en::*},
+    faker::{
+        address::raw::*, company::raw::*, internet::en::*, name::raw::*, phone_number::raw::*,
+    },
     locales::*,
     Fake,
     Faker,
@@ -11,15 +13,916 @@ use fake::{
     // https://docs.rs/fake/latest/fake/faker/index.html
 };
 
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use rand::{distributions::WeightedIndex, rngs::StdRng, seq::SliceRandom, Rng, RngCore, SeedableRng};
+use rand_distr::{Exp, LogNormal, Normal, Zipf};
 use std::{
-    collections::HashMap,
-    fs::{self, OpenOptions},
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    hash::{BuildHasher, RandomState},
     io,
-    io::{stdout, Write},
+    io::{stdout, BufWriter, Write},
+    sync::OnceLock,
+    thread,
 };
 
-fn check_compound_attribute(index: usize, attribute_checker: &Vec<String>) -> bool {
+// Options given to a column after its definition (i.e 'email EMAIL FROM(username, company)')
+#[derive(Clone, Debug, Default)]
+struct ColumnOptions {
+    // Columns of the same row the value is derived from
+    from: Vec<String>,
+    // Locale given with the type (i.e 'name NAME@fr_FR'). None uses the locale of GenerationSettings
+    locale: Option<Locale>,
+    // Chance of the column being NULL in a row (i.e 'phone PHONE NULL(0.3)'). None is never NULL
+    // Err holds the reason it could not be read, reported when the table is added
+    null_probability: Option<Result<f64, String>>,
+    // Bounds of a numeric value from the table's CHECK constraints, true when the bound is inclusive
+    minimum: Option<(f64, bool)>,
+    maximum: Option<(f64, bool)>,
+    // Values the column is limited to by a CHECK constraint (i.e 'CHECK (status IN ('a', 'b'))')
+    allowed_values: Vec<String>,
+    // Distribution of a numeric or date column (i.e 'amount MONEY(5) DIST(lognormal, 3, 1)')
+    // Err holds the reason it could not be read, reported when the table is added
+    distribution: Option<Result<ValueDistribution, String>>,
+    // How the rows of a foreign key are spread over the parents (i.e 'FK userID INTEGER profile(userID) FANOUT(zipf, 1.2)')
+    fanout: Option<Result<FanOut, String>>,
+}
+
+// Distribution given with DIST(...), see parse_distribution. Dates are drawn as days since 1970-01-01
+#[derive(Clone, Debug, PartialEq)]
+enum ValueDistribution {
+    // Mean and standard deviation
+    Normal(f64, f64),
+    // Mean and standard deviation of the value's logarithm, then the start added to the value
+    LogNormal(f64, f64, f64),
+    // Rate (lambda), then the start added to the value
+    Exponential(f64, f64),
+    // Number of values and exponent, then the start (the most common value)
+    Zipf(u64, f64, f64),
+    // Buckets of (low, high, weight), a value is picked evenly inside of a weighted bucket
+    Histogram(Vec<(f64, f64, f64)>),
+}
+
+// Spread of a foreign key's rows over the parent values, given with FANOUT(...), see parse_fanout
+#[derive(Clone, Debug, PartialEq)]
+struct FanOut {
+    shape: FanOutShape,
+    // Children every parent gets at least
+    minimum: u64,
+    // Children a parent gets at most. None is unlimited
+    maximum: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FanOutShape {
+    // Every parent is as likely
+    Uniform,
+    // Parents are ranked at random, the parent of rank r is picked with weight 1 / r^exponent
+    Zipf(f64),
+    // A fraction of the parents gets a share of the children (i.e 0.1, 0.8 -> 10% of users own 80% of orders)
+    Hot(f64, f64),
+}
+
+// Picks the parent of each row for a FANOUT(...) foreign key and counts the children of every parent
+struct FanOutPicker {
+    fanout: FanOut,
+    parents: Vec<String>,
+    parent_indexes: HashMap<String, usize>,
+    weights: Vec<f64>,
+    children: Vec<u64>,
+    // Parents below the minimum, they are picked first. below_positions[parent] is the parent's place in it
+    below_minimum: Vec<usize>,
+    below_positions: Vec<usize>,
+    // Picks the parents that have a weight and are below the maximum, open_parents counts them
+    index: Option<WeightedIndex<f64>>,
+    open_parents: usize,
+}
+
+impl FanOutPicker {
+    fn new(fanout: FanOut, parents: Vec<String>) -> FanOutPicker {
+        /*
+            * Creates the picker of a FANOUT(...) foreign key, weighting every parent by the fan-out's shape
+
+            :parameters:
+                - `fanout`: The fan-out of the foreign key
+                - `parents`: The values of the referenced attribute
+
+            :returns:
+                - `FanOutPicker`: The picker, with no children counted yet
+        */
+        let count = parents.len();
+        let mut ranks: Vec<usize> = (0..count).collect();
+        ranks.shuffle(&mut row_rng());
+
+        let weights: Vec<f64> = ranks
+            .iter()
+            .map(|rank| match fanout.shape {
+                FanOutShape::Uniform => 1.0,
+                FanOutShape::Zipf(exponent) => 1.0 / ((rank + 1) as f64).powf(exponent),
+                FanOutShape::Hot(fraction, share) => {
+                    let hot = ((fraction * count as f64).ceil() as usize).clamp(1, count);
+                    match (*rank < hot, count > hot) {
+                        (true, _) => share / hot as f64,
+                        (false, true) => (1.0 - share) / (count - hot) as f64,
+                        (false, false) => 0.0,
+                    }
+                }
+            })
+            .collect();
+
+        let below_minimum: Vec<usize> = match fanout.minimum {
+            0 => Vec::new(),
+            _ => (0..count).collect(),
+        };
+        FanOutPicker {
+            parent_indexes: parents.iter().enumerate().map(|(index, parent)| (parent.clone(), index)).collect(),
+            index: WeightedIndex::new(&weights).ok(),
+            open_parents: weights.iter().filter(|weight| **weight > 0.0).count(),
+            children: vec![0; count],
+            below_positions: (0..count).collect(),
+            below_minimum,
+            weights,
+            parents,
+            fanout,
+        }
+    }
+
+    fn pick(&self) -> Option<String> {
+        /*
+            * Picks a parent for the next row. Parents below the minimum go first, then the weighted parents below the maximum
+
+            :returns:
+                - `Option<String>`: The parent value, None if every parent is at its maximum
+        */
+        let mut rng = row_rng();
+        if let Some(parent) = self.below_minimum.choose(&mut rng) {
+            return Some(self.parents[*parent].clone());
+        }
+        match (self.open_parents, &self.index) {
+            (0, _) | (_, None) => None,
+            (_, Some(index)) => Some(self.parents[rng.sample(index)].clone()),
+        }
+    }
+
+    fn add_child(&mut self, parent: &str) {
+        /*
+            * Counts a row given to the parent
+
+            :parameters:
+                - `parent`: The parent value of the row
+        */
+        let Some(&parent) = self.parent_indexes.get(parent) else {
+            return;
+        };
+        self.children[parent] += 1;
+
+        if self.children[parent] == self.fanout.minimum {
+            let position = self.below_positions[parent];
+            self.below_minimum.swap_remove(position);
+            if let Some(moved) = self.below_minimum.get(position) {
+                self.below_positions[*moved] = position;
+            }
+        }
+        if Some(self.children[parent]) == self.fanout.maximum && self.weights[parent] > 0.0 {
+            self.open_parents -= 1;
+            // The last open parent keeps its weight, pick checks open_parents first
+            if self.open_parents > 0 {
+                if let Some(index) = self.index.as_mut() {
+                    index.update_weights(&[(parent, &0.0)]).unwrap();
+                }
+            }
+        }
+    }
+
+    fn remove_child(&mut self, parent: &str) {
+        /*
+            * Takes back a row given to the parent, used when the row fails a CHECK constraint
+
+            :parameters:
+                - `parent`: The parent value of the row
+        */
+        let Some(&parent) = self.parent_indexes.get(parent) else {
+            return;
+        };
+        if self.children[parent] == 0 {
+            return;
+        }
+
+        if Some(self.children[parent]) == self.fanout.maximum && self.weights[parent] > 0.0 {
+            self.open_parents += 1;
+            match self.open_parents {
+                // Rebuilt when no parent was open, the last one to close kept its weight
+                1 => {
+                    let open_weights = self.weights.iter().enumerate().map(|(index, weight)| {
+                        match index == parent || Some(self.children[index]) != self.fanout.maximum {
+                            true => *weight,
+                            false => 0.0,
+                        }
+                    });
+                    self.index = WeightedIndex::new(open_weights).ok();
+                }
+                _ => {
+                    if let Some(index) = self.index.as_mut() {
+                        index.update_weights(&[(parent, &self.weights[parent])]).unwrap();
+                    }
+                }
+            }
+        }
+
+        self.children[parent] -= 1;
+        if self.children[parent] + 1 == self.fanout.minimum {
+            self.below_positions[parent] = self.below_minimum.len();
+            self.below_minimum.push(parent);
+        }
+    }
+}
+
+// Values of a key attribute, in the order they were generated so parents can be picked by index
+// and hashed so checking a new value is unique does not go through all of them.
+// The values are kept back to back in one buffer, millions of small strings would scatter the heap
+#[derive(Clone, Debug, Default)]
+struct ValueStore {
+    text: String,
+    // End of every value in text
+    ends: Vec<usize>,
+    // Last value with each hash, earlier values with the same hash are chained through previous
+    last_by_hash: HashMap<u64, usize>,
+    previous: Vec<Option<usize>>,
+    hasher: RandomState,
+}
+
+impl ValueStore {
+    fn get(&self, position: usize) -> &str {
+        let start = match position {
+            0 => 0,
+            _ => self.ends[position - 1],
+        };
+        &self.text[start..self.ends[position]]
+    }
+
+    fn contains(&self, value: &str) -> bool {
+        let mut position = self.last_by_hash.get(&self.hasher.hash_one(value)).copied();
+        while let Some(current) = position {
+            if self.get(current) == value {
+                return true;
+            }
+            position = self.previous[current];
+        }
+        false
+    }
+
+    fn push(&mut self, value: &str) {
+        if self.contains(value) {
+            return;
+        }
+        let hash = self.hasher.hash_one(value);
+        self.previous.push(self.last_by_hash.insert(hash, self.ends.len()));
+        self.text.push_str(value);
+        self.ends.push(self.text.len());
+    }
+
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    fn to_vec(&self) -> Vec<String> {
+        (0..self.len()).map(|position| self.get(position).to_string()).collect()
+    }
+
+    fn truncate(&mut self, length: usize) {
+        // Takes back the values added after the first length, see the CHECK retries of generate_rows
+        while self.len() > length {
+            let position = self.len() - 1;
+            let hash = self.hasher.hash_one(self.get(position));
+            match self.previous.pop().flatten() {
+                Some(previous) => self.last_by_hash.insert(hash, previous),
+                None => self.last_by_hash.remove(&hash),
+            };
+            self.ends.pop();
+            self.text.truncate(self.ends.last().copied().unwrap_or(0));
+        }
+    }
+}
+
+// How the keys of a table with several PK attributes relate, set with PAIRS (...) among its attributes
+// By default a key read backwards is the same key (friends) and a key can not repeat a value
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct PairRules {
+    // (1, 2) and (2, 1) are different keys (follows, message(sender, recipient))
+    directed: bool,
+    // Keys may repeat a value, i.e (1, 1)
+    self_pairs: bool,
+}
+
+// Picks the keys of a table with several PK attributes (a composite key), see generate_mock_data
+// The PairRules of the table decide if a key read backwards is the same key and if a key can repeat a value
+struct CompositeKeyPicker {
+    // Values of each PK attribute, None for attributes that are not redrawn (generated or given a FANOUT(...))
+    pools: Vec<Option<Vec<String>>>,
+    rules: PairRules,
+    // Keys of the rows written so far. Undirected keys are read forwards or backwards, whichever is smaller
+    used: HashSet<Vec<String>>,
+    // Every unused key, enumerated once random keys keep colliding. Only kept when every attribute has a pool
+    remaining: Option<Vec<Vec<String>>>,
+    // Index in remaining of the last key picked from it
+    picked_index: Option<usize>,
+}
+
+impl CompositeKeyPicker {
+    fn new(pools: Vec<Option<Vec<String>>>, rules: PairRules) -> Self {
+        CompositeKeyPicker {
+            pools,
+            rules,
+            used: HashSet::new(),
+            remaining: None,
+            picked_index: None,
+        }
+    }
+
+    fn canonical(&self, key: &[String]) -> Vec<String> {
+        let reversed: Vec<String> = key.iter().rev().cloned().collect();
+        match !self.rules.directed && reversed.as_slice() < key {
+            true => reversed,
+            false => key.to_vec(),
+        }
+    }
+
+    fn is_free(&self, key: &[String]) -> bool {
+        let repeats_value = key.iter().enumerate().any(|(index, value)| key[..index].contains(value));
+        (self.rules.self_pairs || !repeats_value) && !self.used.contains(&self.canonical(key))
+    }
+
+    fn pick(&mut self, candidate: Vec<String>) -> Option<Vec<String>> {
+        /*
+            * Gives an unused key, the candidate itself when it is free
+            * Redraws the attributes with a pool up to MAX_KEY_RETRIES times, then enumerates the unused keys
+            * None when no key is left for the candidate (i.e every pair of the referenced rows is used)
+        */
+        if let Some(remaining) = &self.remaining {
+            if remaining.is_empty() {
+                return None;
+            }
+            let index = row_rng().gen_range(0..remaining.len());
+            self.picked_index = Some(index);
+            return Some(remaining[index].clone());
+        }
+        if self.is_free(&candidate) {
+            return Some(candidate);
+        }
+
+        let draw = |pool: &Option<Vec<String>>, value: &String| match pool {
+            Some(pool) => pool.choose(&mut row_rng()).unwrap_or(value).clone(),
+            None => value.clone(),
+        };
+        for _ in 0..MAX_KEY_RETRIES {
+            let key: Vec<String> = self.pools.iter().zip(&candidate).map(|(pool, value)| draw(pool, value)).collect();
+            if self.is_free(&key) {
+                return Some(key);
+            }
+        }
+
+        // The keys are nearly used up, go through all of them
+        let space = self.pools.iter().try_fold(1_u64, |space, pool| {
+            space.checked_mul(pool.as_ref().map_or(1, |pool| pool.len() as u64))
+        });
+        if space.is_none_or(|space| space > MAX_ENUMERATED_KEYS) {
+            return None;
+        }
+        let mut keys: Vec<Vec<String>> = vec![Vec::new()];
+        for (pool, value) in self.pools.iter().zip(&candidate) {
+            let values = pool.clone().unwrap_or_else(|| vec![value.clone()]);
+            keys = keys
+                .into_iter()
+                .flat_map(|key| {
+                    values.iter().map(move |value| {
+                        let mut key = key.clone();
+                        key.push(value.clone());
+                        key
+                    })
+                })
+                .collect();
+        }
+        let mut seen: HashSet<Vec<String>> = HashSet::new();
+        keys.retain(|key| self.is_free(key) && seen.insert(self.canonical(key)));
+
+        if self.pools.iter().all(|pool| pool.is_some()) {
+            self.remaining = Some(keys);
+            return self.pick(candidate);
+        }
+        keys.choose(&mut row_rng()).cloned()
+    }
+
+    fn add(&mut self, key: &[String]) {
+        // Marks the key of a written row as used
+        if let (Some(remaining), Some(index)) = (self.remaining.as_mut(), self.picked_index.take()) {
+            remaining.swap_remove(index);
+        }
+        self.used.insert(self.canonical(key));
+    }
+}
+
+// Number of rows given to add, see parse_row_count
+#[derive(Clone, Debug, PartialEq)]
+enum RowCount {
+    Absolute(u64),
+    // Rows per row of the parent table (i.e 3x). The parent is the first referenced table unless named (3x:profile)
+    PerParent(f64, Option<String>),
+    // Between low and high rows for every row of the parent table (i.e 0-20x)
+    PerParentRange(u64, u64, Option<String>),
+}
+
+// Foreign key over several attributes that picks whole rows of the parent, see get_composite_reference
+// Example: FK (order_id, line_no) order_line(order_id, line_no)
+#[derive(Clone, Debug, PartialEq)]
+struct CompositeReference {
+    key_definition: String,
+    columns: Vec<String>,
+    referenced_table: String,
+    referenced_columns: Vec<String>,
+}
+
+impl CompositeReference {
+    fn is_unique(&self) -> bool {
+        // PK/FK and AK/FK use every parent row at most once
+        self.key_definition.starts_with("PK") || self.key_definition.starts_with("AK")
+    }
+
+    fn parent_key(&self) -> String {
+        // Key of the parent rows in generate_mock_data (i.e order_line(order_id, line_no))
+        format!("{}({})", self.referenced_table, self.referenced_columns.join(", "))
+    }
+}
+
+// Destination of the generated INSERT statements, opened once per run. Sinks may buffer, so finish is called after the last row
+trait StatementSink {
+    fn write_statement(&mut self, statement: &str) -> io::Result<()>;
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+// Sink writing the statements one per line to a file, stdout or any other writer through a buffer
+struct WriterSink<W: Write> {
+    writer: BufWriter<W>,
+}
+
+impl<W: Write> WriterSink<W> {
+    fn new(writer: W) -> WriterSink<W> {
+        WriterSink { writer: BufWriter::with_capacity(WRITE_BUFFER_SIZE, writer) }
+    }
+}
+
+impl<W: Write> StatementSink for WriterSink<W> {
+    fn write_statement(&mut self, statement: &str) -> io::Result<()> {
+        writeln!(self.writer, "{}", statement)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+// Why generating the mock data stopped. The rows written before it stay in the file and the tables are kept
+#[derive(Debug)]
+enum GenerationError {
+    // The sink could not take a statement (i.e a full disk)
+    Write(io::Error),
+    // A table could not get a row that passes its constraints or keys
+    Rows(String),
+}
+
+impl From<io::Error> for GenerationError {
+    fn from(error: io::Error) -> GenerationError {
+        GenerationError::Write(error)
+    }
+}
+
+impl std::fmt::Display for GenerationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GenerationError::Write(error) => write!(f, "{}", error),
+            GenerationError::Rows(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+// Bytes written to a sink at once, and rows between two updates of the progress line
+const WRITE_BUFFER_SIZE: usize = 1 << 20;
+const PROGRESS_INTERVAL: u64 = 1000;
+
+// Rows of a table generated together, by one thread in parallel mode. The seed gives the same rows for any number of threads
+const CHUNK_ROWS: u64 = 10_000;
+// Streams of seed_row_rng besides the chunks: shuffling the parents of a table, and generating rows again when merging a chunk
+const SETUP_STREAM: u64 = u64::MAX;
+const MERGE_STREAM: u64 = 1 << 62;
+
+// What the rows of a table are generated from, shared by every chunk of its rows. See generate_rows
+struct TableContext<'a> {
+    table_name: String,
+    table_attributes: Vec<String>,
+    table_checks: Vec<(String, Expression)>,
+    // Parsed EXPR(...) types of the columns, compound attributes included, by type
+    table_expressions: HashMap<String, Expression>,
+    table_uniques: Vec<Vec<String>>,
+    check_ranges: HashMap<String, ColumnOptions>,
+    primary_keys: Vec<String>,
+    pairwise_table: bool,
+    pair_rules: PairRules,
+    key_names: Vec<String>,
+    referenced_keys: Vec<(String, Vec<String>)>,
+    key_dictionary: &'a HashMap<String, Vec<String>>,
+    settings: &'a GenerationSettings,
+    // Moment the run started, see row_now
+    now: NaiveDateTime,
+    // Key values and composite reference rows of the tables generated before
+    parent_values: &'a HashMap<(String, String), ValueStore>,
+    parent_rows: &'a HashMap<String, Vec<Vec<String>>>,
+}
+
+// What the rows of a table generated so far took: unique values, composite keys and parents
+struct TableState {
+    unique_values: HashMap<(String, String), ValueStore>,
+    unique_tuples: Vec<HashSet<Vec<String>>>,
+    fanout_pickers: HashMap<String, FanOutPicker>,
+    unused_parents: HashMap<String, Vec<String>>,
+    unused_parent_rows: HashMap<String, Vec<Vec<String>>>,
+    key_picker: Option<CompositeKeyPicker>,
+}
+
+impl TableState {
+    fn split_chunk(&mut self, rows: u64) -> TableState {
+        // State of a chunk generated apart, with no values taken yet and its share of the unused parents
+        let rows = rows as usize;
+        TableState {
+            unique_values: HashMap::new(),
+            unique_tuples: vec![HashSet::new(); self.unique_tuples.len()],
+            fanout_pickers: HashMap::new(),
+            unused_parents: self
+                .unused_parents
+                .iter_mut()
+                .map(|(name, parents)| (name.clone(), parents.split_off(parents.len().saturating_sub(rows))))
+                .collect(),
+            unused_parent_rows: self
+                .unused_parent_rows
+                .iter_mut()
+                .map(|(columns, rows_left)| (columns.clone(), rows_left.split_off(rows_left.len().saturating_sub(rows))))
+                .collect(),
+            key_picker: None,
+        }
+    }
+
+    fn take_row(&mut self, row: &GeneratedRow) -> bool {
+        /*
+            * Takes the unique values of a row generated in a chunk
+            * A row with a value taken before takes nothing and gives its parents back, so it can be generated again
+
+            :parameters:
+                - `row`: The row, generated from the state of its chunk
+
+            :returns:
+                - `bool`: True if the row was taken
+        */
+        let taken_before = row
+            .key_values
+            .iter()
+            .any(|(attribute_key, value)| self.unique_values.get(attribute_key).is_some_and(|values| values.contains(value)))
+            || row.unique_tuples.iter().any(|(index, values)| self.unique_tuples[*index].contains(values));
+        if taken_before {
+            for (attribute_name, parent) in &row.picked_parents {
+                self.unused_parents.get_mut(attribute_name).unwrap().push(parent.clone());
+            }
+            for (columns, parent_row) in &row.picked_parent_rows {
+                self.unused_parent_rows.get_mut(columns).unwrap().push(parent_row.clone());
+            }
+            return false;
+        }
+        for (attribute_key, value) in &row.key_values {
+            self.unique_values.entry(attribute_key.clone()).or_default().push(value);
+        }
+        for (index, values) in &row.unique_tuples {
+            self.unique_tuples[*index].insert(values.clone());
+        }
+        true
+    }
+}
+
+// A generated row and what it took from the state it was generated from
+struct GeneratedRow {
+    // Values of the row for its insert statement, see create_insert_values
+    values: String,
+    // Values added to the unique checkers, and the values of the UNIQUE (...) constraints without a NULL by position
+    key_values: Vec<((String, String), String)>,
+    unique_tuples: Vec<(usize, Vec<String>)>,
+    picked_parents: Vec<(String, String)>,
+    picked_parent_rows: Vec<(String, Vec<String>)>,
+    // Values of the row referenced by composite references of other tables, by parent key
+    referenced_rows: Vec<(String, Vec<String>)>,
+}
+
+// Types DIST(...) can be given to
+const DISTRIBUTED_TYPES: [&str; 5] = ["INTEGER", "DECIMAL", "MONEY", "DATE", "TIMESTAMP"];
+
+// Settings set from the prompt that apply to every table being generated
+#[derive(Clone, Debug, Default)]
+struct GenerationSettings {
+    // Default locale of the localized types (names, addresses, phone numbers, companies)
+    locale: Locale,
+    // Multiplier of the row counts added as a number (not 3x), set with 'scale' or --scale. None is 1
+    scale: Option<f64>,
+    // Seed of every random value, set with 'seed' or --seed. The same seed and tables give the same file
+    seed: Option<u64>,
+    // Threads generating chunks of rows at once, set with 'threads' or --threads. None is 1
+    threads: Option<usize>,
+    // Rows written in each insert statement, set with 'batch' or --batch. None is 1
+    batch: Option<usize>,
+    // Database the table and column names are quoted for, set with 'dialect' or --dialect
+    dialect: Dialect,
+    // Inserts without a column list, the values follow the order of the attributes. Set with 'columns off' or --columns off
+    positional: bool,
+}
+
+thread_local! {
+    // Generator behind row_rng, seeded again for every chunk of rows, see seed_row_rng
+    static ROW_RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+    // Moment the run started, set for every chunk of rows, see row_now
+    static ROW_NOW: Cell<Option<NaiveDateTime>> = const { Cell::new(None) };
+}
+
+// Random number generator of the generated values. Used in place of thread_rng so a seed gives the same rows on any thread
+#[derive(Clone, Copy, Debug, Default)]
+struct RowRng;
+
+impl RngCore for RowRng {
+    fn next_u32(&mut self) -> u32 {
+        ROW_RNG.with(|rng| rng.borrow_mut().next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ROW_RNG.with(|rng| rng.borrow_mut().next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        ROW_RNG.with(|rng| rng.borrow_mut().fill_bytes(dest))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        ROW_RNG.with(|rng| rng.borrow_mut().try_fill_bytes(dest))
+    }
+}
+
+// The Faker library draws from the newer rand it depends on
+impl fake::rand::RngCore for RowRng {
+    fn next_u32(&mut self) -> u32 {
+        RngCore::next_u32(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        RngCore::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        RngCore::fill_bytes(self, dest)
+    }
+}
+
+fn row_rng() -> RowRng {
+    RowRng
+}
+
+fn row_now() -> NaiveDateTime {
+    /*
+        * Gets the moment the dates relative to today are taken from (AGE, DATE FROM(age), EXPR TODAY / NOW)
+        * Every row of a run reads the same moment, whichever thread generates it and however long the run takes
+        * Outside of a run (i.e the tests of single values) the clock is read
+
+        :returns:
+            - `NaiveDateTime`: The moment, to the second
+    */
+    ROW_NOW
+        .with(|now| now.get())
+        .unwrap_or_else(|| chrono::Local::now().naive_local().with_nanosecond(0).unwrap())
+}
+
+fn seed_row_rng(seed: Option<u64>, table_index: u64, chunk: u64) {
+    /*
+        * Seeds the generator of the current thread for one chunk of rows of a table
+        * Each (seed, table, chunk) gets its own stream, so chunks give the same rows whichever thread generates them
+        * Without a seed the generator is seeded from the operating system
+
+        :parameters:
+            - `seed`: The seed set from the prompt, if any
+            - `table_index`: The position of the table in the tables being generated
+            - `chunk`: The chunk of rows of the table (see CHUNK_ROWS), or a stream of its own like SETUP_STREAM
+    */
+    let rng = match seed {
+        Some(seed) => {
+            let mut key = [0_u8; 32];
+            key[..8].copy_from_slice(&seed.to_le_bytes());
+            key[8..16].copy_from_slice(&table_index.to_le_bytes());
+            key[16..24].copy_from_slice(&chunk.to_le_bytes());
+            StdRng::from_seed(key)
+        }
+        None => StdRng::from_entropy(),
+    };
+    ROW_RNG.with(|row_rng| *row_rng.borrow_mut() = rng);
+}
+
+// Locales of the localized types. Each maps to a locale of the Faker library
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Locale {
+    #[default]
+    En,
+    FrFr,
+    DeDe,
+    ItIt,
+    PtPt,
+    PtBr,
+    JaJp,
+    ZhCn,
+    ZhTw,
+    ArSa,
+}
+
+impl Locale {
+    const ALL: [Locale; 10] = [
+        Locale::En,
+        Locale::FrFr,
+        Locale::DeDe,
+        Locale::ItIt,
+        Locale::PtPt,
+        Locale::PtBr,
+        Locale::JaJp,
+        Locale::ZhCn,
+        Locale::ZhTw,
+        Locale::ArSa,
+    ];
+
+    fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::FrFr => "fr_FR",
+            Locale::DeDe => "de_DE",
+            Locale::ItIt => "it_IT",
+            Locale::PtPt => "pt_PT",
+            Locale::PtBr => "pt_BR",
+            Locale::JaJp => "ja_JP",
+            Locale::ZhCn => "zh_CN",
+            Locale::ZhTw => "zh_TW",
+            Locale::ArSa => "ar_SA",
+        }
+    }
+
+    // Country of the locale as written in the country column of the geo dataset
+    fn country(&self) -> &'static str {
+        match self {
+            Locale::En => "United States",
+            Locale::FrFr => "France",
+            Locale::DeDe => "Deutschland",
+            Locale::ItIt => "Italia",
+            Locale::PtPt => "Portugal",
+            Locale::PtBr => "Brasil",
+            Locale::JaJp => "日本",
+            Locale::ZhCn => "中国",
+            Locale::ZhTw => "臺灣",
+            Locale::ArSa => "السعودية",
+        }
+    }
+
+    // Postal code formats of the locale, '#' is replaced by a digit
+    fn postal_formats(&self) -> &'static [&'static str] {
+        match self {
+            Locale::En => &["#####", "#####-####"],
+            Locale::FrFr | Locale::DeDe | Locale::ItIt | Locale::ArSa => &["#####"],
+            Locale::PtPt => &["####-###"],
+            Locale::PtBr => &["#####-###"],
+            Locale::JaJp => &["###-####"],
+            Locale::ZhCn => &["######"],
+            Locale::ZhTw => &["###"],
+        }
+    }
+}
+
+// Databases the insert statements can be written for. Each quotes identifiers its own way and may limit the rows of a statement
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Dialect {
+    #[default]
+    Standard,
+    Postgres,
+    MySql,
+    SqlServer,
+    Sqlite,
+}
+
+impl Dialect {
+    const ALL: [Dialect; 5] = [Dialect::Standard, Dialect::Postgres, Dialect::MySql, Dialect::SqlServer, Dialect::Sqlite];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Dialect::Standard => "standard",
+            Dialect::Postgres => "postgres",
+            Dialect::MySql => "mysql",
+            Dialect::SqlServer => "sqlserver",
+            Dialect::Sqlite => "sqlite",
+        }
+    }
+
+    // Writes a table or column name for the dialect. Only names that need it are quoted (see needs_quotes), so
+    // unquoted names keep matching tables created without quotes on databases that fold their case (userID -> userid)
+    fn quote(&self, identifier: &str) -> String {
+        if !needs_quotes(identifier) {
+            return identifier.to_string();
+        }
+        match self {
+            Dialect::Standard | Dialect::Postgres | Dialect::Sqlite => format!("\"{}\"", identifier.replace('"', "\"\"")),
+            Dialect::MySql => format!("`{}`", identifier.replace('`', "``")),
+            Dialect::SqlServer => format!("[{}]", identifier.replace(']', "]]")),
+        }
+    }
+
+    // Most rows one insert statement can hold, SQL Server refuses a VALUES list of more than 1000 rows
+    fn max_rows(&self) -> Option<usize> {
+        match self {
+            Dialect::SqlServer => Some(1000),
+            _ => None,
+        }
+    }
+}
+
+// Keywords that can not be used as a table or column name without quotes in most databases
+const RESERVED_WORDS: [&str; 34] = [
+    "ALL", "AND", "AS", "BY", "CHECK", "COLUMN", "CREATE", "DEFAULT", "DELETE", "DESC", "DISTINCT", "FROM",
+    "GROUP", "HAVING", "IN", "INDEX", "INSERT", "INTO", "IS", "JOIN", "KEY", "LIMIT", "NOT", "NULL", "ON", "OR",
+    "ORDER", "PRIMARY", "SELECT", "TABLE", "UNIQUE", "USER", "VALUES", "WHERE",
+];
+
+// Generates a String with a Faker of the address, company, name or phone_number modules in the given locale
+macro_rules! fake_localized {
+    ($faker:ident, $locale:expr) => {
+        match $locale {
+            Locale::En => $faker(EN).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::FrFr => $faker(FR_FR).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::DeDe => $faker(DE_DE).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::ItIt => $faker(IT_IT).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::PtPt => $faker(PT_PT).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::PtBr => $faker(PT_BR).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::JaJp => $faker(JA_JP).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::ZhCn => $faker(ZH_CN).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::ZhTw => $faker(ZH_TW).fake_with_rng::<String, _>(&mut row_rng()),
+            Locale::ArSa => $faker(AR_SA).fake_with_rng::<String, _>(&mut row_rng()),
+        }
+        .replace("'", "")
+    };
+}
+
+// Gets a list of the Faker library's data (i.e ADDRESS_STATE, NAME_TITLE) in the given locale
+macro_rules! locale_data {
+    ($list:ident, $locale:expr) => {
+        match $locale {
+            Locale::En => <EN as Data>::$list,
+            Locale::FrFr => <FR_FR as Data>::$list,
+            Locale::DeDe => <DE_DE as Data>::$list,
+            Locale::ItIt => <IT_IT as Data>::$list,
+            Locale::PtPt => <PT_PT as Data>::$list,
+            Locale::PtBr => <PT_BR as Data>::$list,
+            Locale::JaJp => <JA_JP as Data>::$list,
+            Locale::ZhCn => <ZH_CN as Data>::$list,
+            Locale::ZhTw => <ZH_TW as Data>::$list,
+            Locale::ArSa => <AR_SA as Data>::$list,
+        }
+    };
+}
+
+// Parsed EXPR(...) column, see parse_expression
+#[derive(Clone, Debug, PartialEq)]
+enum Expression {
+    Literal(ExpressionValue),
+    // A column generated earlier in the row
+    Column(String),
+    Unary(String, Box<Expression>),
+    Binary(String, Box<Expression>, Box<Expression>),
+    Function(String, Vec<Expression>),
+}
+
+// Value of an expression while it is evaluated. Columns are read from their generated text
+#[derive(Clone, Debug, PartialEq)]
+enum ExpressionValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Date(NaiveDate),
+    Timestamp(NaiveDateTime),
+}
+
+// Reference dataset used by the GEO_* types. Each record is a real city with a matching state, zip and coordinates
+const GEO_DATA: &str = include_str!("geo_data.csv");
+
+// Times a row failing a CHECK or UNIQUE constraint, or a key value that is already taken, is generated again before giving up
+const MAX_CONSTRAINT_RETRIES: u32 = 1000;
+
+// Random composite keys tried before enumerating the unused ones, and the most keys enumerated
+const MAX_KEY_RETRIES: u32 = 100;
+const MAX_ENUMERATED_KEYS: u64 = 10_000_000;
+
+fn check_compound_attribute(index: usize, attribute_checker: &[String]) -> bool {
     /*
         * Checks if the compound attribute is valid
         * Compound attributes are of the form: (attribute1, attribute2, attribute3, ...)
@@ -43,8 +946,12 @@ fn check_compound_attribute(index: usize, attribute_checker: &Vec<String>) -> bo
     //New compound_attributes Vec -> [FIRST_NAME VARCHAR(20), MIDDLE_INITIAL CHAR(1), LAST_NAME VARCHAR(20)]
     //Iterate through compound_attributes, trim each element and check if each attribute is valid
     for compound_attribute in compound_attributes {
-        let compound_attribute = compound_attribute.trim();
-        let compound_attribute = compound_attribute.split(" ").collect::<Vec<&str>>();
+        let mut compound_attribute: Vec<String> = compound_attribute
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .collect();
+        // Types like ONEOF('a', 'b') or DECIMAL(10, 2) contain whitespace
+        merge_parentheses(&mut compound_attribute);
         if compound_attribute.len() != 2 {
             println!("[!] Invalid Compound Attribute");
             return false;
@@ -61,7 +968,11 @@ fn check_data_type(attribute_type: &str) -> bool {
     /*
        * Checks if the data type is valid
        * Returns true if valid, false if invalid
-       * Custom data types made for this program: EMAIL, GROUP, PHONE, SSN, STATE, ZIP, STREET-ADDRESS, FULL-ADDRESS, NAME, PASSWORD, USERNAME
+       * Custom data types made for this program: EMAIL, GROUP, PHONE, SSN, STATE, ZIP, STREET_ADDRESS, FULL_ADDRESS, NAME, PASSWORD, USERNAME
+       * FIRST_NAME, MIDDLE_NAME, MIDDLE_INITIAL, LAST_NAME, PREFIX, SUFFIX, AGE, DOMAIN
+       * GEO_CITY, GEO_STATE, GEO_STATE_ABBR, GEO_ZIP, GEO_LAT, GEO_LONG, GEO_COUNTRY take an optional group: GEO_CITY(billing)
+       * Value types: CONST(value), ONEOF(value, ...) and DEFAULT (the SQL keyword)
+       * EXPR(expression) computes the value from columns generated earlier in the row
 
        :Parameters:
            - `attribute_type`: The data type to check
@@ -72,6 +983,7 @@ fn check_data_type(attribute_type: &str) -> bool {
 
     //Recreate valid types variable, but put all types in alphabetical order
     let valid_types = [
+        "AGE",
         "BIGINT",
         "BIT",
         "BOOLEAN",
@@ -84,14 +996,26 @@ fn check_data_type(attribute_type: &str) -> bool {
         "CITY_US",
         "COMPANYNAME",
         "COMPOUND", // Not a real data type, used for compound attributes
+        "CONST",
         "COUNTRY",
         "DATE",
         "DECIMAL",
+        "DEFAULT",
+        "DOMAIN",
         "DOUBLE PRECISION",
         "EMAIL",
         "ENUM",
+        "EXPR",
+        "FIRST_NAME",
         "FLOAT4",
         "FLOAT8",
+        "FULL_ADDRESS",
+        "GEO_CITY",
+        "GEO_COUNTRY",
+        "GEO_LAT",
+        "GEO_LONG",
+        "GEO_STATE",
+        "GEO_ZIP",
         "GROUP",
         "INDUSTRY",
         "INET",
@@ -99,18 +1023,23 @@ fn check_data_type(attribute_type: &str) -> bool {
         "INTERVAL",
         "JSON",
         "JSONB",
+        "LAST_NAME",
         "LINE",
         "LSEG",
         "MACADDR",
+        "MIDDLE_INITIAL",
+        "MIDDLE_NAME",
         "MONEY",
         "NAME",
         "NUMERIC",
+        "ONEOF",
         "PASSWORD",
         "PATH",
         "PG_LSN",
         "PHONE",
         "POINT",
         "POLYGON",
+        "PREFIX",
         "PROFESSION",
         "REAL",
         "SERIAL",
@@ -120,6 +1049,7 @@ fn check_data_type(attribute_type: &str) -> bool {
         "STATE_US",
         "STREET_ADDRESS",
         "STREET_NAME_US",
+        "SUFFIX",
         "TEXT",
         "TIME",
         "TIMESTAMP",
@@ -130,6 +1060,7 @@ fn check_data_type(attribute_type: &str) -> bool {
         "UUID",
         "VARCHAR",
         "XML",
+        "ZIP",
         "ZIP_US",
     ];
 
@@ -159,1714 +1090,5576 @@ fn check_key_definition(key_def: &str) -> bool {
     valid_keys.contains(&key_def)
 }
 
-fn check_pair(
-    generated_pair_vector: &Vec<String>,
-    previous_pairs: &Vec<Vec<String>>,
-    table_attributes: &Vec<String>,
-    uq_attributes: &HashMap<String, Vec<String>>,
-    count: usize,
-) -> (bool, Vec<String>) {
+fn create_insert_statement(
+    table_name: &str,
+    columns: Option<&[String]>,
+    rows: &[String],
+    dialect: Dialect,
+) -> String {
     /*
-        * Recursively calls itself until it generates a valid composite key pair
-        * All data used in pair generation comes from uq_attributes
+        * Creates the insert statement for one or several rows of the table, see 'batch'
+        * Example: INSERT INTO table_name (userID, name) VALUES (1, 'a'), (2, 'b');
+        * Without columns the values are positional: INSERT INTO table_name VALUES (1, 'a');
+        * Rows past the limit of the dialect (see Dialect::max_rows) go in the next statement, one per line
 
         :parameters:
-            - `generated_pair_vector`: The vector of generated pairs
-            - `previous_pairs`: The vector of previously generated pairs
-            - `table_attributes`: The vector of table attributes
-            - `uq_attributes`: The hashmap of unique attributes
-            - `count`: The number of attributes in the composite key
+            - `table_name`: The name of the table
+            - `columns`: The columns of the values, as given by get_column_names. None leaves the column list out
+            - `rows`: The values of every row, as given by create_insert_values
+            - `dialect`: The database the table and column names are quoted for
 
         :returns:
-            - `bool`: True if valid, false if invalid
-            - `Vec<String>`: The generated pair
+            - `String`: The insert statement for the rows, or several separated by newlines
     */
-    let mut new_pair: Vec<String> = Vec::new();
-
-    // First Time Running With Generated Pair
-    let pair_changed = count > 0;
+    let column_list = match columns {
+        Some(columns) => format!(
+            " ({})",
+            columns.iter().map(|column| dialect.quote(column)).collect::<Vec<String>>().join(", ")
+        ),
+        None => String::new(),
+    };
+    rows.chunks(dialect.max_rows().unwrap_or(rows.len()).max(1))
+        .map(|rows| format!("INSERT INTO {}{} VALUES {};", dialect.quote(table_name), column_list, rows.join(", ")))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
 
-    let reverse_generated_pair: Vec<String> = generated_pair_vector
-        .clone()
-        .into_iter()
-        .rev()
-        .collect::<Vec<String>>();
+fn get_column_names(table_attributes: &[String]) -> Vec<String> {
+    /*
+        * Gets the columns of the table in the order create_insert_values writes their values
+        * Example: ['PK userID INTEGER', 'FK (order_id, line_no) order_line(order_id, line_no)'] -> [userID, order_id, line_no]
+        * A compound attribute is a single column holding all of its parts
 
-    if previous_pairs.contains(&generated_pair_vector)
-        || previous_pairs.contains(&reverse_generated_pair)
-    {
-        // Duplicate Pair Found
-        // Must Generate New Data For Generated Pair
-        for temp_attr in table_attributes.clone() {
-            let temp_attr_list = temp_attr.trim().split(' ').collect::<Vec<&str>>();
-            if temp_attr_list[0].to_uppercase() == "PK/FK" {
-                let temp_attribute_name = temp_attr_list[3]
-                    .split('(')
-                    .nth(1)
-                    .unwrap()
-                    .replace(")", "");
+        :parameters:
+            - `table_attributes`: The vector of table attributes
 
-                if let Some(history) = uq_attributes.get(&temp_attribute_name) {
-                    loop {
-                        if let Some(value) = history.choose(&mut thread_rng()) {
-                            if !new_pair.contains(&value.to_string()) {
-                                new_pair.push(value.to_string()); // Cast to &str
-                                break;
-                            }
-                        }
-                    }
-                }
+        :returns:
+            - `Vec<String>`: The column names
+    */
+    let mut columns: Vec<String> = Vec::new();
+    for attribute in table_attributes {
+        match get_composite_reference(attribute) {
+            Some(reference) => columns.extend(reference.columns),
+            None => {
+                let mut attribute_definition: Vec<String> =
+                    attribute.split_whitespace().map(|s| s.to_owned()).collect();
+                extract_column_options(&mut attribute_definition);
+                let isCompound = merge_compound(&mut attribute_definition);
+                columns.push(get_attribute_name(&attribute_definition, isCompound));
             }
         }
-        return check_pair(
-            &new_pair,
-            previous_pairs,
-            table_attributes,
-            uq_attributes,
-            count + 1,
-        );
     }
-
-    new_pair.extend_from_slice(&generated_pair_vector);
-    (pair_changed, new_pair)
+    columns
 }
 
-
-fn create_insert_statement(
-    table_name: &str,
-    table_attributes: &Vec<String>,
-    statement_data: &HashMap<String, String>,
-) -> String {
+fn create_insert_values(table_attributes: &[String], statement_data: &HashMap<String, String>) -> String {
     /*
-        * Creates the insert statement for the table
-        * Example: INSERT INTO table_name VALUES (data1, data2, data3, ...)
+        * Creates the values of one row of the table in the order of its attributes
+        * Example: (data1, data2, data3, ...)
         * The data is generated from the statement_data hashmap
 
         :parameters:
-            - `table_name`: The name of the table
             - `table_attributes`: The vector of table attributes
             - `statement_data`: The hashmap of generated data for the table
 
         :returns:
-            - `String`: The insert statement for the table
+            - `String`: The values of the row, in parentheses
 
         TODO - Optimize this disgusting code
     */
-    let mut insert_string = format!("INSERT INTO {} VALUES (", table_name);
+    let mut insert_string = String::from("(");
 
     // Iterate over the generated data and add it to the INSERT statement
-    // useless_key has no purpose and is only there to aid in iteration.
     for (index, attribute) in table_attributes.iter().enumerate() {
-        let mut isCompound: bool = false;
-        let compound_attribute;
-        let mut compound_attribute_string = format!("(");
-        let mut attribute_name: Vec<&str> = attribute.trim().split(" ").collect();
-
-        if attribute_name.contains(&"COMPOUND") || attribute_name.contains(&"compound") {
-            isCompound = true;
-
-            // Join all elements after the element containing "COMPOUND" into one string element
-            // "COMPOUND" can either be at attribute_checker[1] or attribute_checker[2]
-            // Attribute checker should then have either 3 elements or 4 elements
-            // 3 elements -> [attribute_name, COMPOUND, compound_attributes]
-            // 4 elements -> [key_definition, attribute_name, COMPOUND, compound_attributes]
-            let index_of_compound = attribute_name
-                .iter()
-                .position(|&x| x == "compound" || x == "COMPOUND")
-                .unwrap(); // Get index of "compound"
-
-            compound_attribute = attribute_name[index_of_compound + 1..].join(" "); // Merge compound attr into one string
-            attribute_name.truncate(index_of_compound + 1); // Remove excess elements
-            attribute_name.push(&compound_attribute); // Push new compound attribute to end
-        }
+        let mut attribute_definition: Vec<String> = attribute
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .collect();
 
-        let mut data: String = "".to_string();
+        extract_column_options(&mut attribute_definition);
+        let isCompound = merge_compound(&mut attribute_definition);
 
-        match attribute_name.len() {
-            1 | 2 => {
-                data = statement_data
-                    .get(attribute_name[0])
-                    .unwrap()
-                    .trim()
-                    .to_string();
-            }
-            3 => match isCompound {
-                true => {
-                    data = statement_data
-                        .get(attribute_name[0])
-                        .unwrap()
-                        .trim()
-                        .to_string();
-                }
-                false => {
-                    data = statement_data
-                        .get(attribute_name[1])
-                        .unwrap()
-                        .trim()
-                        .to_string();
-                }
-            }
-            4 | 5 => {
-                /*
-                    * Examples of Attribute
-                    * PK/FK userID INTEGER profile(userID)
-                    * [PK/AK] full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20))
-                    * [FK] MBR COMPOUND (x_min INTEGER, x_max INTEGER, y_min INTEGER, y_max INTEGER) region(coordinates)
-                */
-                data = statement_data
-                    .get(attribute_name[1])
+        /*
+            * Examples of Attribute
+            * PK/FK userID INTEGER profile(userID)
+            * [PK/AK] full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20))
+            * [FK] MBR COMPOUND (x_min INTEGER, x_max INTEGER, y_min INTEGER, y_max INTEGER) region(coordinates)
+            * FK (order_id, line_no) order_line(order_id, line_no) -> One value for each attribute
+        */
+        let value_string = match get_composite_reference(attribute) {
+            // The attributes have the types of the parent, which are not known here
+            Some(reference) => reference
+                .columns
+                .iter()
+                .map(|column| format_sql_value(statement_data[column].trim(), ""))
+                .collect::<Vec<String>>()
+                .join(", "),
+            None => {
+                let data = statement_data
+                    .get(&get_attribute_name(&attribute_definition, isCompound))
                     .unwrap()
                     .trim()
                     .to_string();
-            }
-            _ => {}
-        }
-
-        match isCompound {
-            true => {
-                let compound_attributes = data.split(", ").collect::<Vec<&str>>();
-                for (cIndex, cAttribute) in compound_attributes.iter().enumerate() {
-                    if cIndex == compound_attributes.len() - 1 {
-                        if cAttribute.parse::<f64>().is_ok() {
-                            compound_attribute_string += &format!("{})", cAttribute);
-                        } else if cAttribute.to_ascii_uppercase() == "NULL" {
-                            compound_attribute_string += "NULL)";
-                        } else if cAttribute == &"0" {
-                            compound_attribute_string += "0)";
-                        } else if cAttribute.to_ascii_uppercase() == "TRUE" {
-                            compound_attribute_string += "TRUE)";
-                        } else if cAttribute.to_ascii_uppercase() == "FALSE" {
-                            compound_attribute_string += "FALSE)";
-                        } else {
-                            compound_attribute_string += &format!("\'{}\')", cAttribute);
-                        }
-                    } else {
-                        if cAttribute.parse::<f64>().is_ok() {
-                            compound_attribute_string += &format!("{},", cAttribute);
-                        } else if cAttribute.to_ascii_uppercase() == "NULL" {
-                            compound_attribute_string += "NULL,";
-                        } else if cAttribute == &"0" {
-                            compound_attribute_string += "0,";
-                        } else if cAttribute.to_ascii_uppercase() == "TRUE" {
-                            compound_attribute_string += "TRUE,";
-                        } else if cAttribute.to_ascii_uppercase() == "FALSE" {
-                            compound_attribute_string += "FALSE,";
-                        } else {
-                            compound_attribute_string += &format!("\'{}\',", cAttribute);
-                        }
-                    }
-                }
-                if index == table_attributes.len() - 1 {
-                    insert_string += &format!("{});", compound_attribute_string);
-                } else {
-                    insert_string += &format!("{}, ", compound_attribute_string);
-                }
-            }
-            false => {
-                // If we're at the last piece of data, close the statement with a semicolon
-                if index == table_attributes.len() - 1 {
-                    if data.parse::<f64>().is_ok() {
-                        insert_string += &format!("{});", data);
-                    } else if data.to_ascii_uppercase() == "NULL" {
-                        insert_string += "NULL);";
-                    } else if data == "0" {
-                        insert_string += "0);";
-                    } else if data.to_ascii_uppercase() == "TRUE" {
-                        insert_string += "TRUE);";
-                    } else if data.to_ascii_uppercase() == "FALSE" {
-                        insert_string += "FALSE);";
-                    } else {
-                        insert_string += &format!("\'{}\');", data);
+                match isCompound {
+                    true if data.eq_ignore_ascii_case("NULL") => "NULL".to_string(),
+                    true => {
+                        // The compound's attributes are given as 'name TYPE; name TYPE; ...'
+                        let compound_index = attribute_definition
+                            .iter()
+                            .position(|element| element.eq_ignore_ascii_case("COMPOUND"))
+                            .unwrap();
+                        let compound = attribute_definition[compound_index + 1].trim();
+                        let compound_types: Vec<String> = compound[1..compound.len() - 1]
+                            .split("; ")
+                            .map(|attribute| {
+                                let attribute_type = attribute
+                                    .trim()
+                                    .split_once(char::is_whitespace)
+                                    .map_or("", |(_, attribute_type)| attribute_type.trim());
+                                split_locale(&normalize_type(attribute_type)).0
+                            })
+                            .collect();
+                        format!(
+                            "({})",
+                            data.split(", ")
+                                .enumerate()
+                                .map(|(index, data)| {
+                                    format_sql_value(data, compound_types.get(index).map_or("", |t| t.as_str()))
+                                })
+                                .collect::<Vec<String>>()
+                                .join(",")
+                        )
                     }
-                    // If we're not at the last piece of data, add a comma to the end of the statement
-                } else {
-                    if data.parse::<f64>().is_ok() {
-                        insert_string += &format!("{}, ", data);
-                    } else if data.to_ascii_uppercase() == "NULL" {
-                        insert_string += "NULL, ";
-                    } else if data == "0" {
-                        insert_string += "0, ";
-                    } else if data.to_ascii_uppercase() == "TRUE" {
-                        insert_string += "TRUE, ";
-                    } else if data.to_ascii_uppercase() == "FALSE" {
-                        insert_string += "FALSE, ";
-                    } else {
-                        insert_string += &format!("\'{}\', ", data);
+                    false => {
+                        // [name TYPE], [key name TYPE] or [key name TYPE reference(attribute)]
+                        let attribute_type = match attribute_definition.len() {
+                            2 | 3 => attribute_definition.last().unwrap(),
+                            _ => &attribute_definition[2],
+                        };
+                        format_sql_value(&data, &split_locale(&normalize_type(attribute_type)).0)
                     }
                 }
             }
+        };
+
+        // If we're at the last piece of data, close the row
+        if index == table_attributes.len() - 1 {
+            insert_string += &format!("{})", value_string);
+        } else {
+            insert_string += &format!("{}, ", value_string);
         }
     }
     insert_string
 }
 
-fn get_random_data(
+fn format_sql_value(data: &str, attribute_type: &str) -> String {
+    /*
+        * Formats a piece of generated data of a column as a SQL literal
+        * Numbers, NULL, TRUE and FALSE are left unquoted, everything else is quoted
+        * Only the DEFAULT type is written as the DEFAULT keyword
+        * Values of CONST(...) / ONEOF(...) given in quotes stay text: CONST('42') -> '42', CONST(42) -> 42, CONST('NULL') -> 'NULL'
+        * ZIP / GEO_* values are only numbers when written with digits alone, so zip codes like 02134 keep their zeros
+        * Quotes inside quoted data are escaped by doubling them (O'Brien -> 'O''Brien')
+
+        :parameters:
+            - `data`: The generated data
+            - `attribute_type`: The type of the column, empty when it is not known (read like ZIP / GEO_*)
+
+        :returns:
+            - `String`: The data as it should appear in the insert statement
+    */
+    let quoted = || format!("\'{}\'", data.replace('\'', "''"));
+
+    // Checked before NULL so CONST('NULL') is the text, the NULLs of NULL(p) are the keyword
+    if (attribute_type.starts_with("CONST") || attribute_type.starts_with("ONEOF"))
+        && get_value_entries(attribute_type)
+            .iter()
+            .any(|(value, is_quoted)| *is_quoted && value == data)
+    {
+        return quoted();
+    }
+    if data.eq_ignore_ascii_case("NULL") {
+        return "NULL".to_string();
+    }
+    if attribute_type == "DEFAULT" {
+        return "DEFAULT".to_string();
+    }
+
+    let is_number = match attribute_type {
+        address_type
+            if address_type.is_empty()
+                || address_type.starts_with("ZIP")
+                || address_type.starts_with("GEO_") =>
+        {
+            let leading_zero = data.len() > 1 && data.starts_with('0') && !data.starts_with("0.");
+            data.parse::<f64>().is_ok()
+                && data.chars().all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+                && !leading_zero
+        }
+        _ => data.parse::<f64>().is_ok_and(f64::is_finite),
+    };
+
+    if is_number {
+        data.to_string()
+    } else if data.eq_ignore_ascii_case("TRUE") || data.eq_ignore_ascii_case("FALSE") {
+        data.to_uppercase()
+    } else {
+        quoted()
+    }
+}
+
+fn get_column_data(
     attribute_type: &str,
     optional_data_size: Option<Vec<u16>>,
+    options: &ColumnOptions,
+    expression: Option<&Expression>,
     statement_data: &HashMap<String, String>,
 ) -> String {
     /*
-        * Generates random data for the attribute type
-        * If the attribute type is a custom data type, the custom data type is generated
-        * If the attribute type is a default data type, the default data type is generated
-
-        ** Uses the Faker library to generate data for default data types
+        * Generates the data for a column, taking the column's options into account
+        * Columns with FROM(...) are derived from the referenced columns, EXPR(...) columns are computed, all others are random
 
         :parameters:
             - `attribute_type`: The type of the attribute
             - `optional_data_size`: The optional data size for the attribute
-            - `statement_data`: The hashmap of generated data for the table
+            - `options`: The options given to the column
+            - `expression`: The parsed expression of an EXPR(...) column, see TableContext
+            - `statement_data`: The hashmap of generated data for the row
 
         :returns:
             - `String`: The generated data for the attribute
+    */
+    // A CHECK constraint limiting the column to a few values replaces the generated data
+    if let Some(allowed_value) = options.allowed_values.choose(&mut row_rng()) {
+        return allowed_value.clone();
+    }
+
+    let locale = options.locale.unwrap_or_default();
+    let generated_data = match (expression, options.from.is_empty(), &options.distribution) {
+        (Some(expression), _, _) => evaluate_expression(expression, statement_data).to_string(),
+        (None, true, Some(Ok(distribution))) => {
+            get_distributed_data(attribute_type, optional_data_size, distribution)
+        }
+        (None, true, _) => get_random_data(attribute_type, optional_data_size, locale, statement_data),
+        (None, false, _) => get_derived_data(
+            attribute_type,
+            optional_data_size,
+            &options.from,
+            locale,
+            statement_data,
+        ),
+    };
+    get_ranged_data(generated_data, options)
+}
+
+fn get_distributed_data(
+    attribute_type: &str,
+    optional_data_size: Option<Vec<u16>>,
+    distribution: &ValueDistribution,
+) -> String {
+    /*
+        * Generates the data of a DIST(...) column from its distribution
+        * Numbers keep the format of their type, dates and timestamps are read as days since 1970-01-01
+        * Numbers past what the type holds become its largest (or smallest) value:
+            * INTEGER -> i32, DECIMAL(p, s) -> p digits with s of them after the point, MONEY(n) -> n digits and cents
+            * DATE, TIMESTAMP -> 0001-01-01 to 9999-12-31, the dates every database takes
+        * Example: DECIMAL(10, 2) with normal(50, 5) -> 48.73, DATE with normal('2021-06-01', 30) -> 2021-05-19
+        * Example: DECIMAL(4, 2) with normal(500, 5) -> 99.99
 
-        :TODO:
-            - Add First Name, Last Name, Middle Name / Middle Initial
+        :parameters:
+            - `attribute_type`: The type of the attribute, one of DISTRIBUTED_TYPES
+            - `optional_data_size`: The optional data size for the attribute
+            - `distribution`: The distribution of the column
+
+        :returns:
+            - `String`: The generated data for the attribute
     */
+    let value = get_distribution_sample(distribution);
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    // Days from 1970-01-01 to the first and the last date a DATE or TIMESTAMP is given. Clamping the days
+    // and seconds to them first keeps chrono::Duration from panicking on a long tail
+    let first_day = (NaiveDate::from_ymd_opt(1, 1, 1).unwrap() - epoch).num_days();
+    let last_day = (NaiveDate::from_ymd_opt(9999, 12, 31).unwrap() - epoch).num_days();
+
+    // Rounded to the decimal places first, so a value just under the limit can not round past it
+    let within = |places: i32, limit: Option<f64>| {
+        let value = (value * 10_f64.powi(places)).round() / 10_f64.powi(places);
+        limit.map_or(value, |limit| value.clamp(-limit, limit))
+    };
+
     match attribute_type {
-        char_type if char_type.starts_with("CHAR") || char_type.starts_with("VARCHAR") => {
-            //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
-            let char_size = optional_data_size
-                .unwrap_or_else(|| vec![thread_rng().gen_range(3..12)])
-                .get(0)
-                .unwrap()
-                .to_owned();
-            Faker
-                .fake::<String>()
-                .chars()
-                .take(char_size as usize)
-                .collect::<String>()
-        }
+        "INTEGER" => (within(0, None).clamp(i32::MIN as f64, i32::MAX as f64) as i64).to_string(),
         decimal_type if decimal_type.starts_with("DECIMAL") => {
-            //Attempt to unwrap and get vec[0] and vec[1] of optional data-size
-            //Vec[0] is the number of digits before the decimal point
-            //Vec[1] is the number of digits after the decimal point
-            //If none, set random values of each
-            let unwrapped_decimal = optional_data_size.unwrap_or_else(|| {
-                vec![thread_rng().gen_range(3..12), thread_rng().gen_range(3..12)]
-            });
-            let digits_before_decimal = unwrapped_decimal.get(0).unwrap().to_owned();
-            let digits_after_decimal = unwrapped_decimal.get(1).unwrap().to_owned();
-            //Create decimal value from digits_before_decimal and digits_after_decimal
-            let decimal_value = format!(
-                "{}.{}",
-                thread_rng().gen_range(0..10_u64.pow(digits_before_decimal as u32)),
-                thread_rng().gen_range(0..10_u64.pow(digits_after_decimal as u32)),
-            );
-            decimal_value
+            let places = optional_data_size.as_ref().and_then(|size| size.get(1).copied()).unwrap_or(2) as i32;
+            let limit = optional_data_size
+                .and_then(|size| size.first().copied())
+                .map(|digits| 10_f64.powi(digits as i32 - places) - 10_f64.powi(-places));
+            format!("{:.*}", places as usize, within(places, limit))
         }
         money_type if money_type.starts_with("MONEY") => {
-            //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
-            let dollar_size = optional_data_size
-                .unwrap_or_else(|| vec![thread_rng().gen_range(3..12)])
-                .get(0)
-                .unwrap()
-                .to_owned();
-
-            // Generate a dollar amount between 3 figures and either dollar size or 12 figures
-            let dollar_amount = thread_rng().gen_range(0..10i32.pow(dollar_size as u32));
-            let cents_amount = thread_rng().gen_range(0..100);
-            // Create decimal value from dollar_amount and cents_amount
-            let decimal_value = format!("{}.{}", dollar_amount, cents_amount);
-            decimal_value
-        }
-        name_type if name_type.starts_with("NAME") => {
-            let name = Name(EN).fake::<String>();
-            name.replace("'", "")
-        }
-        password_type if password_type.starts_with("PASSWORD") => Password(std::ops::Range {
-            start: 8,
-            end: (optional_data_size
-                .unwrap_or_else(|| vec![thread_rng().gen_range(8..12)])
-                .get(0)
-                .unwrap()
-                .to_owned() as usize), //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
-        })
-            .fake(),
-        username_type if username_type.starts_with("USERNAME") => {
-            let first_name = FirstName(EN).fake::<String>();
-            let last_name = LastName(EN).fake::<String>();
-            let mut username = format!("{}{}", first_name, last_name);
-            //Check if optional data size is specified in vec[0], if so, truncate username to that size if it is larger
-            if let Some(size) = optional_data_size {
-                if username.len() > size[0] as usize {
-                    username.truncate(size[0] as usize);
-                }
-            }
-
-            username.replace("'", "")
+            let limit = optional_data_size
+                .and_then(|size| size.first().copied())
+                .map(|digits| 10_f64.powi(digits as i32) - 0.01);
+            format!("{:.2}", within(2, limit))
         }
-        "INTEGER" => Faker.fake::<u16>().to_string(),
-        "BOOLEAN" => Faker.fake::<bool>().to_string(),
         "DATE" => {
-            let year = thread_rng().gen_range(1900..2021);
-            let month = thread_rng().gen_range(1..13);
-            let day = thread_rng().gen_range(1..29);
-            NaiveDate::from_ymd_opt(year, month, day)
-                .unwrap()
-                .to_string()
+            let days = value.floor().clamp(first_day as f64, last_day as f64) as i64;
+            epoch.checked_add_signed(chrono::Duration::days(days)).unwrap_or(epoch).to_string()
         }
         "TIMESTAMP" => {
-            let year = thread_rng().gen_range(1900..2021);
-            let month = thread_rng().gen_range(1..13);
-            let day = thread_rng().gen_range(1..29);
-            let hour = thread_rng().gen_range(0..24);
-            let minute = thread_rng().gen_range(0..60);
-            let second = thread_rng().gen_range(0..60);
-            NaiveDateTime::new(
-                NaiveDate::from_ymd_opt(year, month, day).unwrap(),
-                chrono::NaiveTime::from_hms_opt(hour, minute, second).unwrap(),
-            )
-                .to_string()
-        }
-        "TIME" => {
-            let hour = thread_rng().gen_range(0..24);
-            let minute = thread_rng().gen_range(0..60);
-            let second = thread_rng().gen_range(0..60);
-            chrono::NaiveTime::from_hms_opt(hour, minute, second)
-                .unwrap()
-                .to_string()
-        }
-        "GROUP" => ["Member", "Mod"]
-            .choose(&mut thread_rng())
-            .unwrap()
-            .to_string(),
-        "EMAIL" => {
-            let domains = vec![
-                "@outlook.com",
-                "@gmail.com",
-                "@pitt.edu",
-                "@yahoo.com",
-                "@proton.mail",
-                "@pm.me",
-                "@paranoid.email",
-            ];
-            let username = match (
-                statement_data.get(&"name".to_string()),
-                statement_data.get(&"full name".to_string()),
-                statement_data.get(&"full_name".to_string()),
-            ) {
-                (Some(name), _, _) => name.replace(" ", ""),
-                (_, Some(full_name), _) => full_name.replace(" ", ""),
-                (_, _, Some(full_name_underscore)) => full_name_underscore.replace(" ", ""),
-                _ => {
-                    let name = Name(EN).fake::<String>();
-                    name.replace("'", "").to_string();
-                    name.replace(" ", "").to_string()
-                }
-            };
-            format!("{}{}", username, domains.choose(&mut thread_rng()).unwrap())
-        }
-        "STATE_US" => {
-            //Generate random state in US using faker
-            let state = StateName().fake::<String>();
-            state.replace("'", "")
-        }
-        "CITY_US" => {
-            let city = CityName().fake::<String>();
-            city.replace("'", "")
-        }
-        "CITY_SHORT" => {
-            let city_prefix = CityPrefix().fake::<String>();
-            city_prefix.replace("'", "")
-        }
-        "STREET_NAME_US" => {
-            let street_address = StreetName().fake::<String>();
-            street_address.replace("'", "")
-        }
-        "ZIP_US" => {
-            let zip = ZipCode().fake::<String>();
-            zip.replace("'", "")
-        }
-        "SSN" => {
-            // Generate random 9 digit number
-            let ssn = thread_rng().gen_range(100_000_000..1_000_000_000);
-            ssn.to_string()
-        }
-        "PHONE" => {
-            // Generate random phone number using faker
-            let phone_number = PhoneNumber().fake::<String>();
-            phone_number.replace("'", "")
+            let seconds = (value * 86_400.0)
+                .floor()
+                .clamp(first_day as f64 * 86_400.0, (last_day + 1) as f64 * 86_400.0 - 1.0) as i64;
+            let midnight = epoch.and_hms_opt(0, 0, 0).unwrap();
+            midnight.checked_add_signed(chrono::Duration::seconds(seconds)).unwrap_or(midnight).to_string()
         }
-        "COUNTRY" => {
-            // Generate random country using faker
-            let country = CountryName().fake::<String>();
-            country.replace("'", "")
-        }
-        "COMPANYNAME" => {
-            // Generate random company name using faker
-            let company_name = CompanyName().fake::<String>();
-            company_name.replace("'", "")
+        _ => value.to_string(),
+    }
+}
+
+fn get_distribution_sample(distribution: &ValueDistribution) -> f64 {
+    /*
+        * Draws a number from a distribution. The parameters were checked by parse_distribution
+
+        :parameters:
+            - `distribution`: The distribution to draw from
+
+        :returns:
+            - `f64`: The drawn number
+    */
+    let mut rng = row_rng();
+    match distribution {
+        ValueDistribution::Normal(mean, deviation) => {
+            rng.sample(Normal::new(*mean, *deviation).unwrap())
         }
-        "INDUSTRY" => {
-            // Generate random industry using faker
-            let industry = Industry().fake::<String>();
-            industry.replace("'", "")
+        ValueDistribution::LogNormal(mean, deviation, start) => {
+            start + rng.sample(LogNormal::new(*mean, *deviation).unwrap())
         }
-        "PROFESSION" => {
-            // Generate random profession using faker
-            let profession = Profession().fake::<String>();
-            profession.replace("'", "")
+        ValueDistribution::Exponential(rate, start) => start + rng.sample(Exp::new(*rate).unwrap()),
+        // Zipf ranks start at 1, so the start is the most common value
+        ValueDistribution::Zipf(count, exponent, start) => {
+            start + rng.sample(Zipf::new(*count, *exponent).unwrap()) - 1.0
         }
-        _ => {
-            panic!("Unknown Type In Data Generation! {}", attribute_type);
+        ValueDistribution::Histogram(buckets) => {
+            let index = rng.sample(
+                WeightedIndex::new(buckets.iter().map(|(_, _, weight)| *weight)).unwrap(),
+            );
+            let (low, high, _) = buckets[index];
+            match low < high {
+                true => rng.gen_range(low..high),
+                false => low,
+            }
         }
     }
 }
 
-fn get_references(attribute_definition: &Vec<String>, index: usize) -> (String, String) {
+fn parse_null_probability(arguments: &str) -> Result<f64, String> {
     /*
-        * Gets the referenced table and attribute from the attribute definition
-        * Example: PK userID INTEGER profile(userID)
-        * Referenced Table: profile
-        * Referenced Attribute: userID
+        * Parses the argument of NULL(p): the chance of the column being NULL in a row
+        * Example: '0.3' -> 0.3, '1.5' -> Err, 'abc' -> Err
 
         :parameters:
-            - `attribute_definition`: The vector of attribute definitions
-            - `index`: The index of the attribute definition to get the referenced table and attribute from
+            - `arguments`: The text inside of NULL(...)
 
         :returns:
-            - `String`: The referenced table
-            - `String`: The referenced attribute
+            - `Result<f64, String>`: The probability, or the reason it is invalid
     */
-    let referenced_table = attribute_definition[index]
-        .split('(')
-        .next()
-        .unwrap()
-        .to_string();
-
-    let referenced_attribute = attribute_definition[index]
-        .split('(')
-        .nth(1)
-        .unwrap()
-        .replace(")", "");
-
-    (referenced_table, referenced_attribute)
+    match arguments.trim().parse::<f64>() {
+        Ok(probability) if (0.0..=1.0).contains(&probability) => Ok(probability),
+        Ok(_) => Err(format!("{} Is Not A Probability Between 0 And 1", arguments.trim())),
+        Err(_) => Err(format!("'{}' Is Not A Number", arguments.trim())),
+    }
 }
 
-fn get_referenced_attribute(
-    attribute_list: &Vec<String>,
-    referenced_attribute: &str,
-) -> Option<String> {
+fn parse_fanout(arguments: &str) -> Result<FanOut, String> {
     /*
-        *Returns the table attribute that is referenced by the foreign key
-        *Returns None if the referenced attribute is not found
+        * Parses the arguments of FANOUT(...): a shape followed by optional bounds on the children of every parent
+        * Shapes: uniform (default), zipf, exponent and hot, fraction of parents, share of children
+        * Bounds: min n (every parent gets at least n children) and max n
+        * Example: 'zipf, 1.2, max 20' -> each parent has 0 - 20 children, zipf distributed
 
         :parameters:
-            - `attribute_list`: The list of attributes in the table
-            - `referenced_attribute`: The referenced attribute to find
+            - `arguments`: The text inside of FANOUT(...)
 
         :returns:
-            - `Option<String>`: The referenced attribute or None
+            - `Result<FanOut, String>`: The fan-out, or the reason it is invalid
     */
+    let values = get_value_list(&format!("FANOUT({})", arguments));
+    let mut fanout = FanOut {
+        shape: FanOutShape::Uniform,
+        minimum: 0,
+        maximum: None,
+    };
 
-    if !attribute_list.is_empty() {
-        for attribute in attribute_list {
-            if attribute.split_whitespace().nth(1) == Some(referenced_attribute) {
-                return Some(attribute.to_string());
+    let number = |value: Option<&String>, name: &str| -> Result<f64, String> {
+        match value.and_then(|value| value.trim().parse::<f64>().ok()) {
+            Some(number) if number.is_finite() => Ok(number),
+            _ => Err(format!("{} Needs A Number", name)),
+        }
+    };
+
+    let mut values = values.iter();
+    while let Some(value) = values.next() {
+        let (keyword, bound) = value.split_once(char::is_whitespace).unwrap_or((value, ""));
+        match keyword.to_lowercase().as_str() {
+            "uniform" => fanout.shape = FanOutShape::Uniform,
+            "zipf" => {
+                let exponent = number(values.next(), "zipf")?;
+                if exponent <= 0.0 {
+                    return Err("The Exponent Of zipf Must Be Above 0".to_string());
+                }
+                fanout.shape = FanOutShape::Zipf(exponent);
+            }
+            "hot" => {
+                let fraction = number(values.next(), "hot")?;
+                let share = number(values.next(), "hot")?;
+                if !(fraction > 0.0 && fraction < 1.0 && share > 0.0 && share <= 1.0) {
+                    return Err("hot Needs A Fraction Of Parents And A Share Of Children Between 0 And 1".to_string());
+                }
+                fanout.shape = FanOutShape::Hot(fraction, share);
             }
+            "min" | "max" => {
+                let bound = match bound.trim().parse::<u64>() {
+                    Ok(bound) => bound,
+                    Err(_) => return Err(format!("{} Needs A Whole Number (i.e {} 5)", keyword, keyword)),
+                };
+                match keyword.eq_ignore_ascii_case("min") {
+                    true => fanout.minimum = bound,
+                    false => fanout.maximum = Some(bound),
+                }
+            }
+            _ => return Err(format!("Unknown Fan-Out '{}'", value)),
         }
     }
-    None
-}
 
-fn generate_mock_data(
-    tables: &Vec<String>,
-    key_dictionary: &HashMap<String, Vec<String>>,
-    reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
-    path: &String,
-    iterations: u16,
-) {
+    match fanout.maximum {
+        Some(maximum) if maximum == 0 || maximum < fanout.minimum => {
+            Err("max Must Be At Least 1 And At Least min".to_string())
+        }
+        _ => Ok(fanout),
+    }
+}
+
+fn get_parent_data(
+    attribute_name: &str,
+    picker: Option<&FanOutPicker>,
+    parents: &ValueStore,
+) -> Result<String, GenerationError> {
     /*
-        * Generates the mock data for the tables
-        * Writes the mock data to the file specified by path
+        * Picks the parent value of a foreign key for the row
+        * Keys with FANOUT(...) pick through their FanOutPicker, all others pick evenly
 
         :parameters:
-            - `tables`: The vector of tables to generate mock data for
-            - `key_dictionary`: The hashmap of keys for each table
-            - `reference_dictionary`: The hashmap of references for each table
-            - `path`: The path to write the mock data to
-            - `iterations`: The number of iterations to generate mock data for
+            - `attribute_name`: The name of the foreign key attribute
+            - `picker`: The picker of the attribute, if it has a FANOUT(...)
+            - `parents`: The values of the referenced attribute
+
+        :returns:
+            - `Result<String, GenerationError>`: The parent value, an error if every parent is full
     */
+    match picker {
+        Some(picker) => picker.pick().ok_or_else(|| {
+            GenerationError::Rows(format!(
+                "Every Parent Of {} Already Has The Maximum Number Of Children Of Its FANOUT(...)",
+                attribute_name
+            ))
+        }),
+        None => Ok(parents.get(row_rng().gen_range(0..parents.len())).to_string()),
+    }
+}
 
-    let mut unique_attribute_checker: HashMap<String, Vec<String>> = HashMap::new();
-    let mut unique_pair_checker: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+fn get_repeated_key_error(attribute_name: &str, table_name: &str) -> GenerationError {
+    /*
+        * Reports a key attribute that kept generating values other rows already have
+        * Key spaces that can be counted are checked before generating, see check_key_spaces
 
-    let mut statements_generated: u16 = 0;
+        :parameters:
+            - `attribute_name`: The name of the PK or AK attribute
+            - `table_name`: The name of the table being generated
 
-    print!(
-        "Generating SQL Inserts: {}/{} Created",
-        statements_generated, iterations
-    );
-    stdout().flush().unwrap();
+        :returns:
+            - `GenerationError`: The error naming the attribute
+    */
+    GenerationError::Rows(format!(
+        "Could Not Generate A New Value For {} Of {} After {} Tries, Its Type Has Too Few Different Values For The Rows",
+        attribute_name, table_name, MAX_CONSTRAINT_RETRIES
+    ))
+}
 
-    for table in tables.into_iter() {
-        let mut pairwise_table = false;
+fn take_unused_parent(
+    parents: &mut Vec<String>,
+    attribute_name: &str,
+    table_name: &str,
+) -> Result<String, GenerationError> {
+    /*
+        * Takes the next parent value of a unique foreign key (PK/FK, AK/FK), which no row of the table has used yet
+        * The values were shuffled once, so taking them from the back is a random pick without retries
 
-        let table: Vec<&str> = table.split_whitespace().collect();
+        :parameters:
+            - `parents`: The parent values no row has taken yet
+            - `attribute_name`: The name of the foreign key attribute
+            - `table_name`: The name of the table being generated
 
-        let num_statements = table[0].parse::<i32>().unwrap(); // Keeps Track of Number of Statements to Generate
-        let table_name = String::from(table[1]); //Table name
-        let mut table_attributes: String = table[2..] //Table attributes
-            .join(" ")
-            .to_string();
+        :returns:
+            - `Result<String, GenerationError>`: The parent value, an error if every parent is used
+    */
+    parents.pop().ok_or_else(|| {
+        GenerationError::Rows(format!(
+            "Every Parent Of {} In {} Is Already Used, Unique Foreign Keys Need A Parent Per Row",
+            attribute_name, table_name
+        ))
+    })
+}
 
-        table_attributes.remove(0); // Remove beginning parenthesis
-        table_attributes.remove(table_attributes.len() - 1); // Remove ending parenthesis
+fn parse_distribution(arguments: &str) -> Result<ValueDistribution, String> {
+    /*
+        * Parses the arguments of DIST(...)
+        * normal(mean, deviation), lognormal(mean, deviation[, start]), exponential(rate[, start]),
+        * zipf(count, exponent[, start]) and histogram(low:high:weight, ...)
+        * Dates can be given in place of numbers and are read as days since 1970-01-01 (deviations stay days)
+        * Example: 'normal, 4, 0.8' -> Normal(4, 0.8), 'histogram, 1:3:10, 4:5:90' -> Histogram([(1, 3, 10), (4, 5, 90)])
 
-        let table_attributes: Vec<String> = table_attributes // Split attributes -> example element: 'PK userID INTEGER'
-            .clone()
-            .split(",")
-            .map(|s| s.to_owned())
-            .collect();
+        :parameters:
+            - `arguments`: The text inside of DIST(...)
 
-        let check_references = reference_dictionary.get(&table_name);
-        let attribute_order_keeper: Vec<String> = table_attributes.clone(); // Keeps Track of Order of Attributes. Used for Creating Insert Statements
+        :returns:
+            - `Result<ValueDistribution, String>`: The distribution, or the reason it is invalid
+    */
+    let values = get_value_list(&format!("DIST({})", arguments));
+    let (name, parameters) = match values.split_first() {
+        Some((name, parameters)) => (name.to_lowercase(), parameters),
+        None => return Err("Missing The Distribution Name".to_string()),
+    };
 
-        let mut primary_keys: Vec<String> = Vec::new();
+    let number = |parameter: &str| -> Result<f64, String> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        match get_expression_value(parameter) {
+            ExpressionValue::Number(number) if number.is_finite() => Ok(number),
+            ExpressionValue::Date(date) => Ok((date - epoch).num_days() as f64),
+            ExpressionValue::Timestamp(timestamp) => {
+                Ok((timestamp - epoch.and_hms_opt(0, 0, 0).unwrap()).num_seconds() as f64 / 86_400.0)
+            }
+            _ => Err(format!("'{}' Is Not A Number Or Date", parameter)),
+        }
+    };
+    let numbers = |minimum: usize, maximum: usize| -> Result<Vec<f64>, String> {
+        if !(minimum..=maximum).contains(&parameters.len()) {
+            return Err(format!("Wrong Number Of Parameters For {}", name));
+        }
+        parameters.iter().map(|parameter| number(parameter)).collect()
+    };
 
-        // Check if table is a composite keyed table
-        if let Some(_check_references) = check_references {
-            for attribute in &table_attributes {
-                if attribute.trim()[..2].to_uppercase() == "PK".to_string() {
-                    primary_keys.push(attribute.to_string());
-                }
+    let distribution = match name.as_str() {
+        "normal" => {
+            let parameters = numbers(2, 2)?;
+            ValueDistribution::Normal(parameters[0], parameters[1])
+        }
+        "lognormal" => {
+            let parameters = numbers(2, 3)?;
+            ValueDistribution::LogNormal(parameters[0], parameters[1], *parameters.get(2).unwrap_or(&0.0))
+        }
+        "exponential" => {
+            let parameters = numbers(1, 2)?;
+            ValueDistribution::Exponential(parameters[0], *parameters.get(1).unwrap_or(&0.0))
+        }
+        "zipf" => {
+            let parameters = numbers(2, 3)?;
+            if parameters[0] < 1.0 || parameters[0].fract() != 0.0 {
+                return Err("The Count Of zipf Must Be A Whole Number Of At Least 1".to_string());
             }
-            if primary_keys.len() > 1 {
-                pairwise_table = true;
+            ValueDistribution::Zipf(parameters[0] as u64, parameters[1], *parameters.get(2).unwrap_or(&1.0))
+        }
+        "histogram" => {
+            if parameters.is_empty() {
+                return Err("histogram Needs At Least One low:high:weight Bucket".to_string());
+            }
+            let mut buckets: Vec<(f64, f64, f64)> = Vec::new();
+            for bucket in parameters {
+                let bucket_parameters = bucket
+                    .split(':')
+                    .map(|parameter| number(parameter.trim()))
+                    .collect::<Result<Vec<f64>, String>>()?;
+                match bucket_parameters.as_slice() {
+                    [low, high, weight] if low <= high && *weight >= 0.0 => buckets.push((*low, *high, *weight)),
+                    _ => return Err(format!("Invalid Bucket '{}' (i.e 10:100:5 -> low:high:weight)", bucket)),
+                }
             }
+            ValueDistribution::Histogram(buckets)
         }
+        _ => return Err(format!("Unknown Distribution {}", name)),
+    };
 
-        for _ in 0..num_statements {
-            // Stores generated value for CURRENT Insert statement. Resets after each insert statement is generated
-            let mut statement_data: HashMap<String, String> = HashMap::new();
-
-            // Keep track of previous attribute [Used for tables that reference the same table attribute multiple times]
-            // (PK/FK userID1 INTEGER profile(userID), PK/FK userID2 INTEGER profile(userID)
-            let mut referenced_attributes: HashMap<String, Vec<String>> = HashMap::new();
-
-            // Used in pairwise (composite) key scenarios
-            let mut pair_list: Vec<String> = Vec::new();
-
-            for attribute in &table_attributes {
-                let pairwise_attribute =
-                    if pairwise_table && primary_keys.contains(&attribute.to_string()) {
-                        true
-                    } else {
-                        false
-                    };
-
-                let mut attribute_definition: Vec<String> = attribute
-                    .trim()
-                    .split_whitespace()
-                    .map(|s| s.to_owned())
-                    .collect();
-
-                let isCompound = merge_compound(&mut attribute_definition);
-
-                let attribute_definition = attribute_definition;
+    // The rand_distr constructors reject parameters they can not draw from
+    let valid = match &distribution {
+        ValueDistribution::Normal(mean, deviation) => {
+            Normal::new(*mean, *deviation).is_ok() && *deviation >= 0.0
+        }
+        ValueDistribution::LogNormal(mean, deviation, _) => {
+            LogNormal::new(*mean, *deviation).is_ok() && *deviation >= 0.0
+        }
+        ValueDistribution::Exponential(rate, _) => Exp::new(*rate).is_ok() && *rate > 0.0,
+        ValueDistribution::Zipf(count, exponent, _) => Zipf::new(*count, *exponent).is_ok(),
+        ValueDistribution::Histogram(buckets) => {
+            WeightedIndex::new(buckets.iter().map(|(_, _, weight)| *weight)).is_ok()
+        }
+    };
+    match valid {
+        true => Ok(distribution),
+        false => Err(format!("Invalid Parameters For {}", name)),
+    }
+}
 
-                match &attribute_definition.len() {
-                    1 => {
-                        /*
-                         * User requests default value for attribute
-                         * Does not need to specify attribute
-                         * For instance if table has attribute that would be NULL for mock data user can put "NULL"
-                         * Then for each insert, that attribute will be NULL for
-                         */
-                        match attribute_definition[0].to_uppercase().as_str() {
-                            // TODO - Add more default values
-                            "0" | "NULL" | "TRUE" | "FALSE" => {
-                                statement_data.insert(
-                                    attribute_definition[0].to_string(),
-                                    attribute_definition[0].to_string(),
-                                );
-                            }
-                            _ => {
-                                println!("[!] Invalid default value for attribute");
-                                continue;
-                            }
-                        }
-                    }
-                    2 => {
-                        /*
-                            * Attribute Definition Is Of The Form:
-                            * [attribute name] [attribute type]
-                            * Example: 'userID INTEGER'
+fn get_ranged_data(generated_data: String, options: &ColumnOptions) -> String {
+    /*
+        * Moves a generated number inside of the bounds inferred from the table's CHECK constraints
+        * A number past one bound is mirrored over it, a number outside of two bounds is picked again between them
+        * The number keeps its decimal places. Example: CHECK (price >= 0) with -25.50 -> 25.50, CHECK (qty > 0) with 0 -> 2
 
-                            * Generate Data For Attribute
-                        */
+        :parameters:
+            - `generated_data`: The generated data of the column
+            - `options`: The options of the column, holding the bounds
 
-                        let attribute_name = attribute_definition[0].to_string();
-                        let attribute_type = attribute_definition[1].to_uppercase().to_string();
+        :returns:
+            - `String`: The data inside of the bounds, unchanged if it is not a number
+    */
+    if options.minimum.is_none() && options.maximum.is_none() {
+        return generated_data;
+    }
+    let value = match generated_data.parse::<f64>() {
+        Ok(value) if value.is_finite() => value,
+        _ => return generated_data,
+    };
 
-                        let optional_variable_size: Option<Vec<u16>> =
-                            set_variable_size(&attribute_type);
+    // Smallest step of the generated number, 1 for integers and 0.01 for 2 decimal places
+    let places = generated_data.split_once('.').map_or(0, |(_, decimals)| decimals.len());
+    let step = 10_f64.powi(-(places as i32));
+    let low = options.minimum.map(|(bound, inclusive)| match inclusive {
+        true => (bound / step).ceil() * step,
+        false => ((bound / step).floor() + 1.0) * step,
+    });
+    let high = options.maximum.map(|(bound, inclusive)| match inclusive {
+        true => (bound / step).floor() * step,
+        false => ((bound / step).ceil() - 1.0) * step,
+    });
+
+    if low.is_none_or(|low| value >= low) && high.is_none_or(|high| value <= high) {
+        return generated_data;
+    }
+    let value = match (low, high) {
+        (Some(low), None) => low + (low - value),
+        (None, Some(high)) => high - (value - high),
+        (Some(low), Some(high)) if low <= high => {
+            let steps = ((high - low) / step).round() as i64;
+            low + row_rng().gen_range(0..=steps) as f64 * step
+        }
+        _ => return generated_data,
+    };
+    format!("{:.*}", places, value)
+}
 
-                        let generated_data: String = get_random_data(
-                            &attribute_type,
-                            optional_variable_size.clone(),
-                            &statement_data,
-                        );
+fn get_derived_data(
+    attribute_type: &str,
+    optional_data_size: Option<Vec<u16>>,
+    from: &[String],
+    locale: Locale,
+    statement_data: &HashMap<String, String>,
+) -> String {
+    /*
+        * Derives the data for an attribute from other columns of the same row
+        * USERNAME FROM(name) -> 'Bob Johnson' -> BobJohnson[number]
+        * EMAIL FROM(username) -> [username]@[random domain]
+        * EMAIL FROM(username, company) -> [username]@[domain of the company]
+        * DATE | TIMESTAMP FROM(age) -> Date of birth for someone that age today
+        * AGE FROM(date_of_birth) -> Age today of someone born on that date
+        * Any other type copies the first referenced column
 
-                        statement_data.insert(attribute_name, generated_data);
-                    }
-                    3 => match isCompound {
-                        /*
-                            * Attribute Definition Is Of The Form:
-                            * [key definition] [attribute name] [attribute type]
-                            * OR
-                            * [attribute name] [compound] [(compound attribute)]
-                            * Standard Example: 'PK userID INTEGER'
-                            * Compound Example: 'full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20)
-
-                            * Generate Data For Attribute
-                        */
-                        true => {
-                            /*
-                             * Compound Attribute. Generate Data For Each Attribute In The Compound Attribute
-                             * Example: 'full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20))'
-                             */
-                            let attribute_name = attribute_definition[0].to_string();
-                            // Remove beginning and ending parenthesis of compound attribute
-                            let compound_attribute = attribute_definition[2]
-                                [1..attribute_definition[2].len() - 1]
-                                .to_string();
-                            let compound_attribute =
-                                compound_attribute.split("; ").collect::<Vec<&str>>();
+        :parameters:
+            - `attribute_type`: The type of the attribute
+            - `optional_data_size`: The optional data size for the attribute
+            - `from`: The referenced columns, in order
+            - `locale`: The locale used when the data can not be derived
+            - `statement_data`: The hashmap of generated data for the row
 
-                            let mut compound_attribute_data: Vec<String> = Vec::new();
+        :returns:
+            - `String`: The derived data for the attribute
+    */
+    let sources: Vec<&str> = from
+        .iter()
+        .map(|column| statement_data.get(column).map(|s| s.as_str()).unwrap_or("NULL"))
+        .collect();
 
-                            // Iterate over each attribute in the compound attribute, get type and size, then generate data
-                            for attribute in compound_attribute {
-                                let attribute =
-                                    attribute.split_whitespace().collect::<Vec<&str>>();
-                                let attribute_type =
-                                    attribute[1].trim().to_uppercase().to_string();
+    // Nothing to derive from, generate the data like any other column
+    if sources[0].eq_ignore_ascii_case("NULL") {
+        return get_random_data(attribute_type, optional_data_size, locale, statement_data);
+    }
 
-                                let optional_variable_size: Option<Vec<u16>> =
-                                    set_variable_size(&attribute_type);
+    let today = row_now().date();
 
-                                let generated_data: String = get_random_data(
-                                    &attribute_type,
-                                    optional_variable_size.clone(),
-                                    &statement_data,
-                                );
+    match attribute_type {
+        username_type if username_type.starts_with("USERNAME") => {
+            let mut username = get_handle(&[sources[0]], "user");
+            let suffix = get_number_suffix();
 
-                                compound_attribute_data.push(generated_data);
-                            }
+            if let Some(size) = optional_data_size {
+                let max_length = (size[0] as usize).saturating_sub(suffix.len());
+                username = username.chars().take(max_length).collect();
+            }
+            format!("{}{}", username, suffix)
+        }
+        "EMAIL" => {
+            // An email or username is used as is, a name gets a number suffix to keep it unique
+            let local_part = sources[0].split('@').next().unwrap();
+            let local_part = match local_part.contains(char::is_whitespace) {
+                true => format!("{}{}", get_handle(&[local_part], "user"), get_number_suffix()),
+                false => local_part.to_string(),
+            };
 
-                            // Create string of compound attribute data in form (data1, data2, data3, ...)
-                            let compound_attribute_data = compound_attribute_data.join(", ");
-                            statement_data.insert(attribute_name, compound_attribute_data);
-                        }
-                        false => {
-                            /*
-                             * Standard Attribute. Proceed As Before
-                             */
-                            let attribute_name = attribute_definition[1].to_string();
-                            let attribute_type =
-                                attribute_definition[2].to_uppercase().to_string();
+            let domain = match sources.get(1) {
+                Some(domain_source) if !domain_source.eq_ignore_ascii_case("NULL") => {
+                    get_company_domain(domain_source)
+                }
+                _ => get_email_domain(),
+            };
+            format!("{}@{}", local_part, domain).replace("'", "")
+        }
+        "DATE" | "TIMESTAMP" => {
+            // Born between (age + 1) years ago (exclusive) and age years ago (inclusive)
+            let age = sources[0].parse::<u32>().unwrap_or(0);
+            let latest = today
+                .checked_sub_months(chrono::Months::new(age * 12))
+                .unwrap_or(today);
+            let earliest = latest
+                .checked_sub_months(chrono::Months::new(12))
+                .unwrap_or(latest)
+                .succ_opt()
+                .unwrap_or(latest);
+            let date = earliest
+                + chrono::Duration::days(
+                    row_rng().gen_range(0..=(latest - earliest).num_days().max(0)),
+                );
 
-                            let optional_variable_size: Option<Vec<u16>> =
-                                set_variable_size(&attribute_type);
+            match attribute_type {
+                "DATE" => date.to_string(),
+                _ => format!("{} {}", date, get_random_data("TIME", None, locale, statement_data)),
+            }
+        }
+        "AGE" => {
+            let date_of_birth: String = sources[0].chars().take(10).collect();
+            match NaiveDate::parse_from_str(&date_of_birth, "%Y-%m-%d") {
+                Ok(date_of_birth) => today.years_since(date_of_birth).unwrap_or(0).to_string(),
+                Err(_) => get_random_data(attribute_type, optional_data_size, locale, statement_data),
+            }
+        }
+        _ => {
+            let mut copied = sources[0].to_string();
+            if let Some(size) = optional_data_size {
+                copied = copied.chars().take(size[0] as usize).collect();
+            }
+            copied
+        }
+    }
+}
 
-                            let mut generated_data: String = get_random_data(
-                                &attribute_type,
-                                optional_variable_size.clone(),
-                                &statement_data,
-                            );
+fn get_email_domain() -> String {
+    /*
+        * Picks a random email provider domain
 
-                            if unique_attribute_checker.contains_key(&attribute_name) {
-                                while (unique_attribute_checker[&attribute_name])
-                                    .contains(&generated_data)
-                                {
-                                    generated_data = get_random_data(
-                                        &attribute_type,
-                                        optional_variable_size.clone(),
-                                        &statement_data,
-                                    );
-                                }
-                            }
+        :returns:
+            - `String`: The domain, without the '@'
+    */
+    let domains = [
+        "outlook.com",
+        "gmail.com",
+        "pitt.edu",
+        "yahoo.com",
+        "proton.mail",
+        "pm.me",
+        "paranoid.email",
+    ];
+    domains.choose(&mut row_rng()).unwrap().to_string()
+}
 
-                            unique_attribute_checker
-                                .entry(attribute_name.clone())
-                                .or_insert(Vec::new())
-                                .push(generated_data.clone());
+fn get_company_name(locale: Locale, statement_data: &HashMap<String, String>) -> String {
+    /*
+        * Gets the name of the row's company set by set_row_groups
+        * If the row has no company (i.e data generated outside of a row) a new one is generated
 
-                            statement_data.insert(attribute_name, generated_data.clone());
+        :parameters:
+            - `locale`: The locale of the company, if a new one is generated
+            - `statement_data`: The hashmap of generated data for the row
 
-                            if pairwise_attribute {
-                                pair_list.push(generated_data);
-                            }
-                        }
-                    }
-                    4 => match isCompound {
-                        /*
-                            * Attribute Definition Is Of The Form:
-                            * [foreign key definition] [attribute name] [attribute type] [foreign table]
-                            * OR
-                            * [primary/unique key] [attribute name] [compound] [(compound attribute)]
-                            * Example: 'PK/FK userID INTEGER profile(userID)'
+        :returns:
+            - `String`: The company name
+    */
+    match statement_data.get(&format!("__company({}).name", locale.code())) {
+        Some(company_name) => company_name.to_string(),
+        None => fake_localized!(CompanyName, locale),
+    }
+}
 
-                            * Generate Data For Attribute
-                        */
-                        true => {
-                            /*
-                                * Keyed Compound Attribute.
-                                * Generate Data For Each Attribute In The Compound Attribute
-                                * Add to unique_attribute_checker
-                            */
-                            let comp_attr_name = attribute_definition[1].to_string();
-                            let comp_attr_compound = attribute_definition[3].to_string();
+fn get_company_domain(company_name: &str) -> String {
+    /*
+        * Builds a domain from a company name
+        * Example: 'Kuhn, Schmidt and Bode' -> kuhnschmidtbode.com
+        * A value that already looks like a domain (or an email) is used as is
 
-                            let comp_attr_compound =
-                                comp_attr_compound[1..comp_attr_compound.len() - 1].to_string();
+        :parameters:
+            - `company_name`: The company name or domain
 
-                            let comp_attr_compound =
-                                comp_attr_compound.split("; ").collect::<Vec<&str>>();
+        :returns:
+            - `String`: The domain of the company
+    */
+    let company_name = company_name.rsplit('@').next().unwrap().trim();
+    if company_name.contains('.') && !company_name.contains(char::is_whitespace) {
+        return company_name.trim_start_matches("www.").to_lowercase();
+    }
 
-                            loop {
-                                let mut compound_attribute_data: Vec<String> = Vec::new();
-                                // Iterate over each attribute in the compound attribute, get type and size, then generate data
-                                for attribute in comp_attr_compound.iter() {
-                                    let attribute =
-                                        attribute.split_whitespace().collect::<Vec<&str>>();
-                                    let attribute_type =
-                                        attribute[1].trim().to_uppercase().to_string();
+    let company_suffixes = ["and", "inc", "llc", "ltd", "group", "sons", "co"];
+    let words: Vec<&str> = company_name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty() && !company_suffixes.contains(&word.to_lowercase().as_str()))
+        .collect();
+    format!("{}.com", get_handle(&words, "company").to_lowercase())
+}
 
-                                    let optional_variable_size: Option<Vec<u16>> =
-                                        set_variable_size(&attribute_type);
+fn get_random_data(
+    attribute_type: &str,
+    optional_data_size: Option<Vec<u16>>,
+    locale: Locale,
+    statement_data: &HashMap<String, String>,
+) -> String {
+    /*
+        * Generates random data for the attribute type
+        * If the attribute type is a custom data type, the custom data type is generated
+        * If the attribute type is a default data type, the default data type is generated
 
-                                    let generated_data: String = get_random_data(
-                                        &attribute_type,
-                                        optional_variable_size.clone(),
-                                        &statement_data,
-                                    );
+        ** Uses the Faker library to generate data for default data types
+        ** Names, addresses, phone numbers and companies are generated in the given locale
 
-                                    compound_attribute_data.push(generated_data);
-                                }
+        :parameters:
+            - `attribute_type`: The type of the attribute
+            - `optional_data_size`: The optional data size for the attribute
+            - `locale`: The locale of the generated data
+            - `statement_data`: The hashmap of generated data for the table
 
-                                // Create string of compound attribute data in form (data1, data2, data3, ...)
-                                let compound_attribute_data =
-                                    compound_attribute_data.join(", ");
+        :returns:
+            - `String`: The generated data for the attribute
+    */
+    match attribute_type {
+        char_type if char_type.starts_with("CHAR") || char_type.starts_with("VARCHAR") => {
+            //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
+            let char_size = optional_data_size
+                .unwrap_or_else(|| vec![row_rng().gen_range(3..12)])
+                .first()
+                .unwrap()
+                .to_owned();
+            Faker
+                .fake_with_rng::<String, _>(&mut row_rng())
+                .chars()
+                .take(char_size as usize)
+                .collect::<String>()
+        }
+        decimal_type if decimal_type.starts_with("DECIMAL") => {
+            //Attempt to unwrap and get vec[0] and vec[1] of optional data-size
+            //Vec[0] is the number of digits before the decimal point
+            //Vec[1] is the number of digits after the decimal point
+            //If none, set random values of each
+            let unwrapped_decimal = optional_data_size.unwrap_or_else(|| {
+                vec![row_rng().gen_range(3..12), row_rng().gen_range(3..12)]
+            });
+            let digits_before_decimal = unwrapped_decimal.first().unwrap().to_owned();
+            let digits_after_decimal = unwrapped_decimal.get(1).unwrap().to_owned();
+            //Create decimal value from digits_before_decimal and digits_after_decimal
+            let decimal_value = format!(
+                "{}.{}",
+                row_rng().gen_range(0..10_u64.pow(digits_before_decimal as u32)),
+                row_rng().gen_range(0..10_u64.pow(digits_after_decimal as u32)),
+            );
+            decimal_value
+        }
+        money_type if money_type.starts_with("MONEY") => {
+            //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
+            let dollar_size = optional_data_size
+                .unwrap_or_else(|| vec![row_rng().gen_range(3..12)])
+                .first()
+                .unwrap()
+                .to_owned();
 
-                                // If primary or unique key, add to unique_attribute_checker
-                                if unique_attribute_checker.contains_key(&comp_attr_name) &&
-                                    unique_attribute_checker[&comp_attr_name].contains(&compound_attribute_data) {
-                                    continue;
-                                }
-
-                                // Add to unique_attribute_checker
-                                unique_attribute_checker
-                                    .entry(comp_attr_name.clone())
-                                    .or_insert(Vec::new())
-                                    .push(compound_attribute_data.clone());
-
-                                // Add to statement_data
-                                statement_data.insert(
-                                    comp_attr_name.clone(),
-                                    compound_attribute_data.clone(),
-                                );
+            // Generate a dollar amount between 3 figures and either dollar size or 12 figures
+            let dollar_amount = row_rng().gen_range(0..10i32.pow(dollar_size as u32));
+            let cents_amount = row_rng().gen_range(0..100);
+            // Create decimal value from dollar_amount and cents_amount
+            let decimal_value = format!("{}.{}", dollar_amount, cents_amount);
+            decimal_value
+        }
+        name_type if name_type.starts_with("NAME") => {
+            // Full name of the row's person, so it agrees with any FIRST_NAME / LAST_NAME / EMAIL columns
+            format!(
+                "{} {}",
+                get_person_field("first", locale, statement_data),
+                get_person_field("last", locale, statement_data)
+            )
+        }
+        "FIRST_NAME" => get_person_field("first", locale, statement_data),
+        "MIDDLE_NAME" => get_person_field("middle", locale, statement_data),
+        "MIDDLE_INITIAL" => get_person_field("middle", locale, statement_data)
+            .chars()
+            .take(1)
+            .collect::<String>(),
+        "LAST_NAME" => get_person_field("last", locale, statement_data),
+        "PREFIX" => get_person_field("prefix", locale, statement_data),
+        "SUFFIX" => get_person_field("suffix", locale, statement_data),
+        password_type if password_type.starts_with("PASSWORD") => Password(std::ops::Range {
+            start: 8,
+            end: (optional_data_size
+                .unwrap_or_else(|| vec![row_rng().gen_range(8..12)])
+                .first()
+                .unwrap()
+                .to_owned() as usize), //Attempt to unwrap and get vec[0] of optional data-size, if none, set random value
+        })
+            .fake_with_rng(&mut row_rng()),
+        username_type if username_type.starts_with("USERNAME") => {
+            let first_name = get_person_field("first", locale, statement_data);
+            let last_name = get_person_field("last", locale, statement_data);
+            let mut username = get_handle(&[&first_name, &last_name], "user");
 
-                                // If pairwise, add to pair_list
-                                if pairwise_attribute {
-                                    if pair_list.contains(&compound_attribute_data) {
-                                        continue;
-                                    }
-                                    pair_list.push(compound_attribute_data.clone());
-                                }
-                                break;
-                            }
-                        }
-                        false => {
-                            /*
-                                * Standard Foreign Key Attribute.
-                                * Generate Data For Attribute Based On Referenced Attribute
-                                * I.E. Use Previously Generated Data From Referenced Attribute
-                            */
-                            let (referenced_table, referenced_attribute) =
-                                get_references(&attribute_definition, 3);
-
-                            if get_referenced_attribute(
-                                key_dictionary.get(&referenced_table).unwrap(),
-                                &referenced_attribute.to_uppercase().to_string(),
-                            )
-                                .is_none()
-                            {
-                                println!(
-                                    "\nPROGRAM ERROR IN GENERATING DATA [Getting Referenced Attribute]"
-                                );
-                                std::process::exit(1);
-                            }
+            // The person is fixed for the row, so a number suffix is what lets unique usernames be regenerated
+            let suffix = get_number_suffix();
 
-                            loop {
-                                /*
-                                    * Reference does exist and is valid.
-                                    * Get a random reference for that attribute from the list
-                                 */
-                                let randomized_data = unique_attribute_checker
-                                    .get(&referenced_attribute.to_string())
-                                    .unwrap()
-                                    .choose(&mut thread_rng())
-                                    .unwrap()
-                                    .to_string();
+            //Check if optional data size is specified in vec[0], if so, truncate username to that size if it is larger
+            if let Some(size) = optional_data_size {
+                let max_length = (size[0] as usize).saturating_sub(suffix.len());
+                username = username.chars().take(max_length).collect();
+            }
 
-                                /*
-                                    * Check if the randomized data is the same as the previous data
-                                    * If it is, generate new data
-                                    * [key definition][0] [attribute name][1] [attribute type][2] [foreign table][3]
-                                 */
-                                if referenced_attributes
-                                    .contains_key(&referenced_attribute.to_string())
-                                {
-                                    if referenced_attributes
-                                        .get(&attribute_definition[3].to_string())
-                                        .unwrap()
-                                        .contains(&attribute_definition[1].to_string())
-                                    {
-                                        println!("Data being generated for same attribute.");
-                                        println!("Program error.");
-                                        println!("Exiting program.");
+            format!("{}{}", username, suffix).replace("'", "")
+        }
+        // Value types keep the case they were given in, see normalize_type
+        const_type if const_type.starts_with("CONST") => get_value_list(const_type)
+            .first()
+            .cloned()
+            .unwrap_or_default(),
+        oneof_type if oneof_type.starts_with("ONEOF") => get_value_list(oneof_type)
+            .choose(&mut row_rng())
+            .cloned()
+            .unwrap_or_default(),
+        "DEFAULT" => "DEFAULT".to_string(),
+        "INTEGER" => row_rng().gen_range(0..=i32::MAX).to_string(),
+        "BOOLEAN" => Faker.fake_with_rng::<bool, _>(&mut row_rng()).to_string(),
+        "DATE" => {
+            let year = row_rng().gen_range(1900..2021);
+            let month = row_rng().gen_range(1..13);
+            let day = row_rng().gen_range(1..29);
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .to_string()
+        }
+        "TIMESTAMP" => {
+            let year = row_rng().gen_range(1900..2021);
+            let month = row_rng().gen_range(1..13);
+            let day = row_rng().gen_range(1..29);
+            let hour = row_rng().gen_range(0..24);
+            let minute = row_rng().gen_range(0..60);
+            let second = row_rng().gen_range(0..60);
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+                chrono::NaiveTime::from_hms_opt(hour, minute, second).unwrap(),
+            )
+                .to_string()
+        }
+        "TIME" => {
+            let hour = row_rng().gen_range(0..24);
+            let minute = row_rng().gen_range(0..60);
+            let second = row_rng().gen_range(0..60);
+            chrono::NaiveTime::from_hms_opt(hour, minute, second)
+                .unwrap()
+                .to_string()
+        }
+        "GROUP" => ["Member", "Mod"]
+            .choose(&mut row_rng())
+            .unwrap()
+            .to_string(),
+        "EMAIL" => {
+            // Built from the row's person, so it agrees with the row's name columns
+            // Use EMAIL FROM(column, ...) to build it from specific columns instead
+            format!(
+                "{}{}@{}",
+                get_handle(
+                    &[
+                        &get_person_field("first", locale, statement_data),
+                        &get_person_field("last", locale, statement_data),
+                    ],
+                    "user"
+                ),
+                get_number_suffix(),
+                get_email_domain()
+            )
+        }
+        "DOMAIN" => get_company_domain(&get_company_name(locale, statement_data)),
+        "AGE" => row_rng().gen_range(18..=90).to_string(),
+        "STATE_US" => {
+            //Generate random state in US using faker
+            let state = StateName(EN).fake_with_rng::<String, _>(&mut row_rng());
+            state.replace("'", "")
+        }
+        "CITY_US" => {
+            let city = CityName(EN).fake_with_rng::<String, _>(&mut row_rng());
+            city.replace("'", "")
+        }
+        "CITY_SHORT" => {
+            let city_prefix = CityPrefix(EN).fake_with_rng::<String, _>(&mut row_rng());
+            city_prefix.replace("'", "")
+        }
+        "STREET_NAME_US" => {
+            let street_address = StreetName(EN).fake_with_rng::<String, _>(&mut row_rng());
+            street_address.replace("'", "")
+        }
+        "ZIP_US" => {
+            let zip = ZipCode(EN).fake_with_rng::<String, _>(&mut row_rng());
+            zip.replace("'", "")
+        }
+        "STATE" => match locale {
+            Locale::En => StateName(EN).fake_with_rng::<String, _>(&mut row_rng()).replace("'", ""),
+            // State, region or prefecture of a real city of the locale
+            _ => get_geo_records()[get_geo_index(locale)][1].replace("'", ""),
+        },
+        "ZIP" => {
+            // Post codes follow the locale's postal format (e.g. 12345 or 12345-6789 for en, 75001 for fr_FR)
+            locale
+                .postal_formats()
+                .choose(&mut row_rng())
+                .unwrap()
+                .chars()
+                .map(|c| match c {
+                    '#' => char::from(b'0' + row_rng().gen_range(0..10)),
+                    _ => c,
+                })
+                .collect::<String>()
+        }
+        geo_type if geo_type.starts_with("GEO_") => {
+            /*
+                * Every GEO_* column of the same group reads from the same reference record
+                * The record for each group is picked once per row by set_row_groups
+                * If no record was picked (i.e data generated outside of a row) a random one is used
+            */
+            let geo_index = statement_data
+                .get(&format!("__geo({})", get_geo_group(geo_type)))
+                .and_then(|index| index.parse::<usize>().ok())
+                .unwrap_or_else(|| get_geo_index(locale));
+            let geo_record = &get_geo_records()[geo_index];
+
+            let field = match geo_type.split('(').next().unwrap() {
+                "GEO_CITY" => 0,
+                "GEO_STATE" => 1,
+                "GEO_STATE_ABBR" => 2,
+                "GEO_ZIP" => 3,
+                "GEO_LAT" => 4,
+                "GEO_LONG" => 5,
+                "GEO_COUNTRY" => 6,
+                _ => panic!("Unknown Type In Data Generation! {}", attribute_type),
+            };
+            geo_record[field].replace("'", "")
+        }
+        "STREET_ADDRESS" => get_street_address(locale),
+        "FULL_ADDRESS" => match locale {
+            // One line mailing address: 123 Main Street Apt. 4, Springfield, IL 62704
+            Locale::En => {
+                let city = CityName(EN).fake_with_rng::<String, _>(&mut row_rng());
+                let state = StateAbbr(EN).fake_with_rng::<String, _>(&mut row_rng());
+                let zip = PostCode(EN).fake_with_rng::<String, _>(&mut row_rng());
+                format!("{}, {}, {} {}", get_street_address(locale), city, state, zip)
+                    .replace("'", "")
+            }
+            // Other locales put the post code before the city: 12 Rue Dupont, 75001 Paris
+            _ => {
+                let geo_record = &get_geo_records()[get_geo_index(locale)];
+                format!(
+                    "{}, {} {}",
+                    get_street_address(locale),
+                    geo_record[3],
+                    geo_record[0]
+                )
+                .replace("'", "")
+            }
+        },
+        "SSN" => {
+            // Generate random 9 digit number
+            let ssn = row_rng().gen_range(100_000_000..1_000_000_000);
+            ssn.to_string()
+        }
+        // Generate random phone number using faker
+        "PHONE" => fake_localized!(PhoneNumber, locale),
+        // Generate random country using faker
+        "COUNTRY" => fake_localized!(CountryName, locale),
+        "COMPANYNAME" => get_company_name(locale, statement_data),
+        // Generate random industry using faker
+        "INDUSTRY" => fake_localized!(Industry, locale),
+        // Generate random profession using faker
+        "PROFESSION" => fake_localized!(Profession, locale),
+        _ => {
+            panic!("Unknown Type In Data Generation! {}", attribute_type);
+        }
+    }
+}
 
-                                        std::process::exit(1);
-                                    } else {
-                                        if referenced_attributes
-                                            .get(&attribute_definition[3].to_string())
-                                            .unwrap()
-                                            .contains(&randomized_data)
-                                        {
-                                            continue;
-                                        }
-                                    }
-                                }
+fn get_street_address(locale: Locale) -> String {
+    /*
+        * Generates a street address with a building number and street name
+        * Roughly a third of en addresses also get a secondary unit (Apt. / Suite / Unit)
+        * Example: 4821 Kuhn Crossing Apt. 12 (en), 12 Rue Dupont (fr_FR), Hauptstraße 12 (de_DE)
 
-                                /*
-                                    * True if table is composite keyed
-                                    * Pair list can't have same data for keyed attributes if it does, regenerate data for current attribute
-                                */
-                                if pairwise_attribute {
-                                    if pair_list.contains(&randomized_data) {
-                                        continue;
-                                    }
-                                    pair_list.push(randomized_data.clone());
-                                } else {
-                                    //Not pairwise, check if data is unique for attribute or continue loop and generate new data for attribute
-                                    if attribute_definition[0].starts_with("PK")
-                                        || attribute_definition[0].starts_with("AK")
-                                    {
-                                        if unique_attribute_checker
-                                            .contains_key(&attribute_definition[1])
-                                            && unique_attribute_checker
-                                            [&attribute_definition[1]]
-                                            .contains(&randomized_data)
-                                        {
-                                            continue;
-                                        }
+        :parameters:
+            - `locale`: The locale of the street address
 
-                                        unique_attribute_checker
-                                            .entry(attribute_definition[1].to_string())
-                                            .or_insert(Vec::new())
-                                            .push(randomized_data.clone());
-                                    }
-                                }
+        :returns:
+            - `String`: The generated street address
+    */
+    let building_number = fake_localized!(BuildingNumber, locale);
+    let street_name = fake_localized!(StreetName, locale);
+    let mut street_address = match locale {
+        Locale::DeDe | Locale::ItIt | Locale::PtPt | Locale::PtBr => {
+            format!("{} {}", street_name, building_number)
+        }
+        _ => format!("{} {}", building_number, street_name),
+    };
 
-                                /*
-                                    * Add the attribute to the list of referenced attributes
-                                    * Add the data to the statement data
-                                    * Break out of the loop
-                                 */
-                                referenced_attributes
-                                    .entry(attribute_definition[3].to_string())
-                                    .or_insert(Vec::new())
-                                    .push(attribute_definition[1].to_string());
-
-                                statement_data.insert(
-                                    attribute_definition[1].to_string(),
-                                    randomized_data,
-                                );
-                                break;
-                            }
-                        }
-                    }
-                    5 => {
-                        /*
-                            * This only runs when its a compound attribute with a foreign key
-                            * Attribute Definition Is Of The Form:
-                            * [key definition][0] [attribute name][1] [compound][2] [(compound attribute)][3] [foreign table][4]
-                        */
-                        let (referenced_table, referenced_attribute) =
-                            get_references(&attribute_definition, 4);
+    if locale == Locale::En && row_rng().gen_bool(0.3) {
+        let unit_type = ["Apt.", "Suite", "Unit"].choose(&mut row_rng()).unwrap();
+        street_address += &format!(" {} {}", unit_type, row_rng().gen_range(1..1000));
+    }
 
-                        if get_referenced_attribute(
-                            key_dictionary.get(&referenced_table).unwrap(),
-                            &referenced_attribute.to_uppercase().to_string(),
-                        )
-                            .is_none()
-                        {
-                            println!(
-                                "\nPROGRAM ERROR IN GENERATING DATA [Getting Referenced Attribute]"
-                            );
-                            std::process::exit(1);
-                        }
+    street_address.replace("'", "")
+}
 
-                        loop {
-                            /*
-                                * Reference does exist and is valid.
-                                * Get a random reference for that attribute from the list
-                            */
-                            let randomized_data = unique_attribute_checker
-                                .get(&referenced_attribute.to_string())
-                                .unwrap()
-                                .choose(&mut thread_rng())
-                                .unwrap()
-                                .to_string();
+fn get_handle(words: &[&str], fallback: &str) -> String {
+    /*
+        * Joins words into a handle usable in usernames, emails and domains
+        * Accents are removed and anything that is not an ascii letter or digit is dropped
+        * Example: ['Zoé', 'Müller'] -> ZoeMuller
+        * Words without latin letters (i.e ja_JP or zh_CN names) give the fallback followed by a number
 
-                            if referenced_attributes
-                                .contains_key(&referenced_attribute.to_string())
-                            {
-                                if referenced_attributes
-                                    .get(&attribute_definition[4].to_string())
-                                    .unwrap()
-                                    .contains(&attribute_definition[1].to_string())
-                                {
-                                    println!("Data being generated for same attribute.");
-                                    println!("Program error.");
-                                    println!("Exiting program.");
+        :parameters:
+            - `words`: The words to join
+            - `fallback`: Used when nothing is left of the words
 
-                                    std::process::exit(1);
-                                } else {
-                                    if referenced_attributes
-                                        .get(&attribute_definition[4].to_string())
-                                        .unwrap()
-                                        .contains(&randomized_data)
-                                    {
-                                        continue;
-                                    }
-                                }
-                            }
+        :returns:
+            - `String`: The handle
+    */
+    let handle: String = words
+        .concat()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' => 'A',
+            'ç' => 'c',
+            'Ç' => 'C',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'È' | 'É' | 'Ê' | 'Ë' => 'E',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'Ì' | 'Í' | 'Î' | 'Ï' => 'I',
+            'ñ' => 'n',
+            'Ñ' => 'N',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+            'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' => 'O',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'Ù' | 'Ú' | 'Û' | 'Ü' => 'U',
+            'ý' | 'ÿ' => 'y',
+            'Ý' => 'Y',
+            'ß' => 's',
+            _ => c,
+        })
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
 
-                            /*
-                               * True if table is composite keyed
-                               * Pair list can't have same data for keyed attributes if it does, regenerate data for current attribute
-                            */
-                            if pairwise_attribute {
-                                if pair_list.contains(&randomized_data) {
-                                    continue;
-                                }
-                                pair_list.push(randomized_data.clone());
-                            } else {
-                                //Not pairwise, check if data is unique for attribute or continue loop and generate new data for attribute
-                                if attribute_definition[0].starts_with("PK")
-                                    || attribute_definition[0].starts_with("AK")
-                                {
-                                    if unique_attribute_checker
-                                        .contains_key(&attribute_definition[1])
-                                        && unique_attribute_checker[&attribute_definition[1]]
-                                        .contains(&randomized_data)
-                                    {
-                                        continue;
-                                    }
+    match handle.is_empty() {
+        true => format!("{}{}", fallback, row_rng().gen_range(1000..10000)),
+        false => handle,
+    }
+}
 
-                                    unique_attribute_checker
-                                        .entry(attribute_definition[1].to_string())
-                                        .or_insert(Vec::new())
-                                        .push(randomized_data.clone());
-                                }
-                            }
+fn get_number_suffix() -> String {
+    /*
+        * Returns a number to append to usernames and emails half of the time, otherwise an empty string
+        * Example: BobJohnson -> BobJohnson42
 
-                            /*
-                                * Add the attribute to the list of referenced attributes
-                                * Add the data to the statement data
-                                * Break out of the loop
-                             */
-                            referenced_attributes
-                                .entry(attribute_definition[4].to_string())
-                                .or_insert(Vec::new())
-                                .push(attribute_definition[1].to_string());
+        :returns:
+            - `String`: The number suffix
+    */
+    match row_rng().gen_bool(0.5) {
+        true => row_rng().gen_range(1..1000).to_string(),
+        false => String::new(),
+    }
+}
 
-                            statement_data.insert(
-                                attribute_definition[1].to_string(),
-                                randomized_data,
-                            );
-                            break;
+fn get_suffixed_data(attribute_type: &str, optional_data_size: Option<Vec<u16>>, data: &str) -> String {
+    /*
+        * Adds a number to a derived username or email that repeats the value of an earlier row
+        * Derived data only changes with its source columns, so a key column would repeat it on every retry
+        * Example: EMAIL FROM(username) 'bob@mail.com' -> 'bob417@mail.com'
 
-                        }
-                    }
-                    _ => {
-                        println!("\nPROGRAM ERROR IN GENERATING DATA [Attribute Definition Length]");
-                        std::process::exit(1);
-                    }
-                }
-            }
-            /*
-                * Check if the table is a pairwise table
-                * If it is, check if the pair has been generated before
-                * If it has, generate new data for the pair
-             */
-
-            if pairwise_table {
-                //If pairwise table, make sure composite key is not already existent
-                if !unique_pair_checker.contains_key(&table_name) {
-                    //True if table does not exist in unique_pair_checker
-                    unique_pair_checker
-                        .entry(table_name.clone())
-                        .or_insert(Vec::new())
-                        .push(pair_list.clone());
-                } else {
-                    // Composite table exists, check if pair has been generated before
-                    let (pair_changed, new_pair) = check_pair(
-                        &pair_list,
-                        &unique_pair_checker[&table_name],
-                        &attribute_order_keeper,
-                        &unique_attribute_checker,
-                        0,
-                    );
-                    if pair_changed {
-                        // Pair did exist and new data was generated in check_pair, change data in statement_data to match
-                        // Rewrite the data for the composite key attributes in the statement data
-                        let mut index = 0; // Used to match the new data with the correct attribute
-                        for attribute in attribute_order_keeper.iter() {
-                            let attribute_definitions: Vec<&str> =
-                                attribute.split_ascii_whitespace().collect();
-
-                            if primary_keys.contains(&attribute.to_string()) {
-                                // Attribute is part of composite key
-                                // Rewrite the data for the attribute
-                                statement_data.insert(
-                                    attribute_definitions[1].to_string(),
-                                    new_pair[index].to_string(),
-                                );
-                                index += 1;
-                            }
-                        }
-                    }
+        :parameters:
+            - `attribute_type`: The type of the attribute
+            - `optional_data_size`: The optional data size for the attribute
+            - `data`: The derived data that is already taken
 
-                    // Add the new pair to the unique_pair_checker
-                    unique_pair_checker
-                        .entry(table_name.clone())
-                        .or_insert(Vec::new())
-                        .push(new_pair.clone());
-                }
-            }
+        :returns:
+            - `String`: The derived data with a number added, other types are returned as is
+    */
+    let suffix = row_rng().gen_range(1..10000).to_string();
 
-            // Write the insert statement to the file. Executes each time, will modify later for improved time complexity
-            match write_to_file(
-                create_insert_statement(&table_name, &table_attributes, &statement_data),
-                path,
-            ) {
-                Ok(_) => {
-                    // Successfully wrote to file, increment statements_generated and print progress
-                    statements_generated += 1;
-                    print!(
-                        "\rGenerating SQL Inserts: {}/{} Created",
-                        statements_generated, iterations
-                    );
-                    stdout().flush().unwrap();
-                }
-                Err(_) => {
-                    // Usually implies bigger error, just exit the program
-                    println!("[!] Unable to write to file");
-                    std::process::exit(1);
-                }
+    match attribute_type {
+        username_type if username_type.starts_with("USERNAME") => {
+            let mut username = data.to_string();
+            if let Some(size) = optional_data_size {
+                let max_length = (size[0] as usize).saturating_sub(suffix.len());
+                username = username.chars().take(max_length).collect();
             }
+            format!("{}{}", username, suffix)
         }
+        "EMAIL" => match data.split_once('@') {
+            Some((local_part, domain)) => format!("{}{}@{}", local_part, suffix, domain),
+            None => format!("{}{}", data, suffix),
+        },
+        _ => data.to_string(),
     }
 }
 
-fn merge_compound(attribute_definition: &mut Vec<String>) -> bool {
+fn set_person(locale: Locale, statement_data: &mut HashMap<String, String>) {
     /*
-        * Merges compound attributes into one string
-        * Example: 'full_name, COMPOUND, (first_name, VARCHAR(20);, middle_initial CHAR(1):, last_name VARCHAR(20))'
-        * Becomes: 'full_name, COMPOUND, (first_name VARCHAR(20); middle_initial CHAR(1); last_name VARCHAR(20))'
+        * Generates the person used by the name types of the row being generated
+        * Stored in statement_data under '__person(locale).[first | middle | last | prefix | suffix]'
 
         :parameters:
-            - `attribute_definition`: The vector of attribute definitions
-
-        :returns:
-            - `bool`: True if the attribute definition is a compound attribute, False otherwise
+            - `locale`: The locale of the person
+            - `statement_data`: The hashmap of generated data for the row
     */
-    if attribute_definition.contains(&"compound".to_string())
-        || attribute_definition.contains(&"COMPOUND".to_string())
-    {
-        /*
-            * Join All Elements After The Element Containing "COMPOUND" Into One String Element
-            * "COMPOUND" Can Either Be At attribute_checker[1] Or attribute_checker[2]
-            * Attribute Checker Should Then Have Either 3 Elements Or 4 Elements
-            * 3 Elements -> [attribute_name, COMPOUND, compound_attributes]
-            * 4 Elements -> [key_definition, attribute_name, COMPOUND, compound_attributes]
-            * 5 Elements -> [key_definition, attribute_name, COMPOUND, compound_attributes, foreign_table]
-        */
-        let mut isReference: bool = false;
-        let mut foreign_table: String = String::new();
-        let index_of_compound = attribute_definition // Get index of element of string "compound" or "COMPOUND"
-            .iter()
-            .position(|x| x == "compound" || x == "COMPOUND")
-            .unwrap(); // Get index of "compound"
+    let person = [
+        ("first", fake_localized!(FirstName, locale)),
+        ("middle", fake_localized!(FirstName, locale)),
+        ("last", fake_localized!(LastName, locale)),
+        ("prefix", fake_localized!(Title, locale)),
+        ("suffix", fake_localized!(Suffix, locale)),
+    ];
 
-        let index_of_end_compound = if attribute_definition[0].contains("FK") {
-            /*
-                * Compound Attribute Is A Foreign Key
-                * Get Index Of Element Containing "))" (End Of Compound Attribute)
-                * This Is Because The Compound Attribute Will Contain The Foreign Table
-                * Keeps Foreign Table As Its Own Element
-            */
-            isReference = true;
-            attribute_definition // Get index of element of string ")"
-                .iter()
-                .position(|x| x.contains("))"))
-                .unwrap() // Get index of "))"
-        } else {
-            /*
-                * Not A Foreign Table
-                * Proceed As Before
-            */
-            attribute_definition.len()
-        };
+    for (field, value) in person {
+        statement_data.insert(format!("__person({}).{}", locale.code(), field), value);
+    }
+}
+
+fn get_person_field(
+    field: &str,
+    locale: Locale,
+    statement_data: &HashMap<String, String>,
+) -> String {
+    /*
+        * Gets a field of the row's person set by set_row_groups
+        * If the row has no person (i.e data generated outside of a row) a new one is generated
 
-        let _compound_attribute = attribute_definition[index_of_compound + 1..index_of_end_compound].join(" "); // Merge compound attr into one string
+        :parameters:
+            - `field`: The person field [first | middle | last | prefix | suffix]
+            - `locale`: The locale of the person, if a new one is generated
+            - `statement_data`: The hashmap of generated data for the row
 
-        /*
-            * Remove All Elements After The Element Containing "COMPOUND"
-            * Then Push The Compound Attribute To The Attribute Checker
-        */
-        attribute_definition.truncate(index_of_compound + 1); // Remove excess elements
-        attribute_definition.push(_compound_attribute); // Push new compound attribute to end
-        if isReference {
-            foreign_table = attribute_definition[index_of_end_compound + 1..].join("");
-            attribute_definition.push(foreign_table);
+        :returns:
+            - `String`: The value of the field
+    */
+    let key = format!("__person({}).{}", locale.code(), field);
+    match statement_data.get(&key) {
+        Some(value) => value.to_string(),
+        None => {
+            let mut person: HashMap<String, String> = HashMap::new();
+            set_person(locale, &mut person);
+            person[&key].to_string()
         }
-        return true;
     }
-    false
 }
 
-fn set_variable_size(attr_type: &str) -> Option<Vec<u16>> {
+fn get_geo_records() -> &'static Vec<Vec<&'static str>> {
     /*
-        Improved set_variable_size function
-        Still performs the same functionality as the old function
-        However this function is able to account for decimal values
-        i.e MONEY(10,2) -> 10 is the total number of digits, 2 is the number of digits after the decimal point
-        i.e DECIMAL(8,4) -> 8 is the total number of digits, 4 is the number of digits after the decimal point
+        * Parses the bundled geo dataset once and returns its records
+        * Record fields: [city, state, state_abbr, zip, latitude, longitude, country]
+
+        :returns:
+            - `&Vec<Vec<&str>>`: The geo records
+    */
+    static GEO_RECORDS: OnceLock<Vec<Vec<&'static str>>> = OnceLock::new();
+    GEO_RECORDS.get_or_init(|| {
+        GEO_DATA
+            .lines()
+            .skip(1) // Skip header
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.split(',').collect())
+            .collect()
+    })
+}
+
+fn get_geo_index(locale: Locale) -> usize {
+    /*
+        * Picks a random geo record in the country of the locale
+        * If the dataset has no city in that country, any record is picked
 
         :parameters:
-            - `attr_type`: The type of the attribute
+            - `locale`: The locale of the record
 
         :returns:
-            - `Option<Vec<u16>>`: The variable size for the attribute returned as a vec
-            -  Vec[0] -> Original variable size similar to old function, or number of digits before decimal point
-            -  Vec[1] -> Number of digits after decimal point (usually the only reason for index 1 to exist)
-            -  None -> No variable size for the attribute
+            - `usize`: The index of the record in get_geo_records
     */
-    let some_returned_value: Option<Vec<u16>> = match &attr_type {
-        //Check if s.contains("VARIABLE") [in cases of VARIABLE(n)] and s is not "VARIABLE"
-        s if s.contains("VARCHAR") && !(*s).eq("VARCHAR") => {
-            let variable_size_str = &attr_type[7..];
-            let variable_size_str: String =
-                variable_size_str[1..variable_size_str.len() - 1].to_string();
-            Some(vec![variable_size_str.parse().unwrap(), 0])
-        }
-        s if s.contains("CHAR") && !(*s).eq("CHAR") => {
-            let variable_size_str = &attr_type[4..];
-            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
-            Some(vec![variable_size_str.parse().unwrap(), 0])
-        }
-        s if (s.contains("PASSWORD") && !(*s).eq("PASSWORD"))
-            || (s.contains("USERNAME") && !(*s).eq("USERNAME")) =>
-            {
-                let variable_size_str = &attr_type[8..];
-                let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
-                Some(vec![variable_size_str.parse().unwrap(), 0])
-            }
-        s if s.contains("MONEY") && !(*s).eq("MONEY") => {
-            let variable_size_str = &attr_type[5..];
-            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
-            Some(vec![variable_size_str.parse().unwrap(), 0])
-        }
-        s if s.contains("DECIMAL") && !(*s).eq("DECIMAL") => {
-            let variable_size_str = &attr_type[7..];
-            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
-            let variable_size_str: Vec<&str> = variable_size_str.split(",").collect();
-            Some(vec![
-                variable_size_str[0].trim().parse().unwrap(),
-                variable_size_str[1].trim().parse().unwrap(),
-            ])
-        }
-        _ => None,
-    };
-    some_returned_value
+    let geo_records = get_geo_records();
+    let local_indexes: Vec<usize> = (0..geo_records.len())
+        .filter(|index| geo_records[*index][6] == locale.country())
+        .collect();
+
+    match local_indexes.choose(&mut row_rng()) {
+        Some(index) => *index,
+        None => row_rng().gen_range(0..geo_records.len()),
+    }
 }
 
-fn write_to_file(generated_statement: String, path: &String) -> io::Result<()> {
+fn parse_scale(scale: &str) -> Option<f64> {
     /*
-        * Writes the generated statement to the file specified by path variable
-        * If the file does not exist, it is created
-        * If the file does exist, the generated statement is appended to the file
+        * Parses the multiplier given to 'scale' or --scale
+        * Example: '10' -> 10, '0.5' -> 0.5, '0' -> None
 
         :parameters:
-            - `generated_statement`: The generated statement to write to the file
-            - `path`: The path to write the generated statement to
+            - `scale`: The scale as typed
 
         :returns:
-            - `io::Result<()>`: The result of writing to the file
+            - `Option<f64>`: The scale, None if it is not a number above 0
     */
+    scale
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|scale| scale.is_finite() && *scale > 0.0)
+}
 
-    let mut file = OpenOptions::new()
-        .append(true)
-        .create(true)
-        .open(&path)
-        .unwrap();
+fn parse_seed(seed: &str) -> Option<u64> {
+    /*
+        * Parses the seed given to 'seed' or --seed
+        * Example: '42' -> 42, '-1' -> None
 
-    writeln!(file, "{}", generated_statement)?;
+        :parameters:
+            - `seed`: The seed as typed
 
-    Ok(())
+        :returns:
+            - `Option<u64>`: The seed, None if it is not a whole number of 0 or more
+    */
+    seed.trim().parse::<u64>().ok()
 }
 
-fn main() {
+fn parse_batch(batch: &str) -> Option<usize> {
     /*
-     * Main function for the program
-     * Takes command to input sql tables with their attributes to create mock data
-     * Able to produce mock data for multiple tables at once up
-     * Capable of Reference Integrity and Unique/Keyed Attributes
-     */
+        * Parses the number of rows per insert statement given to 'batch' or --batch
+        * Example: '500' -> 500, '0' -> None
 
-    let mut custom_path: Option<String> = None;
-    let mut tables: Vec<String> = Vec::new();
-    let mut key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
-    let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
-    let mut total_iterations: u16 = 0;
+        :parameters:
+            - `batch`: The number of rows as typed
 
-    display_help(false);
+        :returns:
+            - `Option<usize>`: The number of rows, None if it is not a whole number above 0
+    */
+    batch.trim().parse::<usize>().ok().filter(|batch| *batch > 0)
+}
 
-    loop {
-        print!("[*] Manage Tables --> ");
-        let _ = stdout().flush();
-        let mut sql_input: String = String::new();
-        io::stdin()
-            .read_line(&mut sql_input)
-            .expect("Failed to read SQL command");
+fn parse_threads(threads: &str) -> Option<usize> {
+    /*
+        * Parses the number of threads given to 'threads' or --threads
+        * Example: '8' -> 8, '0' -> None
 
-        match sql_input.trim().to_lowercase().as_str() {
-            // There's at least one table to generate mock data for
-            "generate" | "gen" | "run" if !tables.is_empty() => {
-                if custom_path.is_none() {
-                    let user_folder = dirs::home_dir().unwrap();
-                    //Set default path to users documents directory and create/overwrite file named sample-data.sql
-                    let documents_dir = user_folder.join("Documents");
-                    let file_path = documents_dir.join("sample-data.sql");
-                    custom_path = Some(file_path.to_str().unwrap().to_string());
-                }
+        :parameters:
+            - `threads`: The number of threads as typed
 
-                let custom_path = custom_path.unwrap();
-                fs::write(&custom_path, "").expect("Unable to write to file");
+        :returns:
+            - `Option<usize>`: The number of threads, None if it is not a whole number above 0
+    */
+    threads.trim().parse::<usize>().ok().filter(|threads| *threads > 0)
+}
 
-                println!("[*] Generating Mock Data...");
-                generate_mock_data(
-                    &tables,
-                    &key_dictionary,
-                    &reference_dictionary,
-                    &custom_path,
-                    total_iterations,
-                );
+fn parse_dialect(name: &str) -> Option<Dialect> {
+    /*
+        * Gets the dialect of a database name, ignoring case
+        * Example: postgres | PostgreSQL -> Dialect::Postgres, mariadb -> Dialect::MySql
 
-                //Tell user where the file is located
-                println!("\n[*] Mock Data Generated In '{}'", custom_path);
-                break;
-            }
-            // No tables to generate mock data for
-            "generate" | "gen" | "run" => {
-                println!("[!] No Tables to Generate Mock Data For");
-                continue;
-            }
-            // Clear the terminal
-            "clear" => {
-                print!("{}[2J", 27 as char);
-                continue;
-            }
-            // Display entire help menu
-            "help" => {
-                display_help(true);
-                continue;
-            }
-            // Exit the program
-            "exit" | "quit" => {
-                println!(
-                    "All tables and mock data will be lost. Are you sure you want to exit? (y/n)"
-                );
-                let _ = stdout().flush();
-                let mut input = String::new();
-                io::stdin()
-                    .read_line(&mut input)
-                    .expect("Failed to read SQL command");
-                if input.trim().to_lowercase().as_str() == "y" {
-                    println!("[*] Exiting...");
-                    break;
-                } else {
-                    continue;
-                }
-            }
-            _ => {
-                sql_input = sql_input.replace("\\", "\\\\");
-                let command_tokens = shlex::split(&sql_input.trim()).unwrap_or_default();
-                let sql_command_list: Vec<&str> =
-                    command_tokens.iter().map(|x| x.as_str()).collect();
-                if sql_command_list.len() < 2 {
-                    println!("[!] Invalid SQL Command");
-                    continue;
-                }
+        :parameters:
+            - `name`: The name of the dialect or database
 
-                match sql_command_list[0].to_lowercase().as_str() {
-                    "path" => {
-                        /*
-                            * Get Path From sql_command_list[1]
-                            * Then Check If Directory And If The File Already Exists.
-                            * If It Does, Ask To Overwrite.
-                            * If Not, Create The File
-                        */
-                        let path: &str = sql_command_list[1].trim();
+        :returns:
+            - `Option<Dialect>`: The dialect, None if the name is not a supported dialect
+    */
+    match name.trim().to_lowercase().as_str() {
+        "postgresql" => Some(Dialect::Postgres),
+        "mariadb" => Some(Dialect::MySql),
+        "mssql" => Some(Dialect::SqlServer),
+        name => Dialect::ALL.into_iter().find(|dialect| dialect.name() == name),
+    }
+}
 
-                        if std::path::Path::new(&path).exists() {
-                            println!("[!] File already exists. Overwrite? (y/n)");
-                            let _ = stdout().flush();
-                            let mut input = String::new();
-                            io::stdin()
-                                .read_line(&mut input)
-                                .expect("Failed to read SQL command");
-                            if input.trim().to_lowercase().as_str() == "y" {
-                                fs::write(&path, "").expect("Unable to write to file");
-                                println!("[*] File overwritten");
-                            } else {
-                                println!("[*] File not overwritten");
-                                continue;
-                            }
-                        } else {
-                            fs::write(&path, "").expect("Unable to write to file");
-                            println!("[*] File created");
-                        }
-                        custom_path = Some(path.to_string());
-                    }
-                    "add" => {
-                        let mut valid: bool = true;
-                        let value = sql_command_list[1].parse::<u16>();
-                        match value {
-                            Ok(iterations) => {
-                                if iterations <= 0 {
-                                    println!("[!] Invalid Number of Iterations");
-                                    valid = false;
-                                    break;
-                                }
-                                total_iterations += iterations;
-                            }
-                            Err(_) => {
-                                println!("[!] Error With Iteration Input");
-                                valid = false;
-                                break;
-                            }
-                        };
+fn dialect_names() -> String {
+    // Names of the supported dialects for the messages of 'dialect' and --dialect
+    Dialect::ALL.iter().map(|dialect| dialect.name()).collect::<Vec<&str>>().join(", ")
+}
 
-                        let attributes = sql_command_list[3..sql_command_list.len()].join(" ");
-                        let attributes: String = attributes[1..attributes.len() - 1].to_string(); // Remove beginning and ending parentheses
-                        let attributes = attributes.split(",").collect::<Vec<&str>>();
+fn parse_columns(switch: &str) -> Option<bool> {
+    /*
+        * Parses the switch given to 'columns' or --columns
+        * Example: 'on' -> true, 'OFF' -> false, 'maybe' -> None
 
-                        // Go through each elemeent in the attributes vector and trim it before the for loop below
-                        let attributes = attributes
-                            .iter()
-                            .map(|elem| elem.trim())
-                            .collect::<Vec<&str>>();
+        :parameters:
+            - `switch`: The switch as typed
 
-                        println!("Attributes: {:?}", attributes);
+        :returns:
+            - `Option<bool>`: True if the inserts list their columns, None if it is neither on nor off
+    */
+    match switch.trim().to_lowercase().as_str() {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
 
-                        for attribute in attributes {
-                            let mut isCompound: bool = false;
+fn needs_quotes(identifier: &str) -> bool {
+    /*
+        * Checks if a table or column name has to be quoted to be read as a name
+        * Example: userID -> false, order -> true, 'first name' -> true, 2fa -> true
 
-                            let mut attribute_checker: Vec<String> = attribute
-                                .trim()
-                                .split_whitespace()
-                                .map(|s| s.to_owned())
-                                .collect();
+        :parameters:
+            - `identifier`: The table or column name
 
-                            isCompound = merge_compound(&mut attribute_checker);
+        :returns:
+            - `bool`: True if the name is a reserved word or is not made of letters, digits and '_'
+    */
+    !identifier.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        || !identifier.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        || RESERVED_WORDS.contains(&identifier.to_uppercase().as_str())
+}
 
-                            match attribute_checker.len() {
-                                1 => match attribute_checker[0].to_uppercase().as_str() {
-                                    "0" | "NULL" | "TRUE" | "FALSE" => {
-                                        println!("[!] Valid default value for attribute");
-                                    }
-                                    _ => {
-                                        println!("[!] Invalid default value for attribute");
-                                        valid = false;
-                                        break;
-                                    }
-                                },
-                                2 => {
-                                    if !check_data_type(
-                                        attribute_checker[1].to_uppercase().as_str(),
-                                    ) {
-                                        println!("[!] Invalid Data Type");
-                                        valid = false;
-                                        break;
-                                    }
-                                }
-                                3 => {
-                                    /*
-                                        * Examples Of Possible Attribute Definitions
-                                        * PK userID INTEGER
-                                        * AK email EMAIL
-                                        * full_name COMPOUND (FIRST_NAME VARCHAR(20), MIDDLE_INITIAL CHAR(1), LAST_NAME VARCHAR(20))
-                                    */
-                                    if !(check_data_type(
-                                        //Returns true if attribute is standard attribute
-                                        attribute_checker[2].to_uppercase().as_str(),
-                                    ) || check_data_type(
-                                        //Returns true if compound attribute
-                                        attribute_checker[1].to_uppercase().as_str(),
-                                    )) {
-                                        println!("[!] Invalid Data Type");
-                                        valid = false;
-                                        break;
-                                    }
-
-                                    if isCompound {
-                                        if !check_compound_attribute(2, &attribute_checker) {
-                                            /*
-                                                * Error In Compound Attribute
-                                                * Reason Printed In Function
-                                            */
-                                            valid = false;
-                                            break;
-                                        }
-                                    } else {
-                                        /*
-                                         * A compound attribute with a key definition will have a len of 4
-                                         * No possible way a compound attribute can have a key definition in this case
-                                         * Hence only standard attributes are checked
-                                         */
-                                        if !check_key_definition(
-                                            attribute_checker[0].to_uppercase().as_str(),
-                                        ) {
-                                            println!("[!] Invalid Key Definition");
-                                            valid = false;
-                                            break;
-                                        }
-                                        if attribute_checker[0].to_uppercase() == "PK"
-                                            || attribute_checker[0].to_uppercase() == "AK"
-                                        {
-                                            key_dictionary
-                                                .entry(sql_command_list[2].to_string())
-                                                .or_insert(Vec::new())
-                                                .push(attribute.to_uppercase().to_string());
-                                        } else {
-                                            println!("Possible Error In Key Definition For Compound Attribute");
-                                            println!("Key Definition is Valid. But possible foreign key without proper reference");
-                                            valid = false;
-                                            break;
-                                        }
-                                    }
-                                }
-                                4 => {
-                                    /*
-                                        * Example Of Compound Attribute With Indices
-                                        * [PK/AK][0] MBR[1] COMPOUND[2] (X_MIN; X_MAX; Y_MIN; Y_MAX)[3]
-                                    */
-                                    if !check_key_definition(
-                                        attribute_checker[0].to_uppercase().as_str(),
-                                    ) {
-                                        println!("[!] Invalid Key Definition");
-                                        valid = false;
-                                        break;
-                                    }
+fn parse_locale(code: &str) -> Option<Locale> {
+    /*
+        * Gets the locale of a locale code, ignoring case and accepting '-' for '_'
+        * Example: fr_FR | FR_FR | fr-fr -> Locale::FrFr
 
-                                    if !check_data_type(
-                                        attribute_checker[2].to_uppercase().as_str(),
-                                    ) {
-                                        println!("[!] Invalid Data Type");
-                                        valid = false;
-                                        break;
-                                    }
+        :parameters:
+            - `code`: The locale code
 
-                                    if isCompound {
-                                        if !check_compound_attribute(3, &attribute_checker) {
-                                            valid = false;
-                                            break;
-                                        }
+        :returns:
+            - `Option<Locale>`: The locale, None if the code is not a supported locale
+    */
+    let code = code.trim().replace('-', "_");
+    Locale::ALL
+        .into_iter()
+        .find(|locale| locale.code().eq_ignore_ascii_case(&code))
+}
 
-                                        // Is a keyed compound attribute (Either PK or AK) add compound attribute to key dict
-                                        if attribute_checker[0].to_uppercase() == "PK"
-                                            || attribute_checker[0].to_uppercase() == "AK"
-                                        {
-                                            key_dictionary
-                                                .entry(sql_command_list[2].to_string())
-                                                .or_insert(Vec::new())
-                                                .push(attribute.to_uppercase().to_string());
-                                        } else {
-                                            println!("Possible Error In Key Definition For Compound Attribute");
-                                            println!("Key Definition is Valid. But possible foreign key without proper reference");
-                                            valid = false;
-                                            break;
-                                        }
-                                    } else {
-                                        // Just a foreign key reference handle as before
-                                        let (referenced_table, referenced_attribute_name) =
-                                            get_references(&attribute_checker, 3);
+fn split_locale(attribute_type: &str) -> (String, Option<Locale>) {
+    /*
+        * Splits the locale suffix from a type
+        * Example: NAME@fr_FR -> (NAME, Some(Locale::FrFr)), NAME -> (NAME, None)
+        * A suffix that is not a supported locale is left on the type
 
-                                        let referenced_attribute = get_referenced_attribute(
-                                            &key_dictionary
-                                                .get(&referenced_table.to_string())
-                                                .unwrap_or(&vec![]),
-                                            &referenced_attribute_name.to_uppercase().to_string(),
-                                        );
+        :parameters:
+            - `attribute_type`: The type, with or without a locale suffix
 
-                                        match referenced_attribute {
-                                            Some(_) => {
-                                                if attribute_checker[0]
-                                                    .to_uppercase()
-                                                    .contains("PK")
-                                                    || attribute_checker[0]
-                                                    .to_uppercase()
-                                                    .contains("AK")
-                                                {
-                                                    key_dictionary
-                                                        .entry(sql_command_list[2].to_string())
-                                                        .or_insert(Vec::new())
-                                                        .push(attribute.to_string());
-                                                }
-                                                reference_dictionary
-                                                    .entry(sql_command_list[2].to_string())
-                                                    .or_insert(Vec::new())
-                                                    .push(HashMap::from([(
-                                                        referenced_table.to_string(),
-                                                        referenced_attribute_name.to_string(),
-                                                    )]));
-                                            }
-                                            None => {
-                                                println!("[!] Invalid Reference");
-                                                valid = false;
-                                                break;
-                                            }
-                                        }
-                                    }
-                                }
-                                5 => {
-                                    /*
-                                     * This will ONLY run with a foreign key compound attribute
-                                     * FK full_name COMPOUND (FIRST_NAME VARCHAR(20), MIDDLE_INITIAL CHAR(1), LAST_NAME VARCHAR(20)) profile(full_name)
-                                     */
-                                    if !check_key_definition(
-                                        attribute_checker[0].to_uppercase().as_str(),
-                                    ) {
-                                        println!("[!] Invalid Key Definition");
-                                        valid = false;
-                                        break;
-                                    }
+        :returns:
+            - `String`: The type without the locale suffix
+            - `Option<Locale>`: The locale of the suffix
+    */
+    match attribute_type.rsplit_once('@') {
+        Some((base_type, code)) => match parse_locale(code) {
+            Some(locale) => (base_type.to_string(), Some(locale)),
+            None => (attribute_type.to_string(), None),
+        },
+        None => (attribute_type.to_string(), None),
+    }
+}
 
-                                    if !check_data_type(
-                                        attribute_checker[2].to_uppercase().as_str(),
-                                    ) {
-                                        println!("[!] Invalid Data Type");
-                                        valid = false;
-                                        break;
-                                    }
+fn get_geo_group(attribute_type: &str) -> String {
+    /*
+        * Gets the group name of a GEO_* type
+        * Example: GEO_CITY(billing) -> billing, GEO_CITY -> ''
+        * Columns with the same group name share one geo record per row
 
-                                    if !check_compound_attribute(3, &attribute_checker) {
-                                        valid = false;
-                                        break;
-                                    }
+        :parameters:
+            - `attribute_type`: The GEO_* type
 
-                                    // Compound Attribute With Foreign Key Reference
-                                    let (referenced_table, referenced_attribute_name) =
-                                        get_references(&attribute_checker, 4);
+        :returns:
+            - `String`: The group name
+    */
+    match attribute_type.split_once('(') {
+        Some((_, group)) => group.split(')').next().unwrap().trim().to_uppercase(),
+        None => String::new(),
+    }
+}
 
-                                    let referenced_attribute = get_referenced_attribute(
-                                        &key_dictionary
-                                            .get(&referenced_table.to_string())
-                                            .unwrap_or(&vec![]),
-                                        &referenced_attribute_name.to_uppercase().to_string(),
-                                    );
+fn set_row_groups(
+    table_attributes: &[String],
+    default_locale: Locale,
+    statement_data: &mut HashMap<String, String>,
+) {
+    /*
+        * Picks the shared records used by grouped types for the row being generated
+        * Each GEO_* group gets one record index stored in statement_data under '__geo(group)'
+        * Name types (NAME, FIRST_NAME, LAST_NAME, ...) share one person, see set_person
+        * COMPANYNAME and DOMAIN share one company stored under '__company(locale).name'
+        * Compound attributes are searched as well, so GEO_* types can be used inside them
+        * The person and company are per locale, so NAME@de_DE next to a NAME column is another person
+        * Each GEO_* group is picked in the locale of its first column with a suffix (i.e GEO_CITY@fr_FR)
 
-                                    match referenced_attribute {
-                                        Some(_) => {
-                                            if attribute_checker[0].to_uppercase().contains("PK")
-                                                || attribute_checker[0]
-                                                .to_uppercase()
-                                                .contains("AK")
-                                            {
-                                                key_dictionary
-                                                    .entry(sql_command_list[2].to_string())
-                                                    .or_insert(Vec::new())
-                                                    .push(attribute.to_string());
-                                            }
-                                            reference_dictionary
-                                                .entry(sql_command_list[2].to_string())
-                                                .or_insert(Vec::new())
-                                                .push(HashMap::from([(
-                                                    referenced_table.to_string(),
-                                                    referenced_attribute_name.to_string(),
-                                                )]));
-                                        }
-                                        None => {
-                                            println!("[!] Invalid Reference");
-                                            valid = false;
-                                            break;
-                                        }
-                                    }
-                                }
-                                _ => {
-                                    println!("Error In Attributes For The Table: {}...Attribute: {} Is Not Formatted Properly", sql_command_list[2], attribute);
-                                    valid = false;
-                                    break;
-                                }
-                            }
-                        }
-                        if valid {
-                            let table_string =
-                                sql_command_list[1..sql_command_list.len()].join(" ");
-                            tables.push(table_string);
-                            println!(
-                                "[*] {} Insert Statements Added For {}",
-                                sql_command_list[1], sql_command_list[2]
-                            );
-                        } else {
-                            total_iterations -= sql_command_list[1].parse::<u16>().unwrap();
-                            continue;
-                        }
-                    }
-                    "remove" | "rm" | "del" => {
-                        let table_to_delete = tables
-                            .iter()
-                            .find(|elem| {
-                                elem.split_whitespace().nth(1) == Some(sql_command_list[1])
-                            })
-                            .cloned();
-                        if let Some(table) = table_to_delete {
-                            let num_statements = table.split_whitespace().nth(0).unwrap();
-                            total_iterations -= num_statements.parse::<u16>().unwrap();
-                            tables.retain(|elem| elem != &table);
-                        } else {
-                            println!("[!] Table Not Found");
-                        }
-                    }
-                    "modify" | "mod" => {
-                        let _table_to_modify = tables
-                            .iter()
-                            .find(|elem| {
-                                elem.split_whitespace().nth(1) == Some(sql_command_list[1])
-                            })
-                            .cloned();
-                        // Find attribute name at sql_command_list[2] and replace the entire attribute with sql_command_list[3..sql_command_list.len()]
-                        // TODO
-                    }
-                    "show" => {
-                        // If len == 3 Then A Specific Table Has Been Given To Pull Data From
-                        // Else
-                        let specifier = if sql_command_list.len() == 3 {
-                            Some(sql_command_list[2].to_lowercase())
-                        } else {
-                            None
-                        };
+        :parameters:
+            - `table_attributes`: The vector of table attributes
+            - `default_locale`: The locale of columns without a locale suffix
+            - `statement_data`: The hashmap of generated data for the row
+    */
+    let person_types = [
+        "NAME",
+        "FIRST_NAME",
+        "MIDDLE_NAME",
+        "MIDDLE_INITIAL",
+        "LAST_NAME",
+        "PREFIX",
+        "SUFFIX",
+        "EMAIL",
+        "USERNAME",
+    ];
 
-                        match sql_command_list[1].to_lowercase().as_str() {
-                            // Show Every Table The Program Will Generate Inserts For [Unless Given A Specific Table]
-                            "inserts" => {
+    // Each attribute (or attribute of a compound) is searched on its own so it uses its own locale
+    for attribute in table_attributes.iter().flat_map(|attribute| attribute.split(';')) {
+        let tokens: Vec<(String, Option<Locale>)> = attribute
+            .split_whitespace()
+            .map(|token| {
+                // Drop the parenthesis opening or closing a compound, not the ones of a type like VARCHAR(20)
+                let mut token = token.trim_start_matches('(');
+                if token.matches(')').count() > token.matches('(').count() {
+                    token = &token[..token.len() - 1];
+                }
+                split_locale(&token.to_uppercase())
+            })
+            .collect();
+        let locale = tokens
+            .iter()
+            .find_map(|(_, locale)| *locale)
+            .unwrap_or(default_locale);
+
+        for (token, _) in &tokens {
+            // One person per row and locale, shared by every name type (and EMAIL / USERNAME)
+            let base_type = token.split(['(', ')']).next().unwrap();
+            if person_types.contains(&base_type)
+                && !statement_data.contains_key(&format!("__person({}).first", locale.code()))
+            {
+                set_person(locale, statement_data);
+            }
+
+            // One company per row and locale, shared by COMPANYNAME and DOMAIN
+            if (base_type == "COMPANYNAME" || base_type == "DOMAIN")
+                && !statement_data.contains_key(&format!("__company({}).name", locale.code()))
+            {
+                let company_name = get_company_name(locale, statement_data);
+                statement_data.insert(format!("__company({}).name", locale.code()), company_name);
+            }
+
+            if !token.starts_with("GEO_") {
+                continue;
+            }
+
+            statement_data
+                .entry(format!("__geo({})", get_geo_group(token)))
+                .or_insert_with(|| get_geo_index(locale).to_string());
+        }
+    }
+}
+
+fn redraw_row_groups(attribute: &str, default_locale: Locale, statement_data: &mut HashMap<String, String>) {
+    /*
+        * Picks new shared records for the grouped types of one attribute, keeping the row's other records
+        * A key attribute reading the row's person, company or GEO_* record would repeat its value on every retry
+        * Example: AK first FIRST_NAME repeating 'Ava' -> the row gets a new person, so FIRST_NAME gives a new name
+
+        :parameters:
+            - `attribute`: The attribute whose value has to be generated again
+            - `default_locale`: The locale of columns without a locale suffix
+            - `statement_data`: The hashmap of generated data for the row
+    */
+    let mut records: HashMap<String, String> = HashMap::new();
+    set_row_groups(&[attribute.to_string()], default_locale, &mut records);
+    statement_data.extend(records);
+}
+
+fn get_references(attribute_definition: &[String], index: usize) -> (String, String) {
+    /*
+        * Gets the referenced table and attribute from the attribute definition
+        * Example: PK userID INTEGER profile(userID)
+        * Referenced Table: profile
+        * Referenced Attribute: userID
+
+        :parameters:
+            - `attribute_definition`: The vector of attribute definitions
+            - `index`: The index of the attribute definition to get the referenced table and attribute from
+
+        :returns:
+            - `String`: The referenced table
+            - `String`: The referenced attribute
+    */
+    let referenced_table = attribute_definition[index]
+        .split('(')
+        .next()
+        .unwrap()
+        .to_string();
+
+    let referenced_attribute = attribute_definition[index]
+        .split('(')
+        .nth(1)
+        .unwrap()
+        .replace(")", "");
+
+    (referenced_table, referenced_attribute)
+}
+
+fn get_referenced_attribute(
+    attribute_list: &Vec<String>,
+    referenced_attribute: &str,
+) -> Option<String> {
+    /*
+        *Returns the table attribute that is referenced by the foreign key
+        *Returns None if the referenced attribute is not found
+
+        :parameters:
+            - `attribute_list`: The list of attributes in the table
+            - `referenced_attribute`: The referenced attribute to find
+
+        :returns:
+            - `Option<String>`: The referenced attribute or None
+    */
+
+    if !attribute_list.is_empty() {
+        for attribute in attribute_list {
+            if attribute.split_whitespace().nth(1) == Some(referenced_attribute) {
+                return Some(attribute.to_string());
+            }
+        }
+    }
+    None
+}
+
+fn parse_row_count(row_count: &str) -> Option<RowCount> {
+    /*
+        * Parses the number of rows given to add
+        * Example: '100' -> Absolute(100), '3x' -> PerParent(3, None), '0-20x:profile' -> PerParentRange(0, 20, profile)
+
+        :parameters:
+            - `row_count`: The row count of the add command
+
+        :returns:
+            - `Option<RowCount>`: The row count, None if it can not be read
+    */
+    let (count, parent) = match row_count.split_once(':') {
+        Some((count, parent)) if !parent.is_empty() => (count, Some(parent.to_string())),
+        Some(_) => return None,
+        None => (row_count, None),
+    };
+
+    let Some(multiplier) = count.strip_suffix(['x', 'X']) else {
+        return match parent {
+            Some(_) => None,
+            None => count.parse::<u64>().ok().map(RowCount::Absolute),
+        };
+    };
+    match multiplier.split_once('-') {
+        Some((low, high)) => match (low.parse::<u64>(), high.parse::<u64>()) {
+            (Ok(low), Ok(high)) if low <= high => Some(RowCount::PerParentRange(low, high, parent)),
+            _ => None,
+        },
+        None => match multiplier.parse::<f64>() {
+            Ok(multiplier) if multiplier.is_finite() && multiplier >= 0.0 => {
+                Some(RowCount::PerParent(multiplier, parent))
+            }
+            _ => None,
+        },
+    }
+}
+
+fn get_parent_table(
+    table_name: &str,
+    row_count: &RowCount,
+    reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
+) -> Option<String> {
+    /*
+        * Gets the table a relative row count is taken from
+        * Example: 'add 3x post (PK postID INTEGER, FK userID INTEGER profile(userID))' -> profile
+
+        :parameters:
+            - `table_name`: The name of the table with the row count
+            - `row_count`: The row count of the table
+            - `reference_dictionary`: The hashmap of references for each table
+
+        :returns:
+            - `Option<String>`: The named parent, else the first referenced table. None for absolute row counts
+    */
+    let parent = match row_count {
+        RowCount::Absolute(_) => return None,
+        RowCount::PerParent(_, parent) | RowCount::PerParentRange(_, _, parent) => parent,
+    };
+    parent.clone().or_else(|| {
+        reference_dictionary
+            .get(table_name)?
+            .first()?
+            .keys()
+            .next()
+            .cloned()
+    })
+}
+
+fn get_row_counts(
+    tables: &[String],
+    reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
+    scale: f64,
+) -> Vec<u64> {
+    /*
+        * Works out the number of rows of every table, in order. Parent tables are always added before their children
+        * A range draws the rows of every parent row (i.e 2-5x over 3 profiles -> 3 + 5 + 2 -> 10 rows)
+        * Row counts added as a number are multiplied by the scale, relative ones follow their parent
+
+        :parameters:
+            - `tables`: The vector of tables to generate mock data for
+            - `reference_dictionary`: The hashmap of references for each table
+            - `scale`: The multiplier of the row counts added as a number (at least 1 row is kept)
+
+        :returns:
+            - `Vec<u64>`: The number of rows of each table
+    */
+    let mut table_rows: HashMap<String, u64> = HashMap::new();
+    let mut row_counts: Vec<u64> = Vec::new();
+
+    for table in tables {
+        let table: Vec<&str> = table.split_whitespace().collect();
+        let row_count = parse_row_count(table[0]).unwrap();
+        let parent_rows = get_parent_table(table[1], &row_count, reference_dictionary)
+            .and_then(|parent| table_rows.get(&parent).copied())
+            .unwrap_or(0);
+
+        let rows = match row_count {
+            // Unscaled counts are kept as they are, past 2^53 rows an f64 would round them
+            RowCount::Absolute(rows) if scale == 1.0 => rows,
+            RowCount::Absolute(rows) => ((rows as f64 * scale).round() as u64).max(1),
+            RowCount::PerParent(multiplier, _) => (multiplier * parent_rows as f64).round() as u64,
+            RowCount::PerParentRange(low, high, _) => {
+                (0..parent_rows).map(|_| row_rng().gen_range(low..=high)).sum()
+            }
+        };
+        table_rows.insert(table[1].to_string(), rows);
+        row_counts.push(rows);
+    }
+    row_counts
+}
+
+fn get_value_space(attribute_type: &str, optional_data_size: Option<Vec<u16>>, locale: Locale) -> Option<u64> {
+    /*
+        * Estimates how many different values a type can generate
+        * Example: BOOLEAN -> 2, CHAR(1) -> 62, ONEOF(a, b, c) -> 3, STATE -> 50, EMAIL -> None
+        * STATE, PREFIX, SUFFIX and GEO_* count the values of the locale's lists and cities
+
+        :parameters:
+            - `attribute_type`: The type of the attribute
+            - `optional_data_size`: The optional data size for the attribute
+            - `locale`: The locale the values are generated in
+
+        :returns:
+            - `Option<u64>`: The number of values, None when it is too large to run out of
+    */
+    let size = |index: usize| optional_data_size.as_ref().and_then(|size| size.get(index).copied());
+    match attribute_type {
+        "BOOLEAN" | "GROUP" => Some(2),
+        "INTEGER" => Some(i32::MAX as u64 + 1),
+        "AGE" => Some(73),
+        "MIDDLE_INITIAL" => Some(26),
+        "DATE" => Some(121 * 12 * 28),
+        "TIME" => Some(24 * 60 * 60),
+        "DEFAULT" => Some(1),
+        const_type if const_type.starts_with("CONST") => Some(1),
+        oneof_type if oneof_type.starts_with("ONEOF") => {
+            let mut values = get_value_list(oneof_type);
+            values.sort();
+            values.dedup();
+            Some(values.len() as u64)
+        }
+        // Letters and digits
+        char_type if char_type.starts_with("CHAR") || char_type.starts_with("VARCHAR") => {
+            size(0).map(|length| 62_u64.saturating_pow(length as u32))
+        }
+        money_type if money_type.starts_with("MONEY") => {
+            size(0).map(|digits| 10_u64.saturating_pow(digits as u32).saturating_mul(100))
+        }
+        decimal_type if decimal_type.starts_with("DECIMAL") => match (size(0), size(1)) {
+            (Some(before), Some(after)) => Some(10_u64.saturating_pow(before as u32 + after as u32)),
+            _ => None,
+        },
+        "PREFIX" => Some(locale_data!(NAME_TITLE, locale).len() as u64),
+        "SUFFIX" => Some(locale_data!(NAME_SUFFIX, locale).len() as u64),
+        "STATE" if locale == Locale::En => Some(locale_data!(ADDRESS_STATE, locale).len() as u64),
+        "STATE" => get_geo_space(1, locale),
+        geo_type if geo_type.starts_with("GEO_") => match geo_type.split('(').next().unwrap() {
+            "GEO_CITY" => get_geo_space(0, locale),
+            "GEO_STATE" => get_geo_space(1, locale),
+            "GEO_STATE_ABBR" => get_geo_space(2, locale),
+            "GEO_ZIP" => get_geo_space(3, locale),
+            "GEO_LAT" => get_geo_space(4, locale),
+            "GEO_LONG" => get_geo_space(5, locale),
+            "GEO_COUNTRY" => get_geo_space(6, locale),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn get_geo_space(field: usize, locale: Locale) -> Option<u64> {
+    /*
+        * Counts the different values of one field of the geo records a locale picks from, see get_geo_index
+        * Example: GEO_CITY in en -> the number of US cities in the dataset
+
+        :parameters:
+            - `field`: The index of the field in a geo record, see get_geo_records
+            - `locale`: The locale the records are picked for
+
+        :returns:
+            - `Option<u64>`: The number of different values
+    */
+    let geo_records = get_geo_records();
+    let local_records: Vec<&Vec<&str>> = geo_records.iter().filter(|record| record[6] == locale.country()).collect();
+    let records = match local_records.is_empty() {
+        true => geo_records.iter().collect(),
+        false => local_records,
+    };
+    Some(records.iter().map(|record| record[field]).collect::<HashSet<&str>>().len() as u64)
+}
+
+fn get_column_space(attribute_type: &str, options: &ColumnOptions, locale: Locale) -> Option<u64> {
+    /*
+        * Estimates how many different values a column can generate with its options
+        * Values allowed by a CHECK, the bounds of a CHECK on INTEGER / AGE columns and bounded DIST(...) narrow the type
+        * Example: INTEGER with CHECK (level BETWEEN 1 AND 5) -> 5, INTEGER DIST(zipf, 20) -> 20, CHAR(1) -> 62
+
+        :parameters:
+            - `attribute_type`: The type of the attribute
+            - `options`: The options of the attribute, with the bounds and values of its CHECK constraints
+            - `locale`: The locale the values are generated in
+
+        :returns:
+            - `Option<u64>`: The number of values, None when it is too large to run out of
+    */
+    let type_space = get_value_space(attribute_type, set_variable_size(attribute_type), locale);
+    let narrowest = |space: u64| Some(type_space.map_or(space, |type_space| type_space.min(space)));
+
+    if !options.allowed_values.is_empty() {
+        return narrowest(options.allowed_values.len() as u64);
+    }
+    if matches!(attribute_type, "INTEGER" | "AGE") && (options.minimum.is_some() || options.maximum.is_some()) {
+        let low = options.minimum.map_or(f64::MIN, |(low, inclusive)| match inclusive {
+            true => low.ceil(),
+            false => low.floor() + 1.0,
+        });
+        let high = options.maximum.map_or(f64::MAX, |(high, inclusive)| match inclusive {
+            true => high.floor(),
+            false => high.ceil() - 1.0,
+        });
+        return narrowest((high - low + 1.0).clamp(0.0, u64::MAX as f64) as u64);
+    }
+    let whole_numbers = ["INTEGER", "DATE"].contains(&attribute_type);
+    match &options.distribution {
+        Some(Ok(ValueDistribution::Zipf(count, _, _))) if whole_numbers => narrowest(*count),
+        Some(Ok(ValueDistribution::Histogram(buckets))) if whole_numbers => narrowest(
+            buckets
+                .iter()
+                .map(|(low, high, _)| (high.floor() - low.ceil() + 1.0).max(0.0) as u64)
+                .sum(),
+        ),
+        _ => type_space,
+    }
+}
+
+fn check_key_spaces(
+    tables: &[String],
+    row_counts: &[u64],
+    reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
+    locale: Locale,
+) -> Result<(), Vec<String>> {
+    /*
+        * Checks every table can be generated before anything is written, instead of looping forever while generating
+        * Every unique key can get as many different values as its table has rows (see get_column_space)
+        * Keys referencing another table have as many values as that table has rows
+        * Composite keys (several PK attributes) have the combinations of their attributes, by default a pair of
+        * the same referenced attribute can not repeat a value or be reversed: n referenced rows -> n(n - 1) / 2 pairs
+        * PAIRS (directed) -> n(n - 1) pairs, PAIRS (self) -> n(n + 1) / 2 pairs, PAIRS (directed, self) -> n * n pairs
+        * UNIQUE (...) constraints have the combinations of their attributes, unless one of them may be NULL
+        * Foreign keys need rows in their parent, and a FANOUT(...) must fit the rows of both tables
+        * Only an estimate of the types (see get_value_space), keys that can not run out are skipped
+
+        :parameters:
+            - `tables`: The vector of tables to generate mock data for
+            - `row_counts`: The number of rows of each table, see get_row_counts
+            - `reference_dictionary`: The hashmap of references for each table
+            - `locale`: The locale of columns without one of their own
+
+        :returns:
+            - `Result<(), Vec<String>>`: Every reason the rows can not be generated, if any
+    */
+    let table_rows: HashMap<&str, u64> = tables
+        .iter()
+        .zip(row_counts)
+        .map(|(table, rows)| (table.split_whitespace().nth(1).unwrap(), *rows))
+        .collect();
+
+    let mut problems: Vec<String> = Vec::new();
+    for (table, rows) in tables.iter().zip(row_counts) {
+        let table_name = table.split_whitespace().nth(1).unwrap();
+        let attributes = &table[table.find('(').unwrap() + 1..table.len() - 1];
+        let is_pairwise_table = reference_dictionary.contains_key(table_name)
+            && split_attributes(attributes)
+                .iter()
+                .filter(|attribute| attribute.to_uppercase().starts_with("PK"))
+                .count()
+                > 1;
+        if *rows == 0 {
+            continue;
+        }
+
+        // Bounds and values the CHECK constraints give the attributes
+        let check_ranges = get_check_ranges(
+            &split_attributes(attributes)
+                .iter()
+                .filter_map(|attribute| parse_expression(get_table_check(attribute)?).ok())
+                .collect::<Vec<Expression>>(),
+        );
+
+        // Values of each composite key attribute, with the referenced attribute they come from
+        let mut composite_spaces: Vec<(Option<u64>, String)> = Vec::new();
+        let pair_rules = split_attributes(attributes)
+            .iter()
+            .find_map(|attribute| parse_pair_rules(get_table_pairs(attribute)?).ok())
+            .unwrap_or_default();
+        // Values of every attribute for the UNIQUE (...) constraints, None if it can not run out (or may be NULL)
+        let mut attribute_spaces: HashMap<String, Option<u64>> = HashMap::new();
+        // Composite reference of an attribute, its attributes together take only the parent rows
+        let mut reference_groups: HashMap<String, Vec<String>> = HashMap::new();
+        let mut table_uniques: Vec<Vec<String>> = Vec::new();
+        for attribute in split_attributes(attributes) {
+            if let Some(columns) = get_table_unique(&attribute) {
+                table_uniques.push(columns);
+                continue;
+            }
+            if get_table_check(&attribute).is_some() || get_table_pairs(&attribute).is_some() {
+                continue;
+            }
+            // Every attribute of a composite reference has at most one value per parent row
+            if let Some(reference) = get_composite_reference(&attribute) {
+                let parent_rows = table_rows.get(reference.referenced_table.as_str()).copied().unwrap_or(0);
+                for column in &reference.columns {
+                    attribute_spaces.insert(column.clone(), Some(parent_rows));
+                    reference_groups.insert(column.clone(), reference.columns.clone());
+                }
+                if parent_rows == 0 {
+                    problems.push(format!(
+                        "{} Has No Rows For ({}) Of {}",
+                        reference.referenced_table,
+                        reference.columns.join(", "),
+                        table_name
+                    ));
+                } else if reference.is_unique() && parent_rows < *rows {
+                    problems.push(format!(
+                        "{} Rows Of {} Need {} Different Rows Of {}, But It Only Has {}",
+                        rows, table_name, rows, reference.referenced_table, parent_rows
+                    ));
+                }
+                continue;
+            }
+            let mut attribute_definition: Vec<String> =
+                attribute.split_whitespace().map(|s| s.to_owned()).collect();
+            let mut options = extract_column_options(&mut attribute_definition);
+            let isCompound = merge_compound(&mut attribute_definition);
+            let attribute_name = get_attribute_name(&attribute_definition, isCompound);
+            if let Some(range) = check_ranges.get(&attribute_name) {
+                options.minimum = range.minimum;
+                options.maximum = range.maximum;
+                options.allowed_values = range.allowed_values.clone();
+            }
+
+            // Every part of a compound is combined
+            let compound_space = |compound: &str| {
+                compound[1..compound.len() - 1].split("; ").try_fold(1_u64, |space, part| {
+                    let part_type = part.trim().split_once(char::is_whitespace).map_or("", |(_, t)| t.trim());
+                    let (part_type, part_locale) = split_locale(&normalize_type(part_type));
+                    get_value_space(&part_type, set_variable_size(&part_type), part_locale.unwrap_or(locale))
+                        .map(|part_space| space.saturating_mul(part_space))
+                })
+            };
+            let type_space = |attribute_type: &str| {
+                let (attribute_type, type_locale) = split_locale(&normalize_type(attribute_type));
+                get_column_space(&attribute_type, &options, type_locale.or(options.locale).unwrap_or(locale))
+            };
+            let is_nullable = matches!(options.null_probability, Some(Ok(probability)) if probability > 0.0);
+            let (space, source) = match (attribute_definition.len(), isCompound) {
+                (2, false) => (type_space(&attribute_definition[1]), String::new()),
+                (3, false) => (type_space(&attribute_definition[2]), String::new()),
+                (3, true) => (compound_space(&attribute_definition[2]), String::new()),
+                (4, true) => (compound_space(&attribute_definition[3]), String::new()),
+                (length @ (4 | 5), _) => {
+                    let (referenced_table, referenced_attribute) =
+                        get_references(&attribute_definition, length - 1);
+                    let parent_rows = table_rows.get(referenced_table.as_str()).copied().unwrap_or(0);
+                    if parent_rows == 0 && options.null_probability != Some(Ok(1.0)) {
+                        problems.push(format!(
+                            "{} Has No Rows For The Foreign Key {} Of {}",
+                            referenced_table, attribute_name, table_name
+                        ));
+                    }
+
+                    // Every parent gets between minimum and maximum children
+                    if let Some(Ok(fanout)) = &options.fanout {
+                        if parent_rows * fanout.minimum > *rows
+                            || fanout.maximum.is_some_and(|maximum| parent_rows * maximum < *rows)
+                        {
+                            let children = fanout.maximum.map_or(
+                                format!("At Least {}", fanout.minimum),
+                                |maximum| format!("Between {} And {}", fanout.minimum, maximum),
+                            );
+                            problems.push(format!(
+                                "{} Rows Of {} Can Not Give Each Of The {} Parents Of {} {} Children",
+                                rows, table_name, parent_rows, attribute_name, children
+                            ));
+                        }
+                    }
+                    (Some(parent_rows), format!("{}({})", referenced_table, referenced_attribute))
+                }
+                _ => continue,
+            };
+            attribute_spaces.insert(attribute_name.clone(), space.filter(|_| !is_nullable));
+
+            let key_definition = attribute_definition[0].to_uppercase();
+            let has_key_definition = attribute_definition.len() > 3
+                || (attribute_definition.len() == 3 && !isCompound);
+            if !has_key_definition || !(key_definition.starts_with("PK") || key_definition.starts_with("AK")) {
+                continue;
+            }
+            if is_pairwise_table && key_definition.starts_with("PK") {
+                composite_spaces.push((space, source));
+            } else if let Some(space) = space.filter(|space| space < rows) {
+                problems.push(format!(
+                    "{} Rows Of {} Need {} Different Values Of {}, But Only {} Can Be Generated",
+                    rows, table_name, rows, attribute_name, space
+                ));
+            }
+        }
+
+        if composite_spaces.len() > 1 {
+            // Attributes referencing the same attribute follow the PAIRS (...) rules of the table
+            let mut sources: Vec<&String> = composite_spaces.iter().map(|(_, source)| source).collect();
+            sources.sort();
+            sources.dedup();
+            let combinations = match (sources.len(), composite_spaces[0].0) {
+                (1, Some(values)) if !sources[0].is_empty() => {
+                    let length = composite_spaces.len() as u64;
+                    let keys = (0..length).fold(1_u64, |keys, picked| match pair_rules.self_pairs {
+                        true => keys.saturating_mul(values),
+                        false => keys.saturating_mul(values.saturating_sub(picked)),
+                    });
+                    // Undirected keys are the same read backwards, except the ones that read the same (1, 1)
+                    let palindromes = match pair_rules.self_pairs {
+                        true => values.saturating_pow(length.div_ceil(2) as u32),
+                        false => 0,
+                    };
+                    match pair_rules.directed {
+                        true => Some(keys),
+                        false => Some(keys.saturating_add(palindromes) / 2),
+                    }
+                }
+                _ => composite_spaces
+                    .iter()
+                    .try_fold(1_u64, |combinations, (space, _)| space.map(|space| combinations.saturating_mul(space))),
+            };
+            if let Some(combinations) = combinations.filter(|combinations| combinations < rows) {
+                problems.push(format!(
+                    "{} Rows Of {} Need {} Different Composite Keys, But Only {} Combinations Exist",
+                    rows, table_name, rows, combinations
+                ));
+            }
+        }
+
+        for columns in &table_uniques {
+            // Attributes of the same composite reference count the parent rows once
+            let mut counted_groups: HashSet<&Vec<String>> = HashSet::new();
+            let combinations = columns.iter().try_fold(1_u64, |combinations, column| {
+                let space = attribute_spaces.get(column).copied().flatten()?;
+                match reference_groups.get(column) {
+                    Some(group) if !counted_groups.insert(group) => Some(combinations),
+                    _ => Some(combinations.saturating_mul(space)),
+                }
+            });
+            if let Some(combinations) = combinations.filter(|combinations| combinations < rows) {
+                problems.push(format!(
+                    "{} Rows Of {} Need {} Different Values Of UNIQUE ({}), But Only {} Combinations Exist",
+                    rows, table_name, rows, columns.join(", "), combinations
+                ));
+            }
+        }
+    }
+
+    match problems.is_empty() {
+        true => Ok(()),
+        false => Err(problems),
+    }
+}
+
+fn generate_mock_data(
+    tables: &[String],
+    key_dictionary: &HashMap<String, Vec<String>>,
+    reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
+    sink: &mut dyn StatementSink,
+    row_counts: &[u64],
+    settings: &GenerationSettings,
+) -> Result<(), GenerationError> {
+    /*
+        * Generates the mock data for the tables
+        * Writes the mock data to the sink, which is finished after the last row
+
+        :parameters:
+            - `tables`: The vector of tables to generate mock data for
+            - `key_dictionary`: The hashmap of keys for each table
+            - `reference_dictionary`: The hashmap of references for each table
+            - `sink`: The destination of the insert statements (i.e the file opened by open_file_sink)
+            - `row_counts`: The number of rows of each table, see get_row_counts
+            - `settings`: The settings set from the prompt (i.e the default locale)
+
+        :returns:
+            - `Result<(), GenerationError>`: The error of the sink, or the table that could not get its rows
+    */
+
+    // Values of the key attributes of every table, keyed by (table, attribute) so tables can share attribute names
+    let mut unique_attribute_checker: HashMap<(String, String), ValueStore> = HashMap::new();
+
+    let iterations: u64 = row_counts.iter().sum();
+
+    // Dates relative to today are taken from this moment in every row of the run, see row_now
+    let now = chrono::Local::now().naive_local().with_nanosecond(0).unwrap();
+
+    // Rows of the parents of composite references (FK (a, b) parent(a, b)), kept as they are written
+    let mut referenced_rows: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+    for table in tables {
+        let attributes = &table[table.find('(').unwrap() + 1..table.len() - 1];
+        for reference in split_attributes(attributes).iter().filter_map(|attribute| get_composite_reference(attribute)) {
+            referenced_rows.insert(reference.parent_key(), Vec::new());
+        }
+    }
+    let mut statements_generated: u64 = 0;
+
+    print!(
+        "Generating SQL Inserts: {}/{} Created",
+        statements_generated, iterations
+    );
+    stdout().flush().unwrap();
+
+    for (table_index, table) in tables.iter().enumerate() {
+        // Shuffling the parents of the table draws from a stream of its own, the rows from one per chunk
+        seed_row_rng(settings.seed, table_index as u64, SETUP_STREAM);
+        let mut pairwise_table = false;
+
+        let table: Vec<&str> = table.split_whitespace().collect();
+
+        let num_statements = row_counts[table_index]; // Keeps Track of Number of Statements to Generate
+        let table_name = String::from(table[1]); //Table name
+        // A per-parent range (2-5x) gives every parent row between low and high rows of this table
+        let row_count = parse_row_count(table[0]).unwrap();
+        let parent_range = match &row_count {
+            RowCount::PerParentRange(low, high, _) => {
+                get_parent_table(&table_name, &row_count, reference_dictionary)
+                    .map(|parent| (parent, *low, *high))
+            }
+            _ => None,
+        };
+        let mut table_attributes: String = table[2..] //Table attributes
+            .join(" ")
+            .to_string();
+
+        table_attributes.remove(0); // Remove beginning parenthesis
+        table_attributes.remove(table_attributes.len() - 1); // Remove ending parenthesis
+
+        // Split attributes -> example element: 'PK userID INTEGER'. CHECK (...) and UNIQUE (...) constraints are kept apart
+        let (table_constraints, table_attributes): (Vec<String>, Vec<String>) =
+            split_attributes(&table_attributes)
+                .into_iter()
+                .partition(|attribute| {
+                    get_table_check(attribute).is_some()
+                        || get_table_unique(attribute).is_some()
+                        || get_table_pairs(attribute).is_some()
+                });
+        // The PAIRS (...) rules were validated when the table was added
+        let pair_rules = table_constraints
+            .iter()
+            .find_map(|constraint| parse_pair_rules(get_table_pairs(constraint)?).ok())
+            .unwrap_or_default();
+        let table_constraints: Vec<String> = table_constraints
+            .into_iter()
+            .filter(|constraint| get_table_pairs(constraint).is_none())
+            .collect();
+        let (table_uniques, table_checks): (Vec<String>, Vec<String>) = table_constraints
+            .into_iter()
+            .partition(|constraint| get_table_unique(constraint).is_some());
+
+        // Values of each UNIQUE (...) constraint in the rows written so far
+        let table_uniques: Vec<Vec<String>> = table_uniques
+            .iter()
+            .filter_map(|unique| get_table_unique(unique))
+            .collect();
+        let unique_tuple_checker: Vec<HashSet<Vec<String>>> = vec![HashSet::new(); table_uniques.len()];
+
+        // The CHECK constraints were validated when the table was added
+        let table_checks: Vec<(String, Expression)> = table_checks
+            .iter()
+            .filter_map(|check| {
+                let expression = get_table_check(check)?;
+                Some((expression.to_string(), parse_expression(expression).ok()?))
+            })
+            .collect();
+        let check_ranges = get_check_ranges(
+            &table_checks
+                .iter()
+                .map(|(_, expression)| expression.clone())
+                .collect::<Vec<Expression>>(),
+        );
+
+        // EXPR(...) types are parsed once for the table. Compound attributes give theirs as (name TYPE; name TYPE)
+        let mut table_expressions: HashMap<String, Expression> = HashMap::new();
+        for attribute in &table_attributes {
+            let mut attribute_definition: Vec<String> =
+                attribute.split_whitespace().map(|s| s.to_owned()).collect();
+            extract_column_options(&mut attribute_definition);
+            merge_compound(&mut attribute_definition);
+
+            let attribute_types = attribute_definition.iter().flat_map(|element| match element.starts_with('(') {
+                true => element[1..element.len() - 1]
+                    .split("; ")
+                    .map(|attribute| {
+                        attribute
+                            .trim()
+                            .split_once(char::is_whitespace)
+                            .map_or("", |(_, attribute_type)| attribute_type.trim())
+                            .to_string()
+                    })
+                    .collect::<Vec<String>>(),
+                false => vec![element.clone()],
+            });
+            for attribute_type in attribute_types {
+                let (attribute_type, _) = split_locale(&normalize_type(&attribute_type));
+                if let Some(expression) = attribute_type
+                    .strip_prefix("EXPR(")
+                    .and_then(|expression| expression.strip_suffix(')'))
+                {
+                    let parsed = parse_expression(expression).map_err(|reason| {
+                        GenerationError::Rows(format!("Invalid {} In {}: {}", attribute_type, table_name, reason))
+                    })?;
+                    table_expressions.insert(attribute_type, parsed);
+                }
+            }
+        }
+
+        let check_references = reference_dictionary.get(&table_name);
+
+        let mut primary_keys: Vec<String> = Vec::new();
+
+        // Check if table is a composite keyed table
+        if let Some(_check_references) = check_references {
+            for attribute in &table_attributes {
+                if attribute.trim()[..2].to_uppercase() == "PK" {
+                    primary_keys.push(attribute.to_string());
+                }
+            }
+            if primary_keys.len() > 1 {
+                pairwise_table = true;
+            }
+        }
+
+        // Parent pickers of the FANOUT(...) foreign keys. The parent tables were generated before this one
+        let mut fanout_pickers: HashMap<String, FanOutPicker> = HashMap::new();
+        // Unique foreign keys (PK/FK, AK/FK) without a fan-out take the parent values in a random order, each value once
+        let mut unused_parents: HashMap<String, Vec<String>> = HashMap::new();
+        for attribute in &table_attributes {
+            let mut attribute_definition: Vec<String> =
+                attribute.split_whitespace().map(|s| s.to_owned()).collect();
+            let options = extract_column_options(&mut attribute_definition);
+            merge_compound(&mut attribute_definition);
+
+            let is_foreign_key = attribute_definition.len() == 5
+                || (attribute_definition.len() == 4
+                    && !attribute_definition[2].eq_ignore_ascii_case("COMPOUND"));
+            if !is_foreign_key {
+                continue;
+            }
+            let (referenced_table, referenced_attribute) =
+                get_references(&attribute_definition, attribute_definition.len() - 1);
+
+            // Keys to the parent of a per-parent range get the range as their fan-out, unless they have their own
+            let range_fanout = parent_range
+                .as_ref()
+                .filter(|(parent, _, _)| *parent == referenced_table)
+                .map(|(_, low, high)| FanOut {
+                    shape: FanOutShape::Uniform,
+                    minimum: *low,
+                    maximum: Some(*high),
+                });
+            if let Some(fanout) = options.fanout.and_then(|fanout| fanout.ok()).or(range_fanout) {
+                let attribute_name = attribute_definition[1].to_string();
+                let parents = unique_attribute_checker
+                    .get(&(referenced_table.clone(), referenced_attribute.clone()))
+                    .map(|store| store.to_vec())
+                    .unwrap_or_default();
+
+                // Whether the parents can take the rows was checked by check_key_spaces before generating
+                fanout_pickers.insert(attribute_name, FanOutPicker::new(fanout, parents));
+            } else if (attribute_definition[0].starts_with("PK") || attribute_definition[0].starts_with("AK"))
+                && !(pairwise_table && primary_keys.contains(attribute))
+            {
+                let mut parents = unique_attribute_checker
+                    .get(&(referenced_table, referenced_attribute))
+                    .map(|store| store.to_vec())
+                    .unwrap_or_default();
+                parents.shuffle(&mut row_rng());
+                unused_parents.insert(attribute_definition[1].to_string(), parents);
+            }
+        }
+
+        // Unique composite references (PK/FK (a, b) ...) take the parent rows in a random order, each row once
+        let mut unused_parent_rows: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+        for reference in table_attributes.iter().filter_map(|attribute| get_composite_reference(attribute)) {
+            if reference.is_unique() {
+                let mut parent_rows = referenced_rows[&reference.parent_key()].clone();
+                parent_rows.shuffle(&mut row_rng());
+                unused_parent_rows.insert(reference.columns.join(", "), parent_rows);
+            }
+        }
+        // Attributes of this table referenced by composite references, their values are kept for the children
+        // References name the attributes in any case (order_line(ORDER_ID, ...)), the values are under the names given here
+        let own_columns = get_column_names(&table_attributes);
+        let referenced_keys: Vec<(String, Vec<String>)> = tables
+            .iter()
+            .flat_map(|table| split_attributes(&table[table.find('(').unwrap() + 1..table.len() - 1]))
+            .filter_map(|attribute| get_composite_reference(&attribute))
+            .filter(|reference| reference.referenced_table == table_name)
+            .map(|reference| {
+                let columns = reference
+                    .referenced_columns
+                    .iter()
+                    .map(|column| {
+                        own_columns
+                            .iter()
+                            .find(|own_column| own_column.eq_ignore_ascii_case(column))
+                            .unwrap_or(column)
+                            .clone()
+                    })
+                    .collect();
+                (reference.parent_key(), columns)
+            })
+            .collect();
+
+        // Composite keys draw again from the referenced values when they collide. Names of the key attributes in order
+        let mut key_picker: Option<CompositeKeyPicker> = None;
+        let mut key_names: Vec<String> = Vec::new();
+        if pairwise_table {
+            let mut pools: Vec<Option<Vec<String>>> = Vec::new();
+            for attribute in &primary_keys {
+                let mut attribute_definition: Vec<String> =
+                    attribute.split_whitespace().map(|s| s.to_owned()).collect();
+                extract_column_options(&mut attribute_definition);
+                let isCompound = merge_compound(&mut attribute_definition);
+                let attribute_name = get_attribute_name(&attribute_definition, isCompound);
+
+                let is_foreign_key = attribute_definition.len() == 5 || (attribute_definition.len() == 4 && !isCompound);
+                let pool = match is_foreign_key && !fanout_pickers.contains_key(&attribute_name) {
+                    true => {
+                        let reference = get_references(&attribute_definition, attribute_definition.len() - 1);
+                        unique_attribute_checker.get(&reference).map(|store| store.to_vec())
+                    }
+                    false => None,
+                };
+                pools.push(pool);
+                key_names.push(attribute_name);
+            }
+            key_picker = Some(CompositeKeyPicker::new(pools, pair_rules));
+        }
+
+        // Tables referencing themselves pick the parents of their rows among the rows before
+        let self_referencing = reference_dictionary
+            .get(&table_name)
+            .is_some_and(|references| references.iter().any(|reference| reference.contains_key(&table_name)));
+        let context = TableContext {
+            table_name,
+            table_attributes,
+            table_checks,
+            table_expressions,
+            table_uniques,
+            check_ranges,
+            primary_keys,
+            pairwise_table,
+            pair_rules,
+            key_names,
+            referenced_keys,
+            key_dictionary,
+            settings,
+            now,
+            parent_values: &unique_attribute_checker,
+            parent_rows: &referenced_rows,
+        };
+        let mut state = TableState {
+            unique_values: HashMap::new(),
+            unique_tuples: unique_tuple_checker,
+            fanout_pickers,
+            unused_parents,
+            unused_parent_rows,
+            key_picker,
+        };
+
+        // Tables whose rows only share unique values are generated in chunks on several threads and merged in order,
+        // a row with a unique value an earlier chunk took is generated again. Other tables go chunk after chunk
+        let chunked = state.fanout_pickers.is_empty() && state.key_picker.is_none() && !self_referencing;
+        let threads = settings.threads.unwrap_or(1) as u64;
+        let chunks = num_statements.div_ceil(CHUNK_ROWS);
+        let chunk_rows = move |chunk: u64| CHUNK_ROWS.min(num_statements - chunk * CHUNK_ROWS);
+        let mut own_rows: Vec<(String, Vec<String>)> = Vec::new();
+        // Rows waiting to be written together in one insert statement, and the columns their values are for
+        let batch = settings.batch.unwrap_or(1);
+        let columns = (!settings.positional).then(|| get_column_names(&context.table_attributes));
+        let mut batched_rows: Vec<String> = Vec::with_capacity(batch);
+        let mut next_chunk = 0;
+        while next_chunk < chunks {
+            let round: Vec<u64> = (next_chunk..chunks.min(next_chunk + threads)).collect();
+            next_chunk += round.len() as u64;
+
+            let generated: Vec<Vec<GeneratedRow>> = match chunked {
+                true => {
+                    let chunk_states: Vec<(u64, TableState)> = round
+                        .iter()
+                        .map(|chunk| (*chunk, state.split_chunk(chunk_rows(*chunk))))
+                        .collect();
+                    let context = &context;
+                    thread::scope(|scope| {
+                        let workers: Vec<_> = chunk_states
+                            .into_iter()
+                            .map(|(chunk, mut chunk_state)| {
+                                scope.spawn(move || {
+                                    seed_row_rng(settings.seed, table_index as u64, chunk);
+                                    generate_rows(context, &mut chunk_state, chunk_rows(chunk))
+                                })
+                            })
+                            .collect();
+                        workers.into_iter().map(|worker| worker.join().unwrap()).collect::<Result<_, _>>()
+                    })?
+                }
+                false => round
+                    .iter()
+                    .map(|chunk| {
+                        seed_row_rng(settings.seed, table_index as u64, *chunk);
+                        generate_rows(&context, &mut state, chunk_rows(*chunk))
+                    })
+                    .collect::<Result<_, _>>()?,
+            };
+
+            for (chunk, rows) in round.into_iter().zip(generated) {
+                seed_row_rng(settings.seed, table_index as u64, MERGE_STREAM + chunk);
+                for row in rows {
+                    let row = match !chunked || state.take_row(&row) {
+                        true => row,
+                        false => generate_rows(&context, &mut state, 1)?.pop().unwrap(),
+                    };
+                    own_rows.extend(row.referenced_rows);
+
+                    // Write the insert statement to the sink once the batch is full, the sink buffers it
+                    batched_rows.push(row.values);
+                    if batched_rows.len() == batch {
+                        sink.write_statement(&create_insert_statement(
+                            &context.table_name,
+                            columns.as_deref(),
+                            &batched_rows,
+                            settings.dialect,
+                        ))?;
+                        batched_rows.clear();
+                    }
+                    statements_generated += 1;
+                    if statements_generated.is_multiple_of(PROGRESS_INTERVAL) || statements_generated == iterations {
+                        print!(
+                            "\rGenerating SQL Inserts: {}/{} Created",
+                            statements_generated, iterations
+                        );
+                        stdout().flush().unwrap();
+                    }
+                }
+            }
+        }
+
+        if !batched_rows.is_empty() {
+            sink.write_statement(&create_insert_statement(
+                &context.table_name,
+                columns.as_deref(),
+                &batched_rows,
+                settings.dialect,
+            ))?;
+        }
+
+        // The keys and referenced rows of this table are the parents of the tables after it
+        unique_attribute_checker.extend(state.unique_values);
+        for (parent_key, parent_row) in own_rows {
+            referenced_rows.get_mut(&parent_key).unwrap().push(parent_row);
+        }
+    }
+    Ok(sink.finish()?)
+}
+
+fn generate_rows(
+    context: &TableContext,
+    state: &mut TableState,
+    rows: u64,
+) -> Result<Vec<GeneratedRow>, GenerationError> {
+    /*
+        * Generates rows of a table, taking their unique values, keys and parents from the state
+        * Rows failing a CHECK, UNIQUE or PRIMARY KEY constraint are given back to the state and generated again
+
+        :parameters:
+            - `context`: The attributes, constraints and parents of the table
+            - `state`: What the rows generated before took (i.e the whole table so far, or one chunk of it)
+            - `rows`: The number of rows to generate
+
+        :returns:
+            - `Result<Vec<GeneratedRow>, GenerationError>`: The rows with their values, in order.
+              An error if a row keeps failing its constraints
+    */
+    let TableContext {
+        table_name,
+        table_attributes,
+        table_checks,
+        table_expressions,
+        table_uniques,
+        check_ranges,
+        primary_keys,
+        pairwise_table,
+        pair_rules,
+        key_names,
+        referenced_keys,
+        key_dictionary,
+        settings,
+        now,
+        parent_values,
+        parent_rows,
+    } = context;
+    ROW_NOW.with(|row_now| row_now.set(Some(*now)));
+    let TableState {
+        unique_values: unique_attribute_checker,
+        unique_tuples: unique_tuple_checker,
+        fanout_pickers,
+        unused_parents,
+        unused_parent_rows,
+        key_picker,
+    } = state;
+
+    // Rows failing a CHECK or UNIQUE constraint are thrown away and generated again, up to MAX_CONSTRAINT_RETRIES times
+    let mut generated_rows: Vec<GeneratedRow> = Vec::new();
+    let mut check_retries = 0;
+    while (generated_rows.len() as u64) < rows {
+        // Number of values in the unique checkers before this row, used to take them back if the row fails a constraint
+        let checker_lengths: HashMap<(String, String), usize> = unique_attribute_checker
+            .iter()
+            .map(|(attribute_key, values)| (attribute_key.clone(), values.len()))
+            .collect();
+
+        // Stores generated value for CURRENT Insert statement. Resets after each insert statement is generated
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+
+        // Pick the shared records for grouped types (GEO_*) before generating any attribute
+        set_row_groups(table_attributes, settings.locale, &mut statement_data);
+
+        // Keep track of previous attribute [Used for tables that reference the same table attribute multiple times]
+        // (PK/FK userID1 INTEGER profile(userID), PK/FK userID2 INTEGER profile(userID)
+        let mut referenced_attributes: HashMap<String, Vec<String>> = HashMap::new();
+
+        // Used in pairwise (composite) key scenarios
+        let mut pair_list: Vec<String> = Vec::new();
+
+        // Parents given a child through FANOUT(...) in this row, taken back if the row fails a CHECK
+        let mut fanout_children: Vec<(String, String)> = Vec::new();
+        // Parent rows taken by unique composite references in this row, given back if the row fails a CHECK
+        let mut picked_parent_rows: Vec<(String, Vec<String>)> = Vec::new();
+        // Parent values taken by unique foreign keys in this row, given back if the row fails a CHECK
+        let mut picked_parents: Vec<(String, String)> = Vec::new();
+
+        for attribute in table_attributes {
+            // A composite reference copies the values of one parent row into all of its attributes
+            if let Some(reference) = get_composite_reference(attribute) {
+                let columns = reference.columns.join(", ");
+                let parent_row = match unused_parent_rows.get_mut(&columns) {
+                    Some(parent_rows) => parent_rows.pop().inspect(|parent_row| {
+                        picked_parent_rows.push((columns.clone(), parent_row.clone()));
+                    }),
+                    None => parent_rows[&reference.parent_key()].choose(&mut row_rng()).cloned(),
+                };
+                let parent_row = parent_row.ok_or_else(|| {
+                    GenerationError::Rows(format!(
+                        "{} Has No Rows Left For ({}) Of {}",
+                        reference.referenced_table, columns, table_name
+                    ))
+                })?;
+                for (column, value) in reference.columns.iter().zip(parent_row) {
+                    statement_data.insert(column.clone(), value);
+                }
+                continue;
+            }
+
+            let pairwise_attribute =
+                *pairwise_table && primary_keys.contains(&attribute.to_string());
+
+            let mut attribute_definition: Vec<String> = attribute
+                .split_whitespace()
+                .map(|s| s.to_owned())
+                .collect();
+
+            let mut options = extract_column_options(&mut attribute_definition);
+            options.locale = options.locale.or(Some(settings.locale));
+            let isCompound = merge_compound(&mut attribute_definition);
+
+            // Bounds and values inferred from the table's CHECK constraints
+            if let Some(range) = check_ranges.get(&get_attribute_name(&attribute_definition, isCompound)) {
+                options.minimum = range.minimum;
+                options.maximum = range.maximum;
+                options.allowed_values = range.allowed_values.clone();
+            }
+
+            let attribute_definition = attribute_definition;
+
+            // NULL(p) columns skip generation in roughly p of the rows. The NULLs are never added to the unique checkers
+            if let Some(Ok(null_probability)) = options.null_probability {
+                if row_rng().gen_bool(null_probability) {
+                    statement_data.insert(
+                        get_attribute_name(&attribute_definition, isCompound),
+                        "NULL".to_string(),
+                    );
+                    continue;
+                }
+            }
+
+            match &attribute_definition.len() {
+                1 => {
+                    /*
+                     * User requests default value for attribute
+                     * Does not need to specify attribute
+                     * For instance if table has attribute that would be NULL for mock data user can put "NULL"
+                     * Then for each insert, that attribute will be NULL for
+                     */
+                    match attribute_definition[0].to_uppercase().as_str() {
+                        // TODO - Add more default values
+                        "0" | "NULL" | "TRUE" | "FALSE" => {
+                            statement_data.insert(
+                                attribute_definition[0].to_string(),
+                                attribute_definition[0].to_string(),
+                            );
+                        }
+                        _ => {
+                            println!("[!] Invalid default value for attribute");
+                            continue;
+                        }
+                    }
+                }
+                2 => {
+                    /*
+                        * Attribute Definition Is Of The Form:
+                        * [attribute name] [attribute type]
+                        * Example: 'userID INTEGER'
+
+                        * Generate Data For Attribute
+                    */
+
+                    let attribute_name = attribute_definition[0].to_string();
+                    let attribute_type = normalize_type(&attribute_definition[1]);
+
+                    let optional_variable_size: Option<Vec<u16>> =
+                        set_variable_size(&attribute_type);
+
+                    let generated_data: String = get_column_data(
+                        &attribute_type,
+                        optional_variable_size.clone(),
+                        &options,
+                        table_expressions.get(&attribute_type),
+                        &statement_data,
+                    );
+
+                    statement_data.insert(attribute_name, generated_data);
+                }
+                3 => match isCompound {
+                    /*
+                        * Attribute Definition Is Of The Form:
+                        * [key definition] [attribute name] [attribute type]
+                        * OR
+                        * [attribute name] [compound] [(compound attribute)]
+                        * Standard Example: 'PK userID INTEGER'
+                        * Compound Example: 'full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20)
+
+                        * Generate Data For Attribute
+                    */
+                    true => {
+                        /*
+                         * Compound Attribute. Generate Data For Each Attribute In The Compound Attribute
+                         * Example: 'full_name COMPOUND (first_name VARCHAR(20), middle_initial CHAR(1), last_name VARCHAR(20))'
+                         */
+                        let attribute_name = attribute_definition[0].to_string();
+                        // Remove beginning and ending parenthesis of compound attribute
+                        let compound_attribute = attribute_definition[2]
+                            [1..attribute_definition[2].len() - 1]
+                            .to_string();
+                        let compound_attribute =
+                            compound_attribute.split("; ").collect::<Vec<&str>>();
+
+                        let mut compound_attribute_data: Vec<String> = Vec::new();
+
+                        // Iterate over each attribute in the compound attribute, get type and size, then generate data
+                        for attribute in compound_attribute {
+                            // Everything after the name is the type, so values like ONEOF(1, 2) stay whole
+                            let attribute_type = attribute
+                                .trim()
+                                .split_once(char::is_whitespace)
+                                .map_or("", |(_, attribute_type)| attribute_type.trim());
+                            let (attribute_type, locale) =
+                                split_locale(&normalize_type(attribute_type));
+
+                            let optional_variable_size: Option<Vec<u16>> =
+                                set_variable_size(&attribute_type);
+
+                            let generated_data: String = match table_expressions.get(&attribute_type) {
+                                Some(expression) => evaluate_expression(expression, &statement_data).to_string(),
+                                None => get_random_data(
+                                    &attribute_type,
+                                    optional_variable_size.clone(),
+                                    locale.unwrap_or(settings.locale),
+                                    &statement_data,
+                                ),
+                            };
+
+                            compound_attribute_data.push(generated_data);
+                        }
+
+                        // Create string of compound attribute data in form (data1, data2, data3, ...)
+                        let compound_attribute_data = compound_attribute_data.join(", ");
+                        statement_data.insert(attribute_name, compound_attribute_data);
+                    }
+                    false => {
+                        /*
+                         * Standard Attribute. Proceed As Before
+                         */
+                        let attribute_name = attribute_definition[1].to_string();
+                        let attribute_type =
+                            normalize_type(&attribute_definition[2]);
+
+                        let optional_variable_size: Option<Vec<u16>> =
+                            set_variable_size(&attribute_type);
+
+                        let mut generated_data: String = get_column_data(
+                            &attribute_type,
+                            optional_variable_size.clone(),
+                            &options,
+                            table_expressions.get(&attribute_type),
+                            &statement_data,
+                        );
+
+                        let attribute_key = (table_name.clone(), attribute_name.clone());
+                        if unique_attribute_checker.contains_key(&attribute_key) {
+                            // Names, derived values and other types without a known number of values can still run out
+                            let mut key_retries = 0;
+                            while (unique_attribute_checker[&attribute_key])
+                                .contains(&generated_data)
+                            {
+                                key_retries += 1;
+                                if key_retries > MAX_CONSTRAINT_RETRIES {
+                                    return Err(get_repeated_key_error(&attribute_name, table_name));
+                                }
+                                redraw_row_groups(attribute, settings.locale, &mut statement_data);
+                                generated_data = get_column_data(
+                                    &attribute_type,
+                                    optional_variable_size.clone(),
+                                    &options,
+                                    table_expressions.get(&attribute_type),
+                                    &statement_data,
+                                );
+                                if !options.from.is_empty() {
+                                    generated_data = get_suffixed_data(
+                                        &attribute_type,
+                                        optional_variable_size.clone(),
+                                        &generated_data,
+                                    );
+                                }
+                            }
+                        }
+
+                        unique_attribute_checker
+                            .entry(attribute_key)
+                            .or_default()
+                            .push(&generated_data);
+
+                        statement_data.insert(attribute_name, generated_data.clone());
+
+                        if pairwise_attribute {
+                            pair_list.push(generated_data);
+                        }
+                    }
+                }
+                4 => match isCompound {
+                    /*
+                        * Attribute Definition Is Of The Form:
+                        * [foreign key definition] [attribute name] [attribute type] [foreign table]
+                        * OR
+                        * [primary/unique key] [attribute name] [compound] [(compound attribute)]
+                        * Example: 'PK/FK userID INTEGER profile(userID)'
+
+                        * Generate Data For Attribute
+                    */
+                    true => {
+                        /*
+                            * Keyed Compound Attribute.
+                            * Generate Data For Each Attribute In The Compound Attribute
+                            * Add to unique_attribute_checker
+                        */
+                        let comp_attr_name = attribute_definition[1].to_string();
+                        let comp_attr_compound = attribute_definition[3].to_string();
+
+                        let comp_attr_compound =
+                            comp_attr_compound[1..comp_attr_compound.len() - 1].to_string();
+
+                        let comp_attr_compound =
+                            comp_attr_compound.split("; ").collect::<Vec<&str>>();
+
+                        let mut key_retries = 0;
+                        loop {
+                            key_retries += 1;
+                            if key_retries > MAX_CONSTRAINT_RETRIES {
+                                return Err(get_repeated_key_error(&comp_attr_name, table_name));
+                            }
+                            if key_retries > 1 {
+                                redraw_row_groups(attribute, settings.locale, &mut statement_data);
+                            }
+                            let mut compound_attribute_data: Vec<String> = Vec::new();
+                            // Iterate over each attribute in the compound attribute, get type and size, then generate data
+                            for attribute in comp_attr_compound.iter() {
+                                // Everything after the name is the type, so values like ONEOF(1, 2) stay whole
+                                let attribute_type = attribute
+                                    .trim()
+                                    .split_once(char::is_whitespace)
+                                    .map_or("", |(_, attribute_type)| attribute_type.trim());
+                                let (attribute_type, locale) =
+                                    split_locale(&normalize_type(attribute_type));
+
+                                let optional_variable_size: Option<Vec<u16>> =
+                                    set_variable_size(&attribute_type);
+
+                                let generated_data: String = match table_expressions.get(&attribute_type) {
+                                    Some(expression) => {
+                                        evaluate_expression(expression, &statement_data).to_string()
+                                    }
+                                    None => get_random_data(
+                                        &attribute_type,
+                                        optional_variable_size.clone(),
+                                        locale.unwrap_or(settings.locale),
+                                        &statement_data,
+                                    ),
+                                };
+
+                                compound_attribute_data.push(generated_data);
+                            }
+
+                            // Create string of compound attribute data in form (data1, data2, data3, ...)
+                            let compound_attribute_data =
+                                compound_attribute_data.join(", ");
+
+                            // If primary or unique key, add to unique_attribute_checker
+                            let attribute_key = (table_name.clone(), comp_attr_name.clone());
+                            if unique_attribute_checker.contains_key(&attribute_key) &&
+                                unique_attribute_checker[&attribute_key].contains(&compound_attribute_data) {
+                                continue;
+                            }
+
+                            // Add to unique_attribute_checker
+                            unique_attribute_checker
+                                .entry(attribute_key)
+                                .or_default()
+                                .push(&compound_attribute_data);
+
+                            // Add to statement_data
+                            statement_data.insert(
+                                comp_attr_name.clone(),
+                                compound_attribute_data.clone(),
+                            );
+
+                            // If pairwise, add to pair_list
+                            if pairwise_attribute {
+                                if pair_list.contains(&compound_attribute_data) && !pair_rules.self_pairs {
+                                    continue;
+                                }
+                                pair_list.push(compound_attribute_data.clone());
+                            }
+                            break;
+                        }
+                    }
+                    false => {
+                        /*
+                            * Standard Foreign Key Attribute.
+                            * Generate Data For Attribute Based On Referenced Attribute
+                            * I.E. Use Previously Generated Data From Referenced Attribute
+                        */
+                        let (referenced_table, referenced_attribute) =
+                            get_references(&attribute_definition, 3);
+
+                        if get_referenced_attribute(
+                            key_dictionary.get(&referenced_table).unwrap(),
+                            &referenced_attribute.to_uppercase().to_string(),
+                        )
+                            .is_none()
+                        {
+                            println!(
+                                "\nPROGRAM ERROR IN GENERATING DATA [Getting Referenced Attribute]"
+                            );
+                            std::process::exit(1);
+                        }
+
+                        loop {
+                            /*
+                                * Reference does exist and is valid.
+                                * Get a random reference for that attribute from the list
+                             */
+                            let randomized_data = match unused_parents.get_mut(&attribute_definition[1]) {
+                                Some(parents) => take_unused_parent(parents, &attribute_definition[1], table_name)?,
+                                None => get_parent_data(
+                                    &attribute_definition[1],
+                                    fanout_pickers.get(&attribute_definition[1]),
+                                    get_referenced_values(
+                                        &(referenced_table.clone(), referenced_attribute.clone()),
+                                        table_name,
+                                        unique_attribute_checker,
+                                        parent_values,
+                                    ),
+                                )?,
+                            };
+                            if unused_parents.contains_key(&attribute_definition[1]) {
+                                picked_parents.push((attribute_definition[1].to_string(), randomized_data.clone()));
+                            }
+
+                            /*
+                                * Check if the randomized data is the same as the previous data
+                                * If it is, generate new data
+                                * [key definition][0] [attribute name][1] [attribute type][2] [foreign table][3]
+                             */
+                            if referenced_attributes
+                                .contains_key(&referenced_attribute.to_string())
+                            {
+                                if referenced_attributes
+                                    .get(&attribute_definition[3].to_string())
+                                    .unwrap()
+                                    .contains(&attribute_definition[1].to_string())
+                                {
+                                    println!("Data being generated for same attribute.");
+                                    println!("Program error.");
+                                    println!("Exiting program.");
+
+                                    std::process::exit(1);
+                                } else {
+                                    if referenced_attributes
+                                        .get(&attribute_definition[3].to_string())
+                                        .unwrap()
+                                        .contains(&randomized_data)
+                                    {
+                                        continue;
+                                    }
+                                }
+                            }
+
+                            /*
+                                * True if table is composite keyed
+                                * Pair list can't have same data for keyed attributes if it does, regenerate data for current attribute
+                            */
+                            if pairwise_attribute {
+                                if pair_list.contains(&randomized_data) && !pair_rules.self_pairs {
+                                    continue;
+                                }
+                                pair_list.push(randomized_data.clone());
+                            } else {
+                                //Not pairwise, check if data is unique for attribute or continue loop and generate new data for attribute
+                                if attribute_definition[0].starts_with("PK")
+                                    || attribute_definition[0].starts_with("AK")
+                                {
+                                    let attribute_key = (table_name.clone(), attribute_definition[1].to_string());
+                                    if unique_attribute_checker
+                                        .contains_key(&attribute_key)
+                                        && unique_attribute_checker
+                                        [&attribute_key]
+                                        .contains(&randomized_data)
+                                    {
+                                        continue;
+                                    }
+
+                                    unique_attribute_checker
+                                        .entry(attribute_key)
+                                        .or_default()
+                                        .push(&randomized_data);
+                                }
+                            }
+
+                            /*
+                                * Add the attribute to the list of referenced attributes
+                                * Add the data to the statement data
+                                * Break out of the loop
+                             */
+                            referenced_attributes
+                                .entry(attribute_definition[3].to_string())
+                                .or_default()
+                                .push(attribute_definition[1].to_string());
+
+                            if let Some(picker) = fanout_pickers.get_mut(&attribute_definition[1]) {
+                                picker.add_child(&randomized_data);
+                                fanout_children.push((attribute_definition[1].to_string(), randomized_data.clone()));
+                            }
+
+                            statement_data.insert(
+                                attribute_definition[1].to_string(),
+                                randomized_data,
+                            );
+                            break;
+                        }
+                    }
+                }
+                5 => {
+                    /*
+                        * This only runs when its a compound attribute with a foreign key
+                        * Attribute Definition Is Of The Form:
+                        * [key definition][0] [attribute name][1] [compound][2] [(compound attribute)][3] [foreign table][4]
+                    */
+                    let (referenced_table, referenced_attribute) =
+                        get_references(&attribute_definition, 4);
+
+                    if get_referenced_attribute(
+                        key_dictionary.get(&referenced_table).unwrap(),
+                        &referenced_attribute.to_uppercase().to_string(),
+                    )
+                        .is_none()
+                    {
+                        println!(
+                            "\nPROGRAM ERROR IN GENERATING DATA [Getting Referenced Attribute]"
+                        );
+                        std::process::exit(1);
+                    }
+
+                    loop {
+                        /*
+                            * Reference does exist and is valid.
+                            * Get a random reference for that attribute from the list
+                        */
+                        let randomized_data = match unused_parents.get_mut(&attribute_definition[1]) {
+                            Some(parents) => take_unused_parent(parents, &attribute_definition[1], table_name)?,
+                            None => get_parent_data(
+                                &attribute_definition[1],
+                                fanout_pickers.get(&attribute_definition[1]),
+                                get_referenced_values(
+                                    &(referenced_table.clone(), referenced_attribute.clone()),
+                                    table_name,
+                                    unique_attribute_checker,
+                                    parent_values,
+                                ),
+                            )?,
+                        };
+                        if unused_parents.contains_key(&attribute_definition[1]) {
+                            picked_parents.push((attribute_definition[1].to_string(), randomized_data.clone()));
+                        }
+
+                        if referenced_attributes
+                            .contains_key(&referenced_attribute.to_string())
+                        {
+                            if referenced_attributes
+                                .get(&attribute_definition[4].to_string())
+                                .unwrap()
+                                .contains(&attribute_definition[1].to_string())
+                            {
+                                println!("Data being generated for same attribute.");
+                                println!("Program error.");
+                                println!("Exiting program.");
+
+                                std::process::exit(1);
+                            } else {
+                                if referenced_attributes
+                                    .get(&attribute_definition[4].to_string())
+                                    .unwrap()
+                                    .contains(&randomized_data)
+                                {
+                                    continue;
+                                }
+                            }
+                        }
+
+                        /*
+                           * True if table is composite keyed
+                           * Pair list can't have same data for keyed attributes if it does, regenerate data for current attribute
+                        */
+                        if pairwise_attribute {
+                            if pair_list.contains(&randomized_data) && !pair_rules.self_pairs {
+                                continue;
+                            }
+                            pair_list.push(randomized_data.clone());
+                        } else {
+                            //Not pairwise, check if data is unique for attribute or continue loop and generate new data for attribute
+                            if attribute_definition[0].starts_with("PK")
+                                || attribute_definition[0].starts_with("AK")
+                            {
+                                let attribute_key = (table_name.clone(), attribute_definition[1].to_string());
+                                if unique_attribute_checker
+                                    .contains_key(&attribute_key)
+                                    && unique_attribute_checker[&attribute_key]
+                                    .contains(&randomized_data)
+                                {
+                                    continue;
+                                }
+
+                                unique_attribute_checker
+                                    .entry(attribute_key)
+                                    .or_default()
+                                    .push(&randomized_data);
+                            }
+                        }
+
+                        /*
+                            * Add the attribute to the list of referenced attributes
+                            * Add the data to the statement data
+                            * Break out of the loop
+                         */
+                        referenced_attributes
+                            .entry(attribute_definition[4].to_string())
+                            .or_default()
+                            .push(attribute_definition[1].to_string());
+
+                        if let Some(picker) = fanout_pickers.get_mut(&attribute_definition[1]) {
+                            picker.add_child(&randomized_data);
+                            fanout_children.push((attribute_definition[1].to_string(), randomized_data.clone()));
+                        }
+
+                        statement_data.insert(
+                            attribute_definition[1].to_string(),
+                            randomized_data,
+                        );
+                        break;
+
+                    }
+                }
+                _ => {
+                    println!("\nPROGRAM ERROR IN GENERATING DATA [Attribute Definition Length]");
+                    std::process::exit(1);
+                }
+            }
+        }
+        /*
+            * Check if the table is a pairwise table
+            * If it is, make sure the composite key has not been generated before
+            * A key that was generated before is replaced by an unused one in statement_data
+         */
+        let composite_key = key_picker.as_mut().map(|picker| picker.pick(pair_list.clone()));
+        if let Some(Some(key)) = &composite_key {
+            for (attribute_name, value) in key_names.iter().zip(key) {
+                statement_data.insert(attribute_name.clone(), value.clone());
+            }
+        }
+
+        // Like in SQL a CHECK constraint only fails when it is false, a NULL result passes
+        let failed_check = table_checks.iter().find(|(_, expression)| {
+            let result = evaluate_expression(expression, &statement_data);
+            result != ExpressionValue::Null && !is_expression_true(&result)
+        });
+        // A UNIQUE constraint only fails on values seen before, values with a NULL are never the same
+        let unique_values: Vec<Vec<String>> = table_uniques
+            .iter()
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|column| statement_data.get(column).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+        let failed_unique = table_uniques.iter().enumerate().find(|(index, _)| {
+            !unique_values[*index].iter().any(|value| value == "NULL")
+                && unique_tuple_checker[*index].contains(&unique_values[*index])
+        });
+        let failed_constraint = match (failed_check, failed_unique) {
+            _ if composite_key == Some(None) => Some(format!("PRIMARY KEY ({})", key_names.join(", "))),
+            (Some((check, _)), _) => Some(format!("CHECK ({})", check)),
+            (None, Some((_, columns))) => Some(format!("UNIQUE ({})", columns.join(", "))),
+            (None, None) => None,
+        };
+        if let Some(constraint) = failed_constraint {
+            check_retries += 1;
+            if check_retries > MAX_CONSTRAINT_RETRIES {
+                return Err(GenerationError::Rows(format!(
+                    "Could Not Generate A Row For {} That Passes {} After {} Tries",
+                    table_name, constraint, MAX_CONSTRAINT_RETRIES
+                )));
+            }
+
+            for (attribute_key, values) in unique_attribute_checker.iter_mut() {
+                values.truncate(*checker_lengths.get(attribute_key).unwrap_or(&0));
+            }
+            for (attribute_name, parent) in &fanout_children {
+                fanout_pickers.get_mut(attribute_name).unwrap().remove_child(parent);
+            }
+            for (columns, parent_row) in picked_parent_rows {
+                unused_parent_rows.get_mut(&columns).unwrap().push(parent_row);
+            }
+            for (attribute_name, parent) in picked_parents {
+                unused_parents.get_mut(&attribute_name).unwrap().push(parent);
+            }
+            continue;
+        }
+        check_retries = 0;
+        if let (Some(picker), Some(Some(key))) = (key_picker.as_mut(), &composite_key) {
+            picker.add(key);
+        }
+        for (tuples, values) in unique_tuple_checker.iter_mut().zip(&unique_values) {
+            tuples.insert(values.clone());
+        }
+        let key_values = unique_attribute_checker
+            .iter()
+            .flat_map(|(attribute_key, values)| {
+                (*checker_lengths.get(attribute_key).unwrap_or(&0)..values.len())
+                    .map(move |position| (attribute_key.clone(), values.get(position).to_string()))
+            })
+            .collect();
+        let referenced_rows = referenced_keys
+            .iter()
+            .map(|(parent_key, columns)| {
+                let parent_row = columns
+                    .iter()
+                    .map(|column| statement_data.get(column).cloned().unwrap_or_default())
+                    .collect();
+                (parent_key.clone(), parent_row)
+            })
+            .collect();
+
+        generated_rows.push(GeneratedRow {
+            values: create_insert_values(table_attributes, &statement_data),
+            key_values,
+            unique_tuples: unique_values
+                .into_iter()
+                .enumerate()
+                .filter(|(_, values)| !values.iter().any(|value| value == "NULL"))
+                .collect(),
+            picked_parents,
+            picked_parent_rows,
+            referenced_rows,
+        });
+    }
+    Ok(generated_rows)
+}
+
+fn get_referenced_values<'a>(
+    reference: &(String, String),
+    table_name: &str,
+    own_values: &'a HashMap<(String, String), ValueStore>,
+    parent_values: &'a HashMap<(String, String), ValueStore>,
+) -> &'a ValueStore {
+    /*
+        * Gets the values a foreign key picks its parent from
+        * Parents were generated before the table, only a table referencing itself reads the values of its own rows
+
+        :parameters:
+            - `reference`: The referenced table and attribute
+            - `table_name`: The name of the table being generated
+            - `own_values`: The values of the keys of the table being generated
+            - `parent_values`: The values of the keys of the tables generated before
+
+        :returns:
+            - `&ValueStore`: The values of the referenced attribute
+    */
+    match reference.0 == table_name {
+        true => &own_values[reference],
+        false => &parent_values[reference],
+    }
+}
+
+fn merge_compound(attribute_definition: &mut Vec<String>) -> bool {
+    /*
+        * Merges compound attributes into one string
+        * Example: 'full_name, COMPOUND, (first_name, VARCHAR(20);, middle_initial CHAR(1):, last_name VARCHAR(20))'
+        * Becomes: 'full_name, COMPOUND, (first_name VARCHAR(20); middle_initial CHAR(1); last_name VARCHAR(20))'
+        * Any other parenthesized group split by whitespace is merged as well (see merge_parentheses)
+
+        :parameters:
+            - `attribute_definition`: The vector of attribute definitions
+
+        :returns:
+            - `bool`: True if the attribute definition is a compound attribute, False otherwise
+    */
+
+    /*
+        * Join All Elements Inside The Compound Parentheses Into One String Element
+        * "COMPOUND" Can Either Be At attribute_checker[1] Or attribute_checker[2]
+        * 3 Elements -> [attribute_name, COMPOUND, compound_attributes]
+        * 4 Elements -> [key_definition, attribute_name, COMPOUND, compound_attributes]
+        * 5 Elements -> [key_definition, attribute_name, COMPOUND, compound_attributes, foreign_table]
+    */
+    merge_parentheses(attribute_definition);
+
+    attribute_definition
+        .iter()
+        .any(|x| x.eq_ignore_ascii_case("COMPOUND"))
+}
+
+fn merge_parentheses(attribute_definition: &mut Vec<String>) {
+    /*
+        * Merges elements that were split on whitespace inside of parentheses
+        * Example: ['email', 'EMAIL', 'FROM(username,', 'company)']
+        * Becomes: ['email', 'EMAIL', 'FROM(username, company)']
+
+        :parameters:
+            - `attribute_definition`: The vector of attribute definitions
+    */
+    let mut merged_definition: Vec<String> = Vec::new();
+    let mut depth: i32 = 0;
+    let mut in_quotes = false;
+
+    for element in attribute_definition.drain(..) {
+        let depth_change = get_depth_change(&element, &mut in_quotes);
+
+        match merged_definition.last_mut() {
+            Some(last) if depth > 0 => *last += &format!(" {}", element),
+            _ => merged_definition.push(element),
+        }
+        depth = (depth + depth_change).max(0);
+    }
+
+    *attribute_definition = merged_definition;
+}
+
+fn split_attributes(attributes: &str) -> Vec<String> {
+    /*
+        * Splits the attributes of a table on commas that are not inside of parentheses or quotes
+        * Example: 'PK id INTEGER, price DECIMAL(10,2), email EMAIL FROM(username, company)'
+        * Becomes: ['PK id INTEGER', 'price DECIMAL(10,2)', 'email EMAIL FROM(username, company)']
+
+        :parameters:
+            - `attributes`: The attributes of the table without the surrounding parentheses
+
+        :returns:
+            - `Vec<String>`: The trimmed attributes
+    */
+    let mut split: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut depth: i32 = 0;
+    let mut in_quotes = false;
+
+    for c in attributes.chars() {
+        match c {
+            '\'' => in_quotes = !in_quotes,
+            '(' if !in_quotes => depth += 1,
+            ')' if !in_quotes => depth -= 1,
+            ',' if depth <= 0 && !in_quotes => {
+                split.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        split.push(current.trim().to_string());
+    }
+    split
+}
+
+fn get_attribute_name(attribute_definition: &[String], is_compound: bool) -> String {
+    /*
+        * Gets the name of an attribute from its (merged) definition
+        * [attribute name] [attribute type] -> [0]
+        * [key definition] [attribute name] [attribute type] -> [1]
+        * [attribute name] [compound] [(compound attribute)] -> [0]
+        * [key definition] [attribute name] ... -> [1]
+
+        :parameters:
+            - `attribute_definition`: The vector of attribute definitions
+            - `is_compound`: True if the attribute is a compound attribute
+
+        :returns:
+            - `String`: The name of the attribute
+    */
+    match attribute_definition.len() {
+        1 | 2 => attribute_definition[0].to_string(),
+        3 if is_compound => attribute_definition[0].to_string(),
+        _ => attribute_definition[1].to_string(),
+    }
+}
+
+fn extract_column_options(attribute_definition: &mut Vec<String>) -> ColumnOptions {
+    /*
+        * Removes column modifiers from an attribute definition and returns them as ColumnOptions
+        * Modifiers come after the attribute and are not part of the key/name/type/reference layout
+        * Example: ['email', 'EMAIL', 'FROM(username,', 'company)'] -> ['email', 'EMAIL']
+        * Modifiers:
+            * FROM(column, ...) -> Derive the value from previously generated columns of the row
+            * NULL(p) -> The value is NULL with probability p (0 - 1), otherwise generated as usual
+            * DIST(name, parameters) -> Draw the number or date from a distribution, see parse_distribution
+            * FANOUT(shape, min n, max n) -> Spread the rows of a foreign key over its parents, see parse_fanout
+            * TYPE@locale -> Generate the value in that locale (i.e NAME@fr_FR). The suffix is removed from the type
+
+        :parameters:
+            - `attribute_definition`: The vector of attribute definitions
+
+        :returns:
+            - `ColumnOptions`: The options found on the attribute
+    */
+    let mut options = ColumnOptions::default();
+    let mut kept_definition: Vec<String> = Vec::new();
+    let mut modifier: Option<String> = None;
+    let mut depth: i32 = 0;
+    let mut in_quotes = false;
+
+    for element in attribute_definition.drain(..) {
+        let is_modifier = ["FROM(", "NULL(", "DIST(", "FANOUT("]
+            .iter()
+            .any(|keyword| element.to_uppercase().starts_with(keyword));
+
+        let is_kept = match modifier.as_mut() {
+            Some(current) => {
+                *current += &format!(" {}", element);
+                false
+            }
+            None if depth == 0 && is_modifier => {
+                modifier = Some(element.clone());
+                false
+            }
+            None => {
+                kept_definition.push(element.clone());
+                true
+            }
+        };
+
+        depth = (depth + get_depth_change(&element, &mut in_quotes)).max(0);
+
+        // Locale suffix of the type. Types inside a compound keep theirs, they are read by the compound
+        if is_kept && depth == 0 {
+            let (kept_element, locale) = split_locale(kept_definition.last().unwrap());
+            if locale.is_some() {
+                *kept_definition.last_mut().unwrap() = kept_element;
+                options.locale = locale;
+            }
+        }
+
+        if depth == 0 {
+            if let Some(current) = modifier.take() {
+                let (keyword, arguments) = current.split_once('(').unwrap();
+                let arguments = arguments[..arguments.len() - 1].to_string(); // Remove ending parenthesis
+
+                match keyword.to_uppercase().as_str() {
+                    "FROM" => {
+                        options.from = arguments
+                            .split(',')
+                            .map(|column| column.trim().to_string())
+                            .filter(|column| !column.is_empty())
+                            .collect();
+                    }
+                    "NULL" => options.null_probability = Some(parse_null_probability(&arguments)),
+                    "DIST" => options.distribution = Some(parse_distribution(&arguments)),
+                    "FANOUT" => options.fanout = Some(parse_fanout(&arguments)),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    *attribute_definition = kept_definition;
+    options
+}
+
+fn normalize_type(attribute_type: &str) -> String {
+    /*
+        * Uppercases the keyword of a type, but not what is inside its parentheses
+        * Example: varchar(20) -> VARCHAR(20), const('us') -> CONST('us'), ONEOF(Mod, Member) -> ONEOF(Mod, Member)
+        * Keeps the values of CONST / ONEOF as they were given
+
+        :parameters:
+            - `attribute_type`: The type of the attribute
+
+        :returns:
+            - `String`: The type with an uppercase keyword
+    */
+    match attribute_type.split_once('(') {
+        Some((keyword, arguments)) => format!("{}({}", keyword.trim().to_uppercase(), arguments),
+        None => attribute_type.to_uppercase(),
+    }
+}
+
+fn get_value_list(attribute_type: &str) -> Vec<String> {
+    /*
+        * Gets the values given to CONST(...) or ONEOF(...)
+        * Values are separated by commas outside of quotes. Quotes around a value are removed
+        * Example: ONEOF('New York', 'O''Brien', 42) -> ['New York', 'O'Brien', '42']
+
+        :parameters:
+            - `attribute_type`: The CONST / ONEOF type
+
+        :returns:
+            - `Vec<String>`: The values, empty if none were given
+    */
+    get_value_entries(attribute_type)
+        .into_iter()
+        .map(|(value, _)| value)
+        .collect()
+}
+
+fn get_value_entries(attribute_type: &str) -> Vec<(String, bool)> {
+    /*
+        * Gets the values given to CONST(...) or ONEOF(...), with whether each one was given in quotes
+        * A quoted value is text, even when it reads as a number or a keyword
+        * Example: ONEOF('42', 42, default) -> [('42', true), ('42', false), ('default', false)]
+
+        :parameters:
+            - `attribute_type`: The CONST / ONEOF type
+
+        :returns:
+            - `Vec<(String, bool)>`: The values and whether they were quoted, empty if none were given
+    */
+    let arguments = match (attribute_type.find('('), attribute_type.rfind(')')) {
+        (Some(start), Some(end)) if start < end => &attribute_type[start + 1..end],
+        _ => return Vec::new(),
+    };
+
+    let mut values: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in arguments.chars() {
+        match (quote, c) {
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, ',') => {
+                values.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    values.push(current.trim().to_string());
+
+    values
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            let is_quoted = value.len() > 1
+                && ((value.starts_with('\'') && value.ends_with('\''))
+                    || (value.starts_with('"') && value.ends_with('"')));
+            match is_quoted {
+                true => {
+                    let quote = &value[..1];
+                    (value[1..value.len() - 1].replace(&quote.repeat(2), quote), true)
+                }
+                false => (value, false),
+            }
+        })
+        .collect()
+}
+
+fn get_depth_change(element: &str, in_quotes: &mut bool) -> i32 {
+    /*
+        * Counts how much an element opens (positive) or closes (negative) parentheses
+        * Parentheses inside single quotes are not counted, i.e CONST(')')
+        * The quote state is carried over so a quoted value split on whitespace is handled
+
+        :parameters:
+            - `element`: The element to count
+            - `in_quotes`: Whether the element starts inside single quotes, updated for the next element
+
+        :returns:
+            - `i32`: Opened parentheses minus closed parentheses
+    */
+    let mut depth_change: i32 = 0;
+    for c in element.chars() {
+        match c {
+            '\'' => *in_quotes = !*in_quotes,
+            '(' if !*in_quotes => depth_change += 1,
+            ')' if !*in_quotes => depth_change -= 1,
+            _ => {}
+        }
+    }
+    depth_change
+}
+
+// Operators of EXPR(...) from the loosest to the tightest binding, NOT and unary '-' are read with the operands
+const EXPRESSION_OPERATORS: [&[&str]; 6] = [
+    &["OR"],
+    &["AND"],
+    &["=", "==", "!=", "<>", "<", "<=", ">", ">="],
+    &["||"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+// Functions of EXPR(...) with their minimum and maximum number of arguments
+const EXPRESSION_FUNCTIONS: [(&str, usize, usize); 25] = [
+    ("ABS", 1, 1),
+    ("CEIL", 1, 1),
+    ("COALESCE", 1, usize::MAX),
+    ("CONCAT", 1, usize::MAX),
+    ("DATE_ADD", 2, 3),
+    ("DATE_DIFF", 2, 2),
+    ("DAY", 1, 1),
+    ("FLOOR", 1, 1),
+    ("IF", 3, 3),
+    ("LEFT", 2, 2),
+    ("LENGTH", 1, 1),
+    ("LOWER", 1, 1),
+    ("MAX", 1, usize::MAX),
+    ("MIN", 1, usize::MAX),
+    ("MONTH", 1, 1),
+    ("NOW", 0, 0),
+    ("REPLACE", 3, 3),
+    ("RIGHT", 2, 2),
+    ("ROUND", 1, 2),
+    ("SLUG", 1, 1),
+    ("SUBSTR", 2, 3),
+    ("TODAY", 0, 0),
+    ("TRIM", 1, 1),
+    ("UPPER", 1, 1),
+    ("YEAR", 1, 1),
+];
+
+impl std::fmt::Display for ExpressionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ExpressionValue::Null => write!(f, "NULL"),
+            ExpressionValue::Bool(value) => write!(f, "{}", value),
+            // Whole numbers are written without decimals, others without trailing zeros
+            ExpressionValue::Number(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                write!(f, "{}", *value as i64)
+            }
+            ExpressionValue::Number(value) => {
+                let value = format!("{:.10}", value);
+                write!(f, "{}", value.trim_end_matches('0').trim_end_matches('.'))
+            }
+            ExpressionValue::Text(value) => write!(f, "{}", value),
+            ExpressionValue::Date(value) => write!(f, "{}", value),
+            ExpressionValue::Timestamp(value) => write!(f, "{}", value.format("%Y-%m-%d %H:%M:%S")),
+        }
+    }
+}
+
+fn tokenize_expression(expression: &str) -> Result<Vec<String>, String> {
+    /*
+        * Splits an expression into tokens
+        * Example: "round(price * 1.2, 2) || ' EUR'" -> [round, (, price, *, 1.2, ",", 2, ), ||, ' EUR']
+        * 'text' is a string and "a column" is a column name, a doubled quote inside either is a literal quote
+
+        :parameters:
+            - `expression`: The expression inside of EXPR(...)
+
+        :returns:
+            - `Result<Vec<String>, String>`: The tokens, or the reason the expression can not be read
+    */
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens: Vec<String> = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+        if c.is_whitespace() {
+            index += 1;
+        } else if c == '\'' || c == '"' {
+            // Quoted tokens keep their quotes so strings and column names can be told apart
+            let mut token = c.to_string();
+            index += 1;
+            loop {
+                match chars.get(index) {
+                    None => return Err(format!("Unclosed Quote In {}", expression)),
+                    Some(&quote) if quote == c && chars.get(index + 1) == Some(&c) => {
+                        token.push(c);
+                        index += 2;
+                    }
+                    Some(&quote) if quote == c => {
+                        token.push(c);
+                        index += 1;
+                        break;
+                    }
+                    Some(&other) => {
+                        token.push(other);
+                        index += 1;
+                    }
+                }
+            }
+            tokens.push(token);
+        } else if c.is_alphanumeric() || c == '_' || c == '.' {
+            let start = index;
+            while index < chars.len()
+                && (chars[index].is_alphanumeric() || chars[index] == '_' || chars[index] == '.')
+            {
+                index += 1;
+            }
+            tokens.push(chars[start..index].iter().collect());
+        } else {
+            let pair: String = chars[index..(index + 2).min(chars.len())].iter().collect();
+            if ["<=", ">=", "!=", "<>", "==", "||"].contains(&pair.as_str()) {
+                tokens.push(pair);
+                index += 2;
+            } else if "+-*/%()<>=,".contains(c) {
+                tokens.push(c.to_string());
+                index += 1;
+            } else {
+                return Err(format!("Unexpected '{}' In {}", c, expression));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn parse_expression(expression: &str) -> Result<Expression, String> {
+    /*
+        * Parses the expression of an EXPR(...) column
+        * Columns generated earlier in the row are used by name: EXPR(quantity * unit_price)
+        * Operators: + - * / %, || (join text), = != < <= > >=, AND, OR, NOT
+        * Dates: date + days, date - days, date - date (days between). See EXPRESSION_FUNCTIONS for the functions
+
+        :parameters:
+            - `expression`: The expression inside of EXPR(...)
+
+        :returns:
+            - `Result<Expression, String>`: The parsed expression, or the reason it is invalid
+    */
+    let tokens = tokenize_expression(expression)?;
+    if tokens.is_empty() {
+        return Err("Empty Expression".to_string());
+    }
+
+    let mut position = 0;
+    let parsed = parse_operators(&tokens, &mut position, 0)?;
+    match tokens.get(position) {
+        Some(token) => Err(format!("Unexpected '{}' In {}", token, expression)),
+        None => Ok(parsed),
+    }
+}
+
+fn parse_operators(
+    tokens: &[String],
+    position: &mut usize,
+    level: usize,
+) -> Result<Expression, String> {
+    /*
+        * Parses the operators of one level of EXPRESSION_OPERATORS, left to right
+        * Each side is parsed with the next (tighter binding) level
+
+        :parameters:
+            - `tokens`: The tokens of the expression
+            - `position`: The index of the next token, moved past the parsed tokens
+            - `level`: The index in EXPRESSION_OPERATORS
+
+        :returns:
+            - `Result<Expression, String>`: The parsed expression, or the reason it is invalid
+    */
+    if level == EXPRESSION_OPERATORS.len() {
+        return parse_operand(tokens, position);
+    }
+
+    let mut left = parse_operators(tokens, position, level + 1)?;
+    while let Some(token) = tokens.get(*position) {
+        // IN and BETWEEN bind like the comparisons: x NOT IN (1, 2), x BETWEEN 1 AND 10
+        if EXPRESSION_OPERATORS[level].contains(&"=") {
+            if let Some(membership) = parse_membership(tokens, position, &left, level + 1)? {
+                left = membership;
+                continue;
+            }
+        }
+
+        let operator = token.to_uppercase();
+        if !EXPRESSION_OPERATORS[level].contains(&operator.as_str()) {
+            break;
+        }
+        *position += 1;
+        let right = parse_operators(tokens, position, level + 1)?;
+        left = Expression::Binary(operator, Box::new(left), Box::new(right));
+    }
+    Ok(left)
+}
+
+fn parse_membership(
+    tokens: &[String],
+    position: &mut usize,
+    left: &Expression,
+    level: usize,
+) -> Result<Option<Expression>, String> {
+    /*
+        * Parses [NOT] IN (value, ...) or [NOT] BETWEEN low AND high after the left side of a comparison
+        * BETWEEN becomes left >= low AND left <= high
+
+        :parameters:
+            - `tokens`: The tokens of the expression
+            - `position`: The index of the next token, moved past the parsed tokens
+            - `left`: The already parsed left side
+            - `level`: The level of EXPRESSION_OPERATORS used for the bounds of BETWEEN
+
+        :returns:
+            - `Result<Option<Expression>, String>`: The parsed expression, None if the next tokens are not IN / BETWEEN
+    */
+    let keyword = |index: usize| tokens.get(index).map(|token| token.to_uppercase());
+    let negated = keyword(*position).as_deref() == Some("NOT");
+    let start = *position + negated as usize;
+
+    let membership = match keyword(start).as_deref() {
+        Some("IN") => {
+            *position = start + 1;
+            if keyword(*position).as_deref() != Some("(") {
+                return Err("Missing '(' After IN".to_string());
+            }
+            *position += 1;
+
+            let mut arguments: Vec<Expression> = vec![left.clone()];
+            loop {
+                arguments.push(parse_operators(tokens, position, 0)?);
+                match keyword(*position).as_deref() {
+                    Some(",") => *position += 1,
+                    Some(")") => {
+                        *position += 1;
+                        break;
+                    }
+                    _ => return Err("Missing ')' After The Values Of IN".to_string()),
+                }
+            }
+            Expression::Function("IN".to_string(), arguments)
+        }
+        Some("BETWEEN") => {
+            *position = start + 1;
+            let low = parse_operators(tokens, position, level)?;
+            if keyword(*position).as_deref() != Some("AND") {
+                return Err("Missing AND After BETWEEN".to_string());
+            }
+            *position += 1;
+            let high = parse_operators(tokens, position, level)?;
+
+            Expression::Binary(
+                "AND".to_string(),
+                Box::new(Expression::Binary(">=".to_string(), Box::new(left.clone()), Box::new(low))),
+                Box::new(Expression::Binary("<=".to_string(), Box::new(left.clone()), Box::new(high))),
+            )
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(match negated {
+        true => Expression::Unary("NOT".to_string(), Box::new(membership)),
+        false => membership,
+    }))
+}
+
+fn parse_operand(tokens: &[String], position: &mut usize) -> Result<Expression, String> {
+    /*
+        * Parses a value: a literal, a column, a function call, a parenthesized expression, NOT x or -x
+
+        :parameters:
+            - `tokens`: The tokens of the expression
+            - `position`: The index of the next token, moved past the parsed tokens
+
+        :returns:
+            - `Result<Expression, String>`: The parsed expression, or the reason it is invalid
+    */
+    let token = match tokens.get(*position) {
+        Some(token) => token.clone(),
+        None => return Err("Expression Ends Too Early".to_string()),
+    };
+    *position += 1;
+
+    match token.to_uppercase().as_str() {
+        "(" => {
+            let inner = parse_operators(tokens, position, 0)?;
+            match tokens.get(*position).map(|s| s.as_str()) {
+                Some(")") => {
+                    *position += 1;
+                    Ok(inner)
+                }
+                _ => Err("Missing ')'".to_string()),
+            }
+        }
+        // NOT applies to a whole comparison: NOT a = b -> NOT (a = b)
+        "NOT" => Ok(Expression::Unary(
+            "NOT".to_string(),
+            Box::new(parse_operators(tokens, position, 2)?),
+        )),
+        "-" => Ok(Expression::Unary("-".to_string(), Box::new(parse_operand(tokens, position)?))),
+        "NULL" => Ok(Expression::Literal(ExpressionValue::Null)),
+        "TRUE" => Ok(Expression::Literal(ExpressionValue::Bool(true))),
+        "FALSE" => Ok(Expression::Literal(ExpressionValue::Bool(false))),
+        // Strings written as dates ('2024-12-25') are dates, other strings stay text
+        _ if token.starts_with('\'') => Ok(Expression::Literal(
+            match get_expression_value(&token[1..token.len() - 1]) {
+                date @ (ExpressionValue::Date(_) | ExpressionValue::Timestamp(_)) => date,
+                _ => ExpressionValue::Text(token[1..token.len() - 1].to_string()),
+            },
+        )),
+        _ if token.starts_with('"') => Ok(Expression::Column(token[1..token.len() - 1].to_string())),
+        _ if token.starts_with(|c: char| c.is_ascii_digit() || c == '.') => match token.parse::<f64>() {
+            Ok(number) => Ok(Expression::Literal(ExpressionValue::Number(number))),
+            Err(_) => Err(format!("Invalid Number '{}'", token)),
+        },
+        _ if token.starts_with(|c: char| c.is_alphanumeric() || c == '_') => {
+            if tokens.get(*position).map(|s| s.as_str()) != Some("(") {
+                return Ok(Expression::Column(token));
+            }
+            *position += 1;
+
+            // Function call, arguments are separated by commas
+            let name = token.to_uppercase();
+            let mut arguments: Vec<Expression> = Vec::new();
+            if tokens.get(*position).map(|s| s.as_str()) == Some(")") {
+                *position += 1;
+            } else {
+                loop {
+                    arguments.push(parse_operators(tokens, position, 0)?);
+                    match tokens.get(*position).map(|s| s.as_str()) {
+                        Some(",") => *position += 1,
+                        Some(")") => {
+                            *position += 1;
+                            break;
+                        }
+                        _ => return Err(format!("Missing ')' After The Arguments Of {}", name)),
+                    }
+                }
+            }
+
+            match EXPRESSION_FUNCTIONS.iter().find(|(function, _, _)| *function == name) {
+                Some((_, min, max)) if (*min..=*max).contains(&arguments.len()) => {
+                    Ok(Expression::Function(name, arguments))
+                }
+                Some(_) => Err(format!("Wrong Number Of Arguments For {}", name)),
+                None => Err(format!("Unknown Function {}", name)),
+            }
+        }
+        _ => Err(format!("Unexpected '{}'", token)),
+    }
+}
+
+fn get_expression_columns(expression: &Expression, columns: &mut Vec<String>) {
+    /*
+        * Collects the names of the columns used by an expression
+
+        :parameters:
+            - `expression`: The parsed expression
+            - `columns`: The column names found so far, new names are pushed to it
+    */
+    match expression {
+        Expression::Column(column) if !columns.contains(column) => columns.push(column.clone()),
+        Expression::Unary(_, operand) => get_expression_columns(operand, columns),
+        Expression::Binary(_, left, right) => {
+            get_expression_columns(left, columns);
+            get_expression_columns(right, columns);
+        }
+        Expression::Function(_, arguments) => {
+            for argument in arguments {
+                get_expression_columns(argument, columns);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_expression_value(data: &str) -> ExpressionValue {
+    /*
+        * Reads generated data as an expression value
+        * Example: '12.5' -> Number, '2023-04-01' -> Date, 'NULL' -> Null, 'Bob' -> Text
+
+        :parameters:
+            - `data`: The generated data of a column
+
+        :returns:
+            - `ExpressionValue`: The value
+    */
+    let data = data.trim();
+    if data.eq_ignore_ascii_case("NULL") {
+        return ExpressionValue::Null;
+    }
+    if data.eq_ignore_ascii_case("TRUE") || data.eq_ignore_ascii_case("FALSE") {
+        return ExpressionValue::Bool(data.eq_ignore_ascii_case("TRUE"));
+    }
+    // Numbers written with a leading zero (zip codes, etc) stay text, like in format_sql_value
+    if !format_sql_value(data, "").starts_with('\'') {
+        if let Ok(number) = data.parse::<f64>() {
+            return ExpressionValue::Number(number);
+        }
+    }
+    if let Ok(timestamp) = NaiveDateTime::parse_from_str(data, "%Y-%m-%d %H:%M:%S") {
+        return ExpressionValue::Timestamp(timestamp);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(data, "%Y-%m-%d") {
+        return ExpressionValue::Date(date);
+    }
+    ExpressionValue::Text(data.to_string())
+}
+
+fn compare_expression_values(
+    left: &ExpressionValue,
+    right: &ExpressionValue,
+) -> Option<std::cmp::Ordering> {
+    /*
+        * Compares two expression values
+        * Numbers, dates and booleans compare by value, a date compares to a timestamp at midnight
+        * Anything else compares as text
+
+        :parameters:
+            - `left`: The left value
+            - `right`: The right value
+
+        :returns:
+            - `Option<Ordering>`: The ordering, None when a number can not be compared (NaN)
+    */
+    match (left, right) {
+        (ExpressionValue::Number(left), ExpressionValue::Number(right)) => left.partial_cmp(right),
+        (ExpressionValue::Bool(left), ExpressionValue::Bool(right)) => Some(left.cmp(right)),
+        (ExpressionValue::Date(left), ExpressionValue::Date(right)) => Some(left.cmp(right)),
+        (ExpressionValue::Timestamp(left), ExpressionValue::Timestamp(right)) => {
+            Some(left.cmp(right))
+        }
+        (ExpressionValue::Date(left), ExpressionValue::Timestamp(right)) => {
+            Some(left.and_hms_opt(0, 0, 0).unwrap().cmp(right))
+        }
+        (ExpressionValue::Timestamp(left), ExpressionValue::Date(right)) => {
+            Some(left.cmp(&right.and_hms_opt(0, 0, 0).unwrap()))
+        }
+        _ => Some(left.to_string().cmp(&right.to_string())),
+    }
+}
+
+fn is_expression_true(value: &ExpressionValue) -> bool {
+    /*
+        * Gets the truth of a value used as a condition
+        * NULL, FALSE, 0 and empty text are false, everything else is true
+    */
+    match value {
+        ExpressionValue::Null => false,
+        ExpressionValue::Bool(value) => *value,
+        ExpressionValue::Number(value) => *value != 0.0,
+        ExpressionValue::Text(value) => !value.is_empty(),
+        _ => true,
+    }
+}
+
+fn add_to_date(value: &ExpressionValue, amount: f64, unit: &str) -> ExpressionValue {
+    /*
+        * Adds an amount of a unit to a date or timestamp (a negative amount subtracts)
+        * Units: days (default), months, years, hours, minutes, seconds
+        * A date given hours, minutes or seconds becomes a timestamp
+
+        :parameters:
+            - `value`: The date or timestamp
+            - `amount`: The amount to add
+            - `unit`: The unit of the amount
+
+        :returns:
+            - `ExpressionValue`: The new date or timestamp, NULL if value is not a date or the unit is unknown
+    */
+    let timestamp = match value {
+        ExpressionValue::Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+        ExpressionValue::Timestamp(timestamp) => *timestamp,
+        _ => return ExpressionValue::Null,
+    };
+
+    let unit = unit.to_lowercase();
+    let unit = unit.trim_end_matches('s');
+    let result = match unit {
+        "day" => timestamp.checked_add_signed(chrono::Duration::seconds((amount * 86_400.0) as i64)),
+        "hour" => timestamp.checked_add_signed(chrono::Duration::seconds((amount * 3_600.0) as i64)),
+        "minute" => timestamp.checked_add_signed(chrono::Duration::seconds((amount * 60.0) as i64)),
+        "second" => timestamp.checked_add_signed(chrono::Duration::seconds(amount as i64)),
+        "month" | "year" => {
+            let months = match unit {
+                "year" => amount as i64 * 12,
+                _ => amount as i64,
+            };
+            match months >= 0 {
+                true => timestamp.checked_add_months(chrono::Months::new(months as u32)),
+                false => timestamp.checked_sub_months(chrono::Months::new(months.unsigned_abs() as u32)),
+            }
+        }
+        _ => None,
+    };
+
+    match (result, value) {
+        (None, _) => ExpressionValue::Null,
+        // Dates stay dates unless a part of a day was added
+        (Some(result), ExpressionValue::Date(_)) if result.time() == chrono::NaiveTime::MIN => {
+            ExpressionValue::Date(result.date())
+        }
+        (Some(result), _) => ExpressionValue::Timestamp(result),
+    }
+}
+
+fn evaluate_expression(
+    expression: &Expression,
+    statement_data: &HashMap<String, String>,
+) -> ExpressionValue {
+    /*
+        * Evaluates an expression against the row being generated
+        * Columns that were not generated (or are NULL) are NULL, and NULL in an operation gives NULL
+        * Operations that make no sense (i.e text * 2, division by 0) give NULL as well
+
+        :parameters:
+            - `expression`: The parsed expression
+            - `statement_data`: The hashmap of generated data for the row
+
+        :returns:
+            - `ExpressionValue`: The value of the expression
+    */
+    use ExpressionValue::*;
+
+    match expression {
+        Expression::Literal(value) => value.clone(),
+        Expression::Column(column) => match statement_data.get(column) {
+            Some(data) => get_expression_value(data),
+            None => Null,
+        },
+        Expression::Unary(operator, operand) => {
+            match (operator.as_str(), evaluate_expression(operand, statement_data)) {
+                (_, Null) => Null,
+                ("NOT", value) => Bool(!is_expression_true(&value)),
+                ("-", Number(value)) => Number(-value),
+                _ => Null,
+            }
+        }
+        Expression::Binary(operator, left, right) => {
+            let left = evaluate_expression(left, statement_data);
+            let right = evaluate_expression(right, statement_data);
+
+            match (operator.as_str(), left, right) {
+                ("AND", left, right) => Bool(is_expression_true(&left) && is_expression_true(&right)),
+                ("OR", left, right) => Bool(is_expression_true(&left) || is_expression_true(&right)),
+                (_, Null, _) | (_, _, Null) => Null,
+                ("||", left, right) => Text(format!("{}{}", left, right)),
+                ("=" | "==" | "!=" | "<>" | "<" | "<=" | ">" | ">=", left, right) => {
+                    match compare_expression_values(&left, &right) {
+                        Some(ordering) => Bool(match operator.as_str() {
+                            "=" | "==" => ordering.is_eq(),
+                            "!=" | "<>" => ordering.is_ne(),
+                            "<" => ordering.is_lt(),
+                            "<=" => ordering.is_le(),
+                            ">" => ordering.is_gt(),
+                            _ => ordering.is_ge(),
+                        }),
+                        None => Null,
+                    }
+                }
+                (_, Number(left), Number(right)) => match operator.as_str() {
+                    "+" => Number(left + right),
+                    "-" => Number(left - right),
+                    "*" => Number(left * right),
+                    "/" if right != 0.0 => Number(left / right),
+                    "%" if right != 0.0 => Number(left % right),
+                    _ => Null,
+                },
+                // Date math, numbers are days
+                ("+", date @ (Date(_) | Timestamp(_)), Number(days))
+                | ("+", Number(days), date @ (Date(_) | Timestamp(_))) => add_to_date(&date, days, "days"),
+                ("-", date @ (Date(_) | Timestamp(_)), Number(days)) => add_to_date(&date, -days, "days"),
+                ("-", left @ (Date(_) | Timestamp(_)), right @ (Date(_) | Timestamp(_))) => {
+                    let to_timestamp = |value: ExpressionValue| match value {
+                        Date(date) => date.and_hms_opt(0, 0, 0).unwrap(),
+                        Timestamp(timestamp) => timestamp,
+                        _ => unreachable!(),
+                    };
+                    let seconds = (to_timestamp(left) - to_timestamp(right)).num_seconds();
+                    Number(seconds as f64 / 86_400.0)
+                }
+                // Adding text joins it, like ||
+                ("+", left @ Text(_), right) | ("+", left, right @ Text(_)) => {
+                    Text(format!("{}{}", left, right))
+                }
+                _ => Null,
+            }
+        }
+        Expression::Function(name, arguments) => {
+            let values: Vec<ExpressionValue> = arguments
+                .iter()
+                .map(|argument| evaluate_expression(argument, statement_data))
+                .collect();
+            let number = |index: usize| match values.get(index) {
+                Some(Number(number)) => Some(*number),
+                _ => None,
+            };
+            let text = |index: usize| values[index].to_string();
+
+            match name.as_str() {
+                "IF" => match is_expression_true(&values[0]) {
+                    true => values[1].clone(),
+                    false => values[2].clone(),
+                },
+                "COALESCE" => values.iter().find(|value| **value != Null).cloned().unwrap_or(Null),
+                "CONCAT" => Text(
+                    values
+                        .iter()
+                        .filter(|value| **value != Null)
+                        .map(|value| value.to_string())
+                        .collect(),
+                ),
+                "MIN" | "MAX" => values
+                    .iter()
+                    .filter(|value| **value != Null)
+                    .cloned()
+                    .reduce(|best, value| {
+                        let ordering = compare_expression_values(&value, &best);
+                        match (name.as_str(), ordering) {
+                            ("MIN", Some(std::cmp::Ordering::Less)) => value,
+                            ("MAX", Some(std::cmp::Ordering::Greater)) => value,
+                            _ => best,
+                        }
+                    })
+                    .unwrap_or(Null),
+                "IN" => match &values[0] {
+                    Null => Null,
+                    value => Bool(values[1..].iter().any(|item| {
+                        compare_expression_values(value, item) == Some(std::cmp::Ordering::Equal)
+                    })),
+                },
+                "TODAY" => Date(row_now().date()),
+                "NOW" => Timestamp(row_now()),
+                _ if values.contains(&Null) => Null,
+                "LOWER" => Text(text(0).to_lowercase()),
+                "UPPER" => Text(text(0).to_uppercase()),
+                "TRIM" => Text(text(0).trim().to_string()),
+                "LENGTH" => Number(text(0).chars().count() as f64),
+                "SLUG" => {
+                    // 'Hello, World!' -> hello-world
+                    let slug: String = text(0)
+                        .to_lowercase()
+                        .chars()
+                        .map(|c| match c.is_alphanumeric() {
+                            true => c,
+                            false => '-',
+                        })
+                        .collect();
+                    Text(
+                        slug.split('-')
+                            .filter(|word| !word.is_empty())
+                            .collect::<Vec<&str>>()
+                            .join("-"),
+                    )
+                }
+                "SUBSTR" => match (number(1), values.get(2).map(|_| number(2))) {
+                    // 1-based start like SQL, the length is optional
+                    (Some(start), length) if length != Some(None) => {
+                        let skipped = (start.max(1.0) as usize) - 1;
+                        let length = length.flatten().map_or(usize::MAX, |length| length.max(0.0) as usize);
+                        Text(text(0).chars().skip(skipped).take(length).collect())
+                    }
+                    _ => Null,
+                },
+                "LEFT" | "RIGHT" => match number(1) {
+                    Some(count) => {
+                        let chars: Vec<char> = text(0).chars().collect();
+                        let count = (count.max(0.0) as usize).min(chars.len());
+                        Text(match name.as_str() {
+                            "LEFT" => chars[..count].iter().collect(),
+                            _ => chars[chars.len() - count..].iter().collect(),
+                        })
+                    }
+                    None => Null,
+                },
+                "REPLACE" => Text(text(0).replace(&text(1), &text(2))),
+                "ROUND" => match (number(0), values.get(1).map_or(Some(0.0), |_| number(1))) {
+                    (Some(value), Some(places)) => {
+                        let factor = 10_f64.powi(places as i32);
+                        Number((value * factor).round() / factor)
+                    }
+                    _ => Null,
+                },
+                "FLOOR" => number(0).map_or(Null, |value| Number(value.floor())),
+                "CEIL" => number(0).map_or(Null, |value| Number(value.ceil())),
+                "ABS" => number(0).map_or(Null, |value| Number(value.abs())),
+                "DATE_ADD" => match number(1) {
+                    Some(amount) => {
+                        let unit = values.get(2).map_or("days".to_string(), |unit| unit.to_string());
+                        add_to_date(&values[0], amount, &unit)
+                    }
+                    None => Null,
+                },
+                "DATE_DIFF" => evaluate_expression(
+                    &Expression::Binary(
+                        "-".to_string(),
+                        Box::new(Expression::Literal(values[0].clone())),
+                        Box::new(Expression::Literal(values[1].clone())),
+                    ),
+                    statement_data,
+                ),
+                "YEAR" | "MONTH" | "DAY" => {
+                    let date = match &values[0] {
+                        Date(date) => *date,
+                        Timestamp(timestamp) => timestamp.date(),
+                        _ => return Null,
+                    };
+                    Number(match name.as_str() {
+                        "YEAR" => date.year(),
+                        "MONTH" => date.month() as i32,
+                        _ => date.day() as i32,
+                    } as f64)
+                }
+                _ => Null,
+            }
+        }
+    }
+}
+
+fn get_table_check(attribute: &str) -> Option<&str> {
+    /*
+        * Gets the expression of a table's CHECK constraint
+        * Example: 'CHECK (end_date >= start_date)' -> 'end_date >= start_date', 'check_in DATE' -> None
+
+        :parameters:
+            - `attribute`: An element of the table's attributes
+
+        :returns:
+            - `Option<&str>`: The expression inside of CHECK (...), None if the element is not a CHECK constraint
+    */
+    let attribute = attribute.trim();
+    match attribute.get(..5) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("CHECK") => {
+            attribute[5..].trim().strip_prefix('(')?.strip_suffix(')')
+        }
+        _ => None,
+    }
+}
+
+fn get_composite_reference(attribute: &str) -> Option<CompositeReference> {
+    /*
+        * Gets a foreign key over several attributes
+        * Example: 'FK (order_id, line_no) order_line(order_id, line_no)'
+        * Becomes: CompositeReference { FK, [order_id, line_no], order_line, [order_id, line_no] }
+        * The attributes take the values of one parent row together, their types are the types of the parent
+
+        :parameters:
+            - `attribute`: An element of the table's attributes
+
+        :returns:
+            - `Option<CompositeReference>`: The reference, None if the element is not a foreign key followed by (attribute, ...)
+    */
+    let split_list = |list: &str| list.split(',').map(|column| column.trim().to_string()).collect::<Vec<String>>();
+
+    let (key_definition, rest) = attribute.trim().split_once(char::is_whitespace)?;
+    if !key_definition.to_uppercase().ends_with("FK") {
+        return None;
+    }
+    let (columns, reference) = rest.trim().strip_prefix('(')?.split_once(')')?;
+    let (referenced_table, referenced_columns) = reference.trim().split_once('(')?;
+    Some(CompositeReference {
+        key_definition: key_definition.to_uppercase(),
+        columns: split_list(columns),
+        referenced_table: referenced_table.trim().to_string(),
+        referenced_columns: split_list(referenced_columns.trim().strip_suffix(')')?),
+    })
+}
+
+fn get_table_unique(attribute: &str) -> Option<Vec<String>> {
+    /*
+        * Gets the attributes of a table's UNIQUE constraint
+        * Example: 'UNIQUE (org_id, slug)' -> ['org_id', 'slug'], 'unique_code VARCHAR(8)' -> None
+
+        :parameters:
+            - `attribute`: An element of the table's attributes
+
+        :returns:
+            - `Option<Vec<String>>`: The trimmed attributes inside of UNIQUE (...), None if the element is not a UNIQUE constraint
+    */
+    let attribute = attribute.trim();
+    match attribute.get(..6) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("UNIQUE") => {
+            let columns = attribute[6..].trim().strip_prefix('(')?.strip_suffix(')')?;
+            Some(columns.split(',').map(|column| column.trim().to_string()).collect())
+        }
+        _ => None,
+    }
+}
+
+fn get_table_pairs(attribute: &str) -> Option<&str> {
+    /*
+        * Gets the rules of a table's PAIRS constraint
+        * Example: 'PAIRS (directed, self)' -> 'directed, self', 'pairs_count INTEGER' -> None
+
+        :parameters:
+            - `attribute`: An element of the table's attributes
+
+        :returns:
+            - `Option<&str>`: The rules inside of PAIRS (...), None if the element is not a PAIRS constraint
+    */
+    let attribute = attribute.trim();
+    match attribute.get(..5) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("PAIRS") => {
+            attribute[5..].trim().strip_prefix('(')?.strip_suffix(')')
+        }
+        _ => None,
+    }
+}
+
+fn parse_pair_rules(arguments: &str) -> Result<PairRules, String> {
+    /*
+        * Parses the rules of PAIRS (...): symmetric (default) or directed, and self or no self (default)
+        * Example: 'directed, self' -> PairRules { directed: true, self_pairs: true }
+
+        :parameters:
+            - `arguments`: The text inside of PAIRS (...)
+
+        :returns:
+            - `Result<PairRules, String>`: The rules, or the reason they are invalid
+    */
+    let mut rules = PairRules::default();
+    let mut given: Vec<&str> = Vec::new();
+    for rule in arguments.split(',').map(|rule| rule.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase()) {
+        let (setting, value) = match rule.as_str() {
+            "symmetric" => ("symmetric", false),
+            "directed" => ("symmetric", true),
+            "self" => ("self", true),
+            "no self" | "noself" => ("self", false),
+            _ => return Err(format!("Unknown Rule '{}', Use symmetric Or directed And self Or no self", rule)),
+        };
+        if given.contains(&setting) {
+            return Err(format!("The {} Rule Is Given Twice", setting));
+        }
+        given.push(setting);
+        match setting {
+            "symmetric" => rules.directed = value,
+            _ => rules.self_pairs = value,
+        }
+    }
+    Ok(rules)
+}
+
+fn get_check_ranges(checks: &[Expression]) -> HashMap<String, ColumnOptions> {
+    /*
+        * Infers the bounds and values of columns from simple CHECK constraints so they are generated valid
+        * Handles column <op> number, column = value, column IN (...) and BETWEEN joined by AND
+        * Anything else (OR, NOT, comparing two columns) is left to the row check in generate_mock_data
+
+        :parameters:
+            - `checks`: The parsed CHECK constraints of a table
+
+        :returns:
+            - `HashMap<String, ColumnOptions>`: The minimum, maximum and allowed values of each bounded column
+    */
+    let literal = |expression: &Expression| match expression {
+        Expression::Literal(ExpressionValue::Null) => None,
+        Expression::Literal(value) => Some(value.clone()),
+        Expression::Unary(operator, operand) if operator == "-" => match operand.as_ref() {
+            Expression::Literal(ExpressionValue::Number(number)) => Some(ExpressionValue::Number(-number)),
+            _ => None,
+        },
+        _ => None,
+    };
+    let restrict = |range: &mut ColumnOptions, values: Vec<String>| match range.allowed_values.is_empty() {
+        true => range.allowed_values = values,
+        false => range.allowed_values.retain(|value| values.contains(value)),
+    };
+
+    let mut ranges: HashMap<String, ColumnOptions> = HashMap::new();
+    let mut conditions: Vec<&Expression> = checks.iter().collect();
+    while let Some(condition) = conditions.pop() {
+        match condition {
+            Expression::Binary(operator, left, right) if operator == "AND" => {
+                conditions.push(left);
+                conditions.push(right);
+            }
+            Expression::Binary(operator, left, right) => {
+                // number < column is read as column > number
+                let (column, operator, value) = match (left.as_ref(), literal(left), right.as_ref(), literal(right)) {
+                    (Expression::Column(column), None, _, Some(value)) => (column, operator.as_str(), value),
+                    (_, Some(value), Expression::Column(column), None) => {
+                        let operator = match operator.as_str() {
+                            "<" => ">",
+                            "<=" => ">=",
+                            ">" => "<",
+                            ">=" => "<=",
+                            operator => operator,
+                        };
+                        (column, operator, value)
+                    }
+                    _ => continue,
+                };
+
+                let range = ranges.entry(column.clone()).or_default();
+                match (operator, value) {
+                    ("=" | "==", value) => restrict(range, vec![value.to_string()]),
+                    (">" | ">=", ExpressionValue::Number(bound)) => {
+                        let inclusive = operator == ">=";
+                        range.minimum = match range.minimum {
+                            Some((current, current_inclusive))
+                                if current > bound || (current == bound && !current_inclusive) =>
+                            {
+                                Some((current, current_inclusive))
+                            }
+                            _ => Some((bound, inclusive)),
+                        };
+                    }
+                    ("<" | "<=", ExpressionValue::Number(bound)) => {
+                        let inclusive = operator == "<=";
+                        range.maximum = match range.maximum {
+                            Some((current, current_inclusive))
+                                if current < bound || (current == bound && !current_inclusive) =>
+                            {
+                                Some((current, current_inclusive))
+                            }
+                            _ => Some((bound, inclusive)),
+                        };
+                    }
+                    _ => {}
+                }
+            }
+            Expression::Function(name, arguments) if name == "IN" => {
+                if let [Expression::Column(column), values @ ..] = arguments.as_slice() {
+                    let values: Option<Vec<String>> =
+                        values.iter().map(|value| literal(value).map(|value| value.to_string())).collect();
+                    if let Some(values) = values {
+                        restrict(ranges.entry(column.clone()).or_default(), values);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+fn set_variable_size(attr_type: &str) -> Option<Vec<u16>> {
+    /*
+        Improved set_variable_size function
+        Still performs the same functionality as the old function
+        However this function is able to account for decimal values
+        i.e MONEY(10,2) -> 10 is the total number of digits, 2 is the number of digits after the decimal point
+        i.e DECIMAL(8,4) -> 8 is the total number of digits, 4 is the number of digits after the decimal point
+
+        :parameters:
+            - `attr_type`: The type of the attribute
+
+        :returns:
+            - `Option<Vec<u16>>`: The variable size for the attribute returned as a vec
+            -  Vec[0] -> Original variable size similar to old function, or number of digits before decimal point
+            -  Vec[1] -> Number of digits after decimal point (usually the only reason for index 1 to exist)
+            -  None -> No variable size for the attribute
+    */
+    let some_returned_value: Option<Vec<u16>> = match &attr_type {
+        // The values and expressions of these types can name other types, i.e ONEOF('CHARLIE', 'MONEY')
+        s if s.starts_with("CONST") || s.starts_with("ONEOF") || s.starts_with("EXPR") => None,
+        //Check if s.contains("VARIABLE") [in cases of VARIABLE(n)] and s is not "VARIABLE"
+        s if s.contains("VARCHAR") && !(*s).eq("VARCHAR") => {
+            let variable_size_str = &attr_type[7..];
+            let variable_size_str: String =
+                variable_size_str[1..variable_size_str.len() - 1].to_string();
+            Some(vec![variable_size_str.parse().unwrap(), 0])
+        }
+        s if s.contains("CHAR") && !(*s).eq("CHAR") => {
+            let variable_size_str = &attr_type[4..];
+            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
+            Some(vec![variable_size_str.parse().unwrap(), 0])
+        }
+        s if (s.contains("PASSWORD") && !(*s).eq("PASSWORD"))
+            || (s.contains("USERNAME") && !(*s).eq("USERNAME")) =>
+            {
+                let variable_size_str = &attr_type[8..];
+                let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
+                Some(vec![variable_size_str.parse().unwrap(), 0])
+            }
+        s if s.contains("MONEY") && !(*s).eq("MONEY") => {
+            let variable_size_str = &attr_type[5..];
+            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
+            Some(vec![variable_size_str.parse().unwrap(), 0])
+        }
+        s if s.contains("DECIMAL") && !(*s).eq("DECIMAL") => {
+            let variable_size_str = &attr_type[7..];
+            let variable_size_str = &variable_size_str[1..variable_size_str.len() - 1];
+            let variable_size_str: Vec<&str> = variable_size_str.split(",").collect();
+            Some(vec![
+                variable_size_str[0].trim().parse().unwrap(),
+                variable_size_str[1].trim().parse().unwrap(),
+            ])
+        }
+        _ => None,
+    };
+    some_returned_value
+}
+
+fn open_file_sink(path: &str) -> io::Result<WriterSink<File>> {
+    /*
+        * Opens the file the insert statements are written to, once per run
+        * If the file does not exist, it is created
+        * If the file does exist, it is emptied first
+
+        :parameters:
+            - `path`: The path to write the generated statements to
+
+        :returns:
+            - `io::Result<WriterSink<File>>`: The buffered sink of the file, or the error opening it
+    */
+    Ok(WriterSink::new(File::create(path)?))
+}
+
+fn main() {
+    /*
+     * Main function for the program
+     * Takes command to input sql tables with their attributes to create mock data
+     * Able to produce mock data for multiple tables at once up
+     * Capable of Reference Integrity and Unique/Keyed Attributes
+     */
+
+    let mut custom_path: Option<String> = None;
+    let mut tables: Vec<String> = Vec::new();
+    let mut key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+    let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+    let mut settings = GenerationSettings::default();
+
+    // --scale 10, --seed 42, --threads 8, --batch 500, --dialect mysql and --columns off do the same as the commands
+    // of the same name
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    for (index, argument) in arguments.iter().enumerate() {
+        let Some((flag, value)) = ["--scale", "--seed", "--threads", "--batch", "--dialect", "--columns"].into_iter().find_map(|flag| {
+            let value = match argument.strip_prefix(flag)? {
+                "" => arguments.get(index + 1).map(|value| value.as_str()),
+                value => value.strip_prefix('='),
+            };
+            Some((flag, value))
+        }) else {
+            continue;
+        };
+        let set = match flag {
+            "--scale" => value.and_then(parse_scale).map(|scale| settings.scale = Some(scale)),
+            "--seed" => value.and_then(parse_seed).map(|seed| settings.seed = Some(seed)),
+            "--threads" => value.and_then(parse_threads).map(|threads| settings.threads = Some(threads)),
+            "--batch" => value.and_then(parse_batch).map(|batch| settings.batch = Some(batch)),
+            "--dialect" => value.and_then(parse_dialect).map(|dialect| settings.dialect = dialect),
+            _ => value.and_then(parse_columns).map(|columns| settings.positional = !columns),
+        };
+        if set.is_none() {
+            match flag {
+                "--scale" => println!("[!] --scale Needs A Number Above 0 (i.e --scale 10)"),
+                "--seed" => println!("[!] --seed Needs A Whole Number (i.e --seed 42)"),
+                "--threads" => println!("[!] --threads Needs A Whole Number Above 0 (i.e --threads 8)"),
+                "--batch" => println!("[!] --batch Needs A Whole Number Above 0 (i.e --batch 500)"),
+                "--dialect" => println!("[!] --dialect Needs One Of {} (i.e --dialect postgres)", dialect_names()),
+                _ => println!("[!] --columns Needs on Or off (i.e --columns off)"),
+            }
+            std::process::exit(1);
+        }
+    }
+
+    display_help(false);
+
+    loop {
+        print!("[*] Manage Tables --> ");
+        let _ = stdout().flush();
+        let mut sql_input: String = String::new();
+        io::stdin()
+            .read_line(&mut sql_input)
+            .expect("Failed to read SQL command");
+
+        match sql_input.trim().to_lowercase().as_str() {
+            // There's at least one table to generate mock data for
+            "generate" | "gen" | "run" if !tables.is_empty() => {
+                if custom_path.is_none() {
+                    let user_folder = dirs::home_dir().unwrap();
+                    //Set default path to users documents directory and create/overwrite file named sample-data.sql
+                    let documents_dir = user_folder.join("Documents");
+                    let file_path = documents_dir.join("sample-data.sql");
+                    custom_path = Some(file_path.to_str().unwrap().to_string());
+                }
+
+                // Rows of every table, relative row counts (3x) follow their parent and the rest are scaled
+                // Per-parent ranges (0-20x) draw their counts from a stream of their own, before any table
+                seed_row_rng(settings.seed, u64::MAX, SETUP_STREAM);
+                let row_counts =
+                    get_row_counts(&tables, &reference_dictionary, settings.scale.unwrap_or(1.0));
+                if let Err(problems) = check_key_spaces(&tables, &row_counts, &reference_dictionary, settings.locale) {
+                    for problem in problems {
+                        println!("[!] {}", problem);
+                    }
+                    println!("[!] Nothing Was Generated, Lower The Row Counts Or Widen The Types Above");
+                    continue;
+                }
+
+                let custom_path = custom_path.clone().unwrap();
+                let mut sink = match open_file_sink(&custom_path) {
+                    Ok(sink) => sink,
+                    Err(error) => {
+                        println!("[!] Unable To Open '{}': {}", custom_path, error);
+                        continue;
+                    }
+                };
+
+                println!("[*] Generating Mock Data...");
+                if let Err(error) = generate_mock_data(
+                    &tables,
+                    &key_dictionary,
+                    &reference_dictionary,
+                    &mut sink,
+                    &row_counts,
+                    &settings,
+                ) {
+                    // The tables are kept, so the run can be tried again once the disk, path or tables are fixed
+                    match &error {
+                        GenerationError::Write(_) => println!("\n[!] Unable To Write To '{}': {}", custom_path, error),
+                        GenerationError::Rows(_) => println!("\n[!] {}", error),
+                    }
+                    println!("[!] Generation Stopped, '{}' Only Holds The Rows Written Before", custom_path);
+                    continue;
+                }
+
+                //Tell user where the file is located
+                println!("\n[*] Mock Data Generated In '{}'", custom_path);
+                break;
+            }
+            // No tables to generate mock data for
+            "generate" | "gen" | "run" => {
+                println!("[!] No Tables to Generate Mock Data For");
+                continue;
+            }
+            // Clear the terminal
+            "clear" => {
+                print!("{}[2J", 27 as char);
+                continue;
+            }
+            // Display entire help menu
+            "help" => {
+                display_help(true);
+                continue;
+            }
+            // Exit the program
+            "exit" | "quit" => {
+                println!(
+                    "All tables and mock data will be lost. Are you sure you want to exit? (y/n)"
+                );
+                let _ = stdout().flush();
+                let mut input = String::new();
+                io::stdin()
+                    .read_line(&mut input)
+                    .expect("Failed to read SQL command");
+                if input.trim().to_lowercase().as_str() == "y" {
+                    println!("[*] Exiting...");
+                    break;
+                } else {
+                    continue;
+                }
+            }
+            _ => {
+                // Kept before the shlex split, which removes the quotes of CONST('...') / ONEOF('...')
+                let raw_input = sql_input.trim().to_string();
+                sql_input = sql_input.replace("\\", "\\\\");
+                let command_tokens = shlex::split(sql_input.trim()).unwrap_or_default();
+                let sql_command_list: Vec<&str> =
+                    command_tokens.iter().map(|x| x.as_str()).collect();
+                if sql_command_list.len() < 2 {
+                    println!("[!] Invalid SQL Command");
+                    continue;
+                }
+
+                match sql_command_list[0].to_lowercase().as_str() {
+                    "path" => {
+                        /*
+                            * Get Path From sql_command_list[1]
+                            * Then Check If Directory And If The File Already Exists.
+                            * If It Does, Ask To Overwrite.
+                            * If Not, Create The File
+                        */
+                        let path: &str = sql_command_list[1].trim();
+
+                        if std::path::Path::new(&path).exists() {
+                            println!("[!] File already exists. Overwrite? (y/n)");
+                            let _ = stdout().flush();
+                            let mut input = String::new();
+                            io::stdin()
+                                .read_line(&mut input)
+                                .expect("Failed to read SQL command");
+                            if input.trim().to_lowercase().as_str() != "y" {
+                                println!("[*] File not overwritten");
+                                continue;
+                            } else if let Err(error) = fs::write(path, "") {
+                                println!("[!] Unable To Overwrite '{}': {}", path, error);
+                                continue;
+                            } else {
+                                println!("[*] File overwritten");
+                            }
+                        } else if let Err(error) = fs::write(path, "") {
+                            println!("[!] Unable To Create '{}': {}", path, error);
+                            continue;
+                        } else {
+                            println!("[*] File created");
+                        }
+                        custom_path = Some(path.to_string());
+                    }
+                    "scale" => {
+                        // Multiplier of every row count added as a number, checked against the key spaces when generating
+                        match parse_scale(sql_command_list[1]) {
+                            Some(scale) => {
+                                settings.scale = Some(scale);
+                                println!("[*] Scale Set To {}", scale);
+                            }
+                            None => println!("[!] Scale Needs A Number Above 0 (i.e scale 10, scale 0.5)"),
+                        }
+                    }
+                    "seed" => {
+                        // Seed of every random value, the same seed and tables give the same file
+                        match parse_seed(sql_command_list[1]) {
+                            Some(seed) => {
+                                settings.seed = Some(seed);
+                                println!("[*] Seed Set To {}", seed);
+                            }
+                            None => println!("[!] Seed Needs A Whole Number (i.e seed 42)"),
+                        }
+                    }
+                    "threads" => {
+                        // Threads generating chunks of rows at once, the rows stay in order in the file
+                        match parse_threads(sql_command_list[1]) {
+                            Some(threads) => {
+                                settings.threads = Some(threads);
+                                println!("[*] Threads Set To {}", threads);
+                            }
+                            None => println!("[!] Threads Needs A Whole Number Above 0 (i.e threads 8)"),
+                        }
+                    }
+                    "batch" => {
+                        // Rows written in each insert statement, 1 writes a statement per row
+                        match parse_batch(sql_command_list[1]) {
+                            Some(batch) => {
+                                settings.batch = Some(batch);
+                                println!("[*] Batch Set To {}", batch);
+                            }
+                            None => println!("[!] Batch Needs A Whole Number Above 0 (i.e batch 500)"),
+                        }
+                    }
+                    "dialect" => {
+                        // Database the table and column names of the inserts are quoted for
+                        match parse_dialect(sql_command_list[1]) {
+                            Some(dialect) => {
+                                settings.dialect = dialect;
+                                println!("[*] Dialect Set To {}", dialect.name());
+                            }
+                            None => println!("[!] Unknown Dialect. The Dialects Are {}", dialect_names()),
+                        }
+                    }
+                    "columns" => {
+                        // Column lists of the inserts, off writes the values in the order of the attributes
+                        match parse_columns(sql_command_list[1]) {
+                            Some(columns) => {
+                                settings.positional = !columns;
+                                println!("[*] Column Lists Turned {}", if columns { "On" } else { "Off" });
+                            }
+                            None => println!("[!] Columns Needs on Or off (i.e columns off)"),
+                        }
+                    }
+                    "locale" => {
+                        // Default locale of every localized column without its own TYPE@locale
+                        match parse_locale(sql_command_list[1]) {
+                            Some(locale) => {
+                                settings.locale = locale;
+                                println!("[*] Locale Set To {}", locale.code());
+                            }
+                            None => {
+                                println!(
+                                    "[!] Unknown Locale. Type 'show locales' For The Supported Locales"
+                                );
+                            }
+                        }
+                    }
+                    "add" => {
+                        let mut valid: bool = true;
+                        // A number of rows (100), rows per parent row (3x) or a range per parent row (0-20x)
+                        let row_count = match parse_row_count(sql_command_list[1]) {
+                            Some(RowCount::Absolute(0)) => {
+                                println!("[!] Invalid Number of Iterations");
+                                continue;
+                            }
+                            Some(row_count) => row_count,
+                            None => {
+                                println!("[!] Error With Iteration Input (i.e add 100 profile, add 3x post, add 0-20x:profile post)");
+                                continue;
+                            }
+                        };
+
+                        // Everything from the first parenthesis on, taken from the raw input so quoted values are kept
+                        let table_attributes = match raw_input.find('(') {
+                            Some(start) => raw_input[start..].trim().to_string(),
+                            None => String::new(),
+                        };
+                        if table_attributes.len() < 2 || !table_attributes.ends_with(')') {
+                            println!("[!] Table Attributes Must Be Inside Parentheses");
+                            continue;
+                        }
+
+                        let attributes: String =
+                            table_attributes[1..table_attributes.len() - 1].to_string(); // Remove beginning and ending parentheses
+                        // Split on commas outside of parentheses, each element is trimmed
+                        let attributes = split_attributes(&attributes);
+
+                        println!("Attributes: {:?}", attributes);
+
+                        // Names of the attributes checked so far. FROM(...) may only reference these
+                        let mut previous_attributes: Vec<String> = Vec::new();
+                        // CHECK (...) and UNIQUE (...) constraints, checked once every attribute of the table is known
+                        let mut table_checks: Vec<&str> = Vec::new();
+                        let mut table_uniques: Vec<Vec<String>> = Vec::new();
+                        let mut table_pairs = 0;
+
+                        for attribute in &attributes {
+                            if let Some(check) = get_table_check(attribute) {
+                                table_checks.push(check);
+                                continue;
+                            }
+                            if let Some(columns) = get_table_unique(attribute) {
+                                table_uniques.push(columns);
+                                continue;
+                            }
+                            if let Some(rules) = get_table_pairs(attribute) {
+                                if let Err(reason) = parse_pair_rules(rules) {
+                                    println!("[!] Invalid PAIRS ({}): {}", rules, reason);
+                                    valid = false;
+                                    break;
+                                }
+                                table_pairs += 1;
+                                continue;
+                            }
+
+                            // FK (a, b) parent(a, b) -> The attributes are new, the referenced ones are keys of an added table
+                            if let Some(reference) = get_composite_reference(attribute) {
+                                let parent_keys = key_dictionary.get(&reference.referenced_table);
+                                if !check_key_definition(&reference.key_definition) {
+                                    println!("[!] Invalid Key Definition");
+                                    valid = false;
+                                    break;
+                                }
+                                if reference.referenced_columns.iter().any(|column| column.contains(['(', ')'])) {
+                                    println!("[!] {} Can Not Take NULL(...), FANOUT(...) Or Other Modifiers", attribute);
+                                    valid = false;
+                                    break;
+                                }
+                                if reference.columns.len() < 2 || reference.columns.len() != reference.referenced_columns.len() {
+                                    println!(
+                                        "[!] Invalid Reference {}: Give The Same Number (2 Or More) Of Attributes On Both Sides (i.e FK (order_id, line_no) order_line(order_id, line_no))",
+                                        attribute
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                                if let Some(column) = reference.columns.iter().enumerate().find(|(index, column)| {
+                                    column.is_empty()
+                                        || column.contains(char::is_whitespace)
+                                        || reference.columns[..*index].contains(column)
+                                        || previous_attributes.contains(column)
+                                }).map(|(_, column)| column) {
+                                    println!("[!] Invalid Attribute Name '{}' In {}", column, attribute);
+                                    valid = false;
+                                    break;
+                                }
+                                if parent_keys.is_none() {
+                                    println!("[!] Parent Table {} Must Be Added Before {}", reference.referenced_table, sql_command_list[2]);
+                                    valid = false;
+                                    break;
+                                }
+                                // PK/FK keys keep the case they were given in, the others are uppercase
+                                if let Some(column) = reference.referenced_columns.iter().find(|column| {
+                                    !parent_keys.unwrap().iter().any(|key| {
+                                        key.split_whitespace().nth(1).is_some_and(|name| name.eq_ignore_ascii_case(column))
+                                    })
+                                }) {
+                                    println!("[!] Invalid Reference: {} Is Not A PK Or AK Attribute Of {}", column, reference.referenced_table);
+                                    valid = false;
+                                    break;
+                                }
+
+                                for (column, referenced_column) in reference.columns.iter().zip(&reference.referenced_columns) {
+                                    reference_dictionary
+                                        .entry(sql_command_list[2].to_string())
+                                        .or_default()
+                                        .push(HashMap::from([(
+                                            reference.referenced_table.to_string(),
+                                            referenced_column.to_string(),
+                                        )]));
+                                    previous_attributes.push(column.to_string());
+                                }
+                                continue;
+                            }
+
+                            let mut isCompound: bool = false;
+
+                            let mut attribute_checker: Vec<String> = attribute
+                                .split_whitespace()
+                                .map(|s| s.to_owned())
+                                .collect();
+
+                            let options = extract_column_options(&mut attribute_checker);
+                            isCompound = merge_compound(&mut attribute_checker);
+
+                            // Locale suffixes left on a type (compound types keep theirs) must be supported locales
+                            if let Some(unknown_locale) = attribute_checker
+                                .join(" ")
+                                .split(|c: char| c.is_whitespace() || "();,".contains(c))
+                                .find(|token| {
+                                    let (attribute_type, locale) = split_locale(token);
+                                    let base_type = attribute_type.split('@').next().unwrap();
+                                    locale.is_none()
+                                        && token.contains('@')
+                                        && check_data_type(&base_type.to_uppercase())
+                                })
+                            {
+                                println!(
+                                    "[!] Unknown Locale In {}. Type 'show locales' For The Supported Locales",
+                                    unknown_locale
+                                );
+                                valid = false;
+                                break;
+                            }
+
+                            if let Some(reference) = options
+                                .from
+                                .iter()
+                                .find(|reference| !previous_attributes.contains(reference))
+                            {
+                                println!(
+                                    "[!] FROM({}) Must Reference An Attribute Defined Before {}",
+                                    reference,
+                                    get_attribute_name(&attribute_checker, isCompound)
+                                );
+                                valid = false;
+                                break;
+                            }
+
+                            // EXPR(...) must parse and may only use the columns defined before it
+                            if let Some(expression_type) = attribute_checker
+                                .iter()
+                                .map(|element| normalize_type(element))
+                                .find(|element| element.starts_with("EXPR("))
+                            {
+                                let expression = expression_type
+                                    .strip_prefix("EXPR(")
+                                    .and_then(|expression| expression.strip_suffix(')'))
+                                    .unwrap_or_default();
+                                let parsed = match parse_expression(expression) {
+                                    Ok(parsed) => parsed,
+                                    Err(reason) => {
+                                        println!("[!] Invalid {}: {}", expression_type, reason);
+                                        valid = false;
+                                        break;
+                                    }
+                                };
+
+                                let mut columns: Vec<String> = Vec::new();
+                                get_expression_columns(&parsed, &mut columns);
+                                if let Some(column) = columns.iter().find(|column| !previous_attributes.contains(column)) {
+                                    println!(
+                                        "[!] EXPR Column {} Must Be An Attribute Defined Before {}",
+                                        column,
+                                        get_attribute_name(&attribute_checker, isCompound)
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                            }
+                            previous_attributes.push(get_attribute_name(&attribute_checker, isCompound));
+
+                            // CONST(...) and ONEOF(...) need at least one value. CONST and DEFAULT are the same in every row
+                            if let Some(value_type) = attribute_checker.iter().find(|element| {
+                                ["CONST", "ONEOF", "DEFAULT"]
+                                    .contains(&normalize_type(element).split('(').next().unwrap())
+                            }) {
+                                let value_type = normalize_type(value_type);
+                                if value_type != "DEFAULT" && get_value_list(&value_type).is_empty() {
+                                    println!("[!] {} Needs At Least One Value (i.e CONST('US'), ONEOF(1, 2, 3))", value_type);
+                                    valid = false;
+                                    break;
+                                }
+
+                                let key_definition = attribute_checker[0].to_uppercase();
+                                if attribute_checker.len() == 3
+                                    && !value_type.starts_with("ONEOF")
+                                    && (key_definition.starts_with("PK") || key_definition.starts_with("AK"))
+                                {
+                                    println!(
+                                        "[!] {} Can Not Be A Unique Key, {} Is The Same In Every Row",
+                                        get_attribute_name(&attribute_checker, isCompound),
+                                        value_type
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                            }
+
+                            if let Some(null_probability) = &options.null_probability {
+                                if let Err(reason) = null_probability {
+                                    println!(
+                                        "[!] Invalid NULL(p) On {}: {} (i.e NULL(0.3))",
+                                        get_attribute_name(&attribute_checker, isCompound),
+                                        reason
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+
+                                // Only attributes of 3+ elements that are not unkeyed compounds start with a key definition
+                                let has_key_definition = attribute_checker.len() > 3
+                                    || (attribute_checker.len() == 3 && !isCompound);
+                                if has_key_definition
+                                    && attribute_checker[0].to_uppercase().starts_with("PK")
+                                {
+                                    println!(
+                                        "[!] Primary Key {} Cannot Be NULL",
+                                        get_attribute_name(&attribute_checker, isCompound)
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                            }
+
+                            // DIST(...) draws the value of a plain (or keyed) INTEGER, DECIMAL, MONEY, DATE or TIMESTAMP column
+                            if let Some(distribution) = &options.distribution {
+                                let attribute_name = get_attribute_name(&attribute_checker, isCompound);
+                                let distributed_type = match (attribute_checker.len(), isCompound) {
+                                    (2, _) => normalize_type(&attribute_checker[1]),
+                                    (3, false) => normalize_type(&attribute_checker[2]),
+                                    _ => String::new(),
+                                };
+
+                                if let Err(reason) = distribution {
+                                    println!("[!] Invalid DIST(...) On {}: {}", attribute_name, reason);
+                                    valid = false;
+                                    break;
+                                }
+                                if !DISTRIBUTED_TYPES.iter().any(|distributed| {
+                                    distributed_type == *distributed
+                                        || distributed_type.starts_with(&format!("{}(", distributed))
+                                }) {
+                                    println!(
+                                        "[!] DIST(...) On {} Only Works On {} Columns That Are Not Foreign Keys Or Compounds",
+                                        attribute_name,
+                                        DISTRIBUTED_TYPES.join(", ")
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                                if !options.from.is_empty() {
+                                    println!("[!] {} Can Not Use Both FROM(...) And DIST(...)", attribute_name);
+                                    valid = false;
+                                    break;
+                                }
+                            }
+
+                            // FANOUT(...) spreads the rows of a foreign key (FK or PK/FK) over the parent values
+                            if let Some(fanout) = &options.fanout {
+                                let attribute_name = get_attribute_name(&attribute_checker, isCompound);
+                                let key_definition = attribute_checker[0].to_uppercase();
+                                let is_foreign_key = attribute_checker.len() == 5
+                                    || (attribute_checker.len() == 4 && !isCompound);
+
+                                if let Err(reason) = fanout {
+                                    println!("[!] Invalid FANOUT(...) On {}: {}", attribute_name, reason);
+                                    valid = false;
+                                    break;
+                                }
+                                if !is_foreign_key || !(key_definition == "FK" || key_definition == "PK/FK") {
+                                    println!(
+                                        "[!] FANOUT(...) On {} Only Works On FK And PK/FK Attributes",
+                                        attribute_name
+                                    );
+                                    valid = false;
+                                    break;
+                                }
+                            }
+
+                            match attribute_checker.len() {
+                                1 => match attribute_checker[0].to_uppercase().as_str() {
+                                    "0" | "NULL" | "TRUE" | "FALSE" => {
+                                        println!("[!] Valid default value for attribute");
+                                    }
+                                    _ => {
+                                        println!("[!] Invalid default value for attribute");
+                                        valid = false;
+                                        break;
+                                    }
+                                },
+                                2 => {
+                                    if !check_data_type(
+                                        attribute_checker[1].to_uppercase().as_str(),
+                                    ) {
+                                        println!("[!] Invalid Data Type");
+                                        valid = false;
+                                        break;
+                                    }
+                                }
+                                3 => {
+                                    /*
+                                        * Examples Of Possible Attribute Definitions
+                                        * PK userID INTEGER
+                                        * AK email EMAIL
+                                        * full_name COMPOUND (FIRST_NAME VARCHAR(20), MIDDLE_INITIAL CHAR(1), LAST_NAME VARCHAR(20))
+                                    */
+                                    if !(check_data_type(
+                                        //Returns true if attribute is standard attribute
+                                        attribute_checker[2].to_uppercase().as_str(),
+                                    ) || check_data_type(
+                                        //Returns true if compound attribute
+                                        attribute_checker[1].to_uppercase().as_str(),
+                                    )) {
+                                        println!("[!] Invalid Data Type");
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    if isCompound {
+                                        if !check_compound_attribute(2, &attribute_checker) {
+                                            /*
+                                                * Error In Compound Attribute
+                                                * Reason Printed In Function
+                                            */
+                                            valid = false;
+                                            break;
+                                        }
+                                    } else {
+                                        /*
+                                         * A compound attribute with a key definition will have a len of 4
+                                         * No possible way a compound attribute can have a key definition in this case
+                                         * Hence only standard attributes are checked
+                                         */
+                                        if !check_key_definition(
+                                            attribute_checker[0].to_uppercase().as_str(),
+                                        ) {
+                                            println!("[!] Invalid Key Definition");
+                                            valid = false;
+                                            break;
+                                        }
+                                        if attribute_checker[0].to_uppercase() == "PK"
+                                            || attribute_checker[0].to_uppercase() == "AK"
+                                        {
+                                            key_dictionary
+                                                .entry(sql_command_list[2].to_string())
+                                                .or_default()
+                                                .push(attribute.to_uppercase().to_string());
+                                        } else {
+                                            println!("Possible Error In Key Definition For Compound Attribute");
+                                            println!("Key Definition is Valid. But possible foreign key without proper reference");
+                                            valid = false;
+                                            break;
+                                        }
+                                    }
+                                }
+                                4 => {
+                                    /*
+                                        * Example Of Compound Attribute With Indices
+                                        * [PK/AK][0] MBR[1] COMPOUND[2] (X_MIN; X_MAX; Y_MIN; Y_MAX)[3]
+                                    */
+                                    if !check_key_definition(
+                                        attribute_checker[0].to_uppercase().as_str(),
+                                    ) {
+                                        println!("[!] Invalid Key Definition");
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    if !check_data_type(
+                                        attribute_checker[2].to_uppercase().as_str(),
+                                    ) {
+                                        println!("[!] Invalid Data Type");
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    if isCompound {
+                                        if !check_compound_attribute(3, &attribute_checker) {
+                                            valid = false;
+                                            break;
+                                        }
+
+                                        // Is a keyed compound attribute (Either PK or AK) add compound attribute to key dict
+                                        if attribute_checker[0].to_uppercase() == "PK"
+                                            || attribute_checker[0].to_uppercase() == "AK"
+                                        {
+                                            key_dictionary
+                                                .entry(sql_command_list[2].to_string())
+                                                .or_default()
+                                                .push(attribute.to_uppercase().to_string());
+                                        } else {
+                                            println!("Possible Error In Key Definition For Compound Attribute");
+                                            println!("Key Definition is Valid. But possible foreign key without proper reference");
+                                            valid = false;
+                                            break;
+                                        }
+                                    } else {
+                                        // Just a foreign key reference handle as before
+                                        let (referenced_table, referenced_attribute_name) =
+                                            get_references(&attribute_checker, 3);
+
+                                        let referenced_attribute = get_referenced_attribute(
+                                            key_dictionary
+                                                .get(&referenced_table.to_string())
+                                                .unwrap_or(&vec![]),
+                                            &referenced_attribute_name.to_uppercase().to_string(),
+                                        );
+
+                                        match referenced_attribute {
+                                            Some(_) => {
+                                                if attribute_checker[0]
+                                                    .to_uppercase()
+                                                    .contains("PK")
+                                                    || attribute_checker[0]
+                                                    .to_uppercase()
+                                                    .contains("AK")
+                                                {
+                                                    key_dictionary
+                                                        .entry(sql_command_list[2].to_string())
+                                                        .or_default()
+                                                        .push(attribute.to_string());
+                                                }
+                                                reference_dictionary
+                                                    .entry(sql_command_list[2].to_string())
+                                                    .or_default()
+                                                    .push(HashMap::from([(
+                                                        referenced_table.to_string(),
+                                                        referenced_attribute_name.to_string(),
+                                                    )]));
+                                            }
+                                            None => {
+                                                println!("[!] Invalid Reference");
+                                                valid = false;
+                                                break;
+                                            }
+                                        }
+                                    }
+                                }
+                                5 => {
+                                    /*
+                                     * This will ONLY run with a foreign key compound attribute
+                                     * FK full_name COMPOUND (FIRST_NAME VARCHAR(20), MIDDLE_INITIAL CHAR(1), LAST_NAME VARCHAR(20)) profile(full_name)
+                                     */
+                                    if !check_key_definition(
+                                        attribute_checker[0].to_uppercase().as_str(),
+                                    ) {
+                                        println!("[!] Invalid Key Definition");
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    if !check_data_type(
+                                        attribute_checker[2].to_uppercase().as_str(),
+                                    ) {
+                                        println!("[!] Invalid Data Type");
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    if !check_compound_attribute(3, &attribute_checker) {
+                                        valid = false;
+                                        break;
+                                    }
+
+                                    // Compound Attribute With Foreign Key Reference
+                                    let (referenced_table, referenced_attribute_name) =
+                                        get_references(&attribute_checker, 4);
+
+                                    let referenced_attribute = get_referenced_attribute(
+                                        key_dictionary
+                                            .get(&referenced_table.to_string())
+                                            .unwrap_or(&vec![]),
+                                        &referenced_attribute_name.to_uppercase().to_string(),
+                                    );
+
+                                    match referenced_attribute {
+                                        Some(_) => {
+                                            if attribute_checker[0].to_uppercase().contains("PK")
+                                                || attribute_checker[0]
+                                                .to_uppercase()
+                                                .contains("AK")
+                                            {
+                                                key_dictionary
+                                                    .entry(sql_command_list[2].to_string())
+                                                    .or_default()
+                                                    .push(attribute.to_string());
+                                            }
+                                            reference_dictionary
+                                                .entry(sql_command_list[2].to_string())
+                                                .or_default()
+                                                .push(HashMap::from([(
+                                                    referenced_table.to_string(),
+                                                    referenced_attribute_name.to_string(),
+                                                )]));
+                                        }
+                                        None => {
+                                            println!("[!] Invalid Reference");
+                                            valid = false;
+                                            break;
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    println!("Error In Attributes For The Table: {}...Attribute: {} Is Not Formatted Properly", sql_command_list[2], attribute);
+                                    valid = false;
+                                    break;
+                                }
+                            }
+                        }
+
+                        // CHECK constraints must parse, use attributes of the table and be possible to satisfy
+                        let mut parsed_checks: Vec<Expression> = Vec::new();
+                        for check in table_checks.iter().filter(|_| valid) {
+                            let parsed = match parse_expression(check) {
+                                Ok(parsed) => parsed,
+                                Err(reason) => {
+                                    println!("[!] Invalid CHECK ({}): {}", check, reason);
+                                    valid = false;
+                                    break;
+                                }
+                            };
+
+                            let mut columns: Vec<String> = Vec::new();
+                            get_expression_columns(&parsed, &mut columns);
+                            if let Some(column) = columns.iter().find(|column| !previous_attributes.contains(column)) {
+                                println!("[!] CHECK Column {} Is Not An Attribute Of {}", column, sql_command_list[2]);
+                                valid = false;
+                                break;
+                            }
+                            parsed_checks.push(parsed);
+                        }
+                        // A composite PK/FK is the whole primary key, pairs of PK attributes are keyed through CompositeKeyPicker instead
+                        let primary_keys = attributes
+                            .iter()
+                            .filter(|attribute| attribute.to_uppercase().starts_with("PK"))
+                            .count();
+                        if valid
+                            && primary_keys > 1
+                            && attributes.iter().any(|attribute| {
+                                get_composite_reference(attribute).is_some_and(|reference| reference.key_definition.starts_with("PK"))
+                            })
+                        {
+                            println!("[!] A PK/FK Over Several Attributes Must Be The Only PK Of {}", sql_command_list[2]);
+                            valid = false;
+                        }
+                        if valid && table_pairs > 0 && primary_keys < 2 {
+                            println!("[!] PAIRS (...) Only Works On Tables With Several PK Attributes (i.e PK/FK a INTEGER profile(userID), PK/FK b INTEGER profile(userID))");
+                            valid = false;
+                        }
+                        if valid && table_pairs > 1 {
+                            println!("[!] {} Can Only Have One PAIRS (...)", sql_command_list[2]);
+                            valid = false;
+                        }
+
+                        // UNIQUE constraints must name different attributes of the table
+                        for columns in table_uniques.iter().filter(|_| valid) {
+                            if let Some(column) = columns.iter().find(|column| !previous_attributes.contains(column)) {
+                                if column.is_empty() {
+                                    println!("[!] Invalid UNIQUE ({}): Every Attribute Needs A Name", columns.join(", "));
+                                } else {
+                                    println!("[!] UNIQUE Column {} Is Not An Attribute Of {}", column, sql_command_list[2]);
+                                }
+                                valid = false;
+                                break;
+                            }
+                            if columns.iter().enumerate().any(|(index, column)| columns[..index].contains(column)) {
+                                println!("[!] Invalid UNIQUE ({}): An Attribute Is Listed Twice", columns.join(", "));
+                                valid = false;
+                                break;
+                            }
+                        }
+                        if valid {
+                            let impossible_range = get_check_ranges(&parsed_checks).into_iter().find(|(_, range)| {
+                                match (range.minimum, range.maximum) {
+                                    (Some((low, low_inclusive)), Some((high, high_inclusive))) => {
+                                        low > high || (low == high && !(low_inclusive && high_inclusive))
+                                    }
+                                    _ => false,
+                                }
+                            });
+                            if let Some((column, _)) = impossible_range {
+                                println!("[!] The CHECK Constraints On {} Can Never Be True", column);
+                                valid = false;
+                            }
+                        }
+
+                        // Relative row counts are taken from a parent table added before this one
+                        if valid && !matches!(row_count, RowCount::Absolute(_)) {
+                            match get_parent_table(sql_command_list[2], &row_count, &reference_dictionary) {
+                                Some(parent)
+                                    if tables
+                                        .iter()
+                                        .any(|table| table.split_whitespace().nth(1) == Some(parent.as_str())) => {}
+                                Some(parent) => {
+                                    println!("[!] Parent Table {} Must Be Added Before {}", parent, sql_command_list[2]);
+                                    valid = false;
+                                }
+                                None => {
+                                    println!(
+                                        "[!] {} Has No Foreign Key To Take Its Row Count From (i.e add 3x:profile {} (...))",
+                                        sql_command_list[2], sql_command_list[2]
+                                    );
+                                    valid = false;
+                                }
+                            }
+                        }
+
+                        if valid {
+                            let table_string = format!(
+                                "{} {} {}",
+                                sql_command_list[1], sql_command_list[2], table_attributes
+                            );
+                            tables.push(table_string);
+                            println!(
+                                "[*] {} Insert Statements Added For {}",
+                                sql_command_list[1], sql_command_list[2]
+                            );
+                        } else {
+                            continue;
+                        }
+                    }
+                    "remove" | "rm" | "del" => {
+                        let table_to_delete = tables
+                            .iter()
+                            .find(|elem| {
+                                elem.split_whitespace().nth(1) == Some(sql_command_list[1])
+                            })
+                            .cloned();
+                        // A child would be generated without its parent, so its references are removed first
+                        let child_table = tables
+                            .iter()
+                            .filter(|_| table_to_delete.is_some())
+                            .map(|elem| elem.split_whitespace().collect::<Vec<&str>>())
+                            .find(|child| {
+                                let references_table = reference_dictionary
+                                    .get(child[1])
+                                    .is_some_and(|references| {
+                                        references
+                                            .iter()
+                                            .any(|reference| reference.contains_key(sql_command_list[1]))
+                                    });
+                                let counts_from_table = parse_row_count(child[0]).is_some_and(|row_count| {
+                                    get_parent_table(child[1], &row_count, &reference_dictionary).as_deref()
+                                        == Some(sql_command_list[1])
+                                });
+                                child[1] != sql_command_list[1] && (references_table || counts_from_table)
+                            });
+                        if let Some(child_table) = child_table {
+                            println!(
+                                "[!] Can Not Remove {}, {} References It Or Takes Its Row Count From It. Remove {} First",
+                                sql_command_list[1], child_table[1], child_table[1]
+                            );
+                        } else if let Some(table) = table_to_delete {
+                            tables.retain(|elem| elem != &table);
+                        } else {
+                            println!("[!] Table Not Found");
+                        }
+                    }
+                    "modify" | "mod" => {
+                        let _table_to_modify = tables
+                            .iter()
+                            .find(|elem| {
+                                elem.split_whitespace().nth(1) == Some(sql_command_list[1])
+                            })
+                            .cloned();
+                        // Find attribute name at sql_command_list[2] and replace the entire attribute with sql_command_list[3..sql_command_list.len()]
+                        // TODO
+                    }
+                    "show" => {
+                        // If len == 3 Then A Specific Table Has Been Given To Pull Data From
+                        // Else
+                        let specifier = if sql_command_list.len() == 3 {
+                            Some(sql_command_list[2].to_lowercase())
+                        } else {
+                            None
+                        };
+
+                        match sql_command_list[1].to_lowercase().as_str() {
+                            // Show Every Table The Program Will Generate Inserts For [Unless Given A Specific Table]
+                            "inserts" => {
                                 for table in &tables {
                                     if specifier.is_none()
                                         || *specifier.as_ref().unwrap()
@@ -1879,544 +6672,2037 @@ fn main() {
                                         );
                                     }
                                 }
-                            }
-                            // Show The Keys For Every Table [Unless Given A Specific Table]
-                            "keys" => {
-                                for (table, keys) in &key_dictionary {
-                                    if specifier.is_none()
-                                        || *specifier.as_ref().unwrap()
-                                        == table.split(' ').nth(1).unwrap().to_lowercase()
-                                    {
-                                        println!("Keys For Table {} -> {:?}", table, keys);
-                                    }
+                                if let Some(scale) = settings.scale {
+                                    println!("Scale -> {} (Row Counts Added As A Number Are Multiplied By It)", scale);
+                                }
+                                if let Some(seed) = settings.seed {
+                                    println!("Seed -> {} (The Same Seed And Tables Give The Same Inserts)", seed);
+                                }
+                                if let Some(threads) = settings.threads {
+                                    println!("Threads -> {} (Chunks Of {} Rows Are Generated At Once)", threads, CHUNK_ROWS);
+                                }
+                                if let Some(batch) = settings.batch {
+                                    println!("Batch -> {} (Rows Written In Each Insert Statement)", batch);
+                                }
+                                println!("Dialect -> {} (Table And Column Names That Need It Are Quoted For It)", settings.dialect.name());
+                                if settings.positional {
+                                    println!("Columns -> off (Values Follow The Order Of The Attributes)");
+                                }
+                            }
+                            // Show The Keys For Every Table [Unless Given A Specific Table]
+                            "keys" => {
+                                for (table, keys) in &key_dictionary {
+                                    if specifier.is_none()
+                                        || *specifier.as_ref().unwrap()
+                                        == table.split(' ').nth(1).unwrap().to_lowercase()
+                                    {
+                                        println!("Keys For Table {} -> {:?}", table, keys);
+                                    }
+                                }
+                            }
+                            // Show's All Tables That Reference Another Table [Unless Given A Specific Table]
+                            "references" | "refs" => {
+                                for (ref_table, key) in &reference_dictionary {
+                                    if specifier.is_none()
+                                        || *specifier.as_ref().unwrap()
+                                        == ref_table.split(' ').nth(1).unwrap().to_lowercase()
+                                    {
+                                        for value in key {
+                                            for (r_key, r_value) in value {
+                                                println!("Referencing Table -> {} References {} From Table {}", ref_table, r_value, r_key);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            // Show's All Custom Types Made Specifically For This Program
+                            "types" => {
+                                println!(
+                                    "
+                                        Custom Types Created For This Program
+
+                                        NAME -> Name is a custom type used to replace typical string DataTypes in sql for
+                                        when the user wishes to generate a realistic name, assigning a typical
+                                        VARCHAR(length) to an attribute where you wish to be a name may result in a randomly
+                                        generated string that does not represent a persons full name in real life
+
+                                        FIRST_NAME | MIDDLE_NAME | MIDDLE_INITIAL | LAST_NAME | PREFIX | SUFFIX -> Parts of a name
+                                        Every name type in a row belongs to the same person, so NAME is always
+                                        FIRST_NAME LAST_NAME and the EMAIL / USERNAME of the row are built from that name
+                                        PREFIX -> Title such as 'Dr.' or 'Mrs.'   SUFFIX -> 'Jr.', 'III', 'PhD', ...
+
+                                        EMAIL -> Email is another custom type used to replace string DataTypes in sql. This
+                                        ensures that every value generated for said attribute will follow standard email
+                                        format 'abc@abc.abc' and also excludes symbols that are commonly excluded in
+                                        deployed email domain services (^&% etc) 
+
+                                        PASSWORD(N) -> Password is another custom type used to replace string DataTypes in sql.
+                                        While it generates similar strings that would be generated should you just do a
+                                        VARCHAR(length) variable in its place, PASSWORD types have the added step of 
+                                        ensuring common password complexity constraints are enforced such as
+                                            Password Must Include At Least: 
+                                                [1 Capital Letter, 1 Lowercase, 1 Number, 1 Special Char]
+                                                [N = Max Length Of Password And It Generates Length Between 8..N]
+                                                [NOTE] IF YOU DO NOT ADD A LENGTH TO PASSWORD I.E PASSWORD(40)
+                                                [THEN] THE PROGRAM WILL PANIC. THIS INCLUDES STANDARD VARCHAR(N) AND CHAR(N) TYPES
+
+                                        GROUP -> Is a custom DataType that technically would replace String SQL types.
+                                        Assigning this type to an attribute will assign either 'mod' or 'member' as a role
+
+                                        USERNAME(N) -> Is a custom DataType that technically would replace String SQL types.
+                                        Assigning this type to an attribute will assign a randomly generated username
+
+                                        MONEY(N) -> Is a shorthand for designating decimal values of xxx.xx
+                                        All values will only have 2 decimal places between 0..99
+                                        N -> Max figure for the money value (i.e MONEY(7) generates values between 0.00 and 9,999,999.99)
+
+                                        STREET_ADDRESS -> Building number and street name, sometimes followed by a
+                                        secondary unit (i.e 4821 Kuhn Crossing Apt. 12)
+
+                                        FULL_ADDRESS -> One line mailing address made of a street address, city, state and zip
+                                        (i.e 4821 Kuhn Crossing Apt. 12, Lake Jany, OR 97301)
+
+                                        STATE | ZIP -> A state name and a postal code
+
+                                        GEO_CITY | GEO_STATE | GEO_STATE_ABBR | GEO_ZIP | GEO_LAT | GEO_LONG | GEO_COUNTRY ->
+                                        Address group types. Every GEO_* column in a row is taken from the same real city
+                                        in the bundled reference dataset, so the city, state, zip and coordinates always agree
+                                        An optional group name keeps several addresses apart in one row:
+                                        (i.e billing_city GEO_CITY(billing), billing_zip GEO_ZIP(billing), ship_city GEO_CITY(shipping))
+
+                                        AGE -> Age in years (18 - 90)    DOMAIN -> Web domain of the row's COMPANYNAME
+
+                                        CONST(value) -> The same value in every row (i.e country CONST('US'), retries CONST(0))
+                                        ONEOF(value, ...) -> A value picked from the list for every row (i.e status ONEOF('active', 'banned'))
+                                        DEFAULT -> Writes the SQL DEFAULT keyword so the database fills in the column's default
+                                        (i.e created_at DEFAULT). Values keep their case, quote values that contain commas
+
+                                        EXPR(expression) -> A value computed from columns defined earlier in the same table
+                                        Operators: + - * / %, || (joins text), = != < <= > >=, AND, OR, NOT, 'text', \"column name\"
+                                        A date plus or minus a number moves it by days, a date minus a date is the days between them
+                                        Functions: LOWER, UPPER, TRIM, LENGTH, SUBSTR, LEFT, RIGHT, REPLACE, CONCAT, SLUG,
+                                        ROUND, FLOOR, CEIL, ABS, MIN, MAX, IF, COALESCE, DATE_ADD(date, n, 'months'), DATE_DIFF,
+                                        YEAR, MONTH, DAY, TODAY(), NOW(). A NULL column makes the result NULL
+                                        (i.e total EXPR(quantity * unit_price), end_date EXPR(start_date + duration))
+                                        (i.e slug EXPR(slug(title)), tier EXPR(IF(total > 100, 'gold', 'basic')))
+
+                                        FROM(column, ...) -> Not a type, but a modifier placed after an attribute's type
+                                        Derives the value from columns defined earlier in the same table instead of making a new one
+                                        (i.e username USERNAME(16) FROM(name) -> 'Bob Johnson' Becomes BobJohnson42)
+                                        (i.e email EMAIL FROM(username, company) -> BobJohnson42@kuhnschmidtbode.com)
+                                        (i.e dob DATE FROM(age) -> A Date Of Birth Matching The Age Column, AGE FROM(dob) Works Too)
+
+                                        NULL(p) -> Not a type, but a modifier placed after an attribute's type (or reference)
+                                        The column is NULL in roughly p of the rows (0 - 1) and generated as usual otherwise
+                                        Works on plain, compound and foreign key columns, but not on primary keys
+                                        (i.e phone PHONE NULL(0.3) -> About 30% Of The Phone Numbers Are NULL)
+
+                                        FANOUT(shape, min n, max n) -> Not a type, but a modifier placed after a foreign key's reference
+                                        Sets how the rows are spread over the parent values instead of evenly, every part is optional
+                                        uniform -> Every parent is as likely (the default)
+                                        zipf, exponent -> A few parents get most of the rows (i.e FANOUT(zipf, 1.2))
+                                        hot, fraction, share -> The fraction of the parents gets the share of the rows
+                                        min n | max n -> Every parent gets at least / at most n rows
+                                        (i.e FK userID INTEGER profile(userID) FANOUT(zipf, 1.1, max 20) -> 0 - 20 Posts Per Profile)
+                                        (i.e FK userID INTEGER profile(userID) FANOUT(hot, 0.1, 0.8) -> 10% Of Users Own 80% Of Orders)
+
+                                        DIST(name, parameters) -> Not a type, but a modifier placed after an attribute's type
+                                        Draws the value from a distribution instead of evenly, for INTEGER, DECIMAL, MONEY,
+                                        DATE and TIMESTAMP columns. Dates can be used in place of numbers, deviations are in days
+                                        normal(mean, deviation)                (i.e rating INTEGER DIST(normal, 4, 0.8))
+                                        lognormal(mean, deviation[, start])    Mean and deviation of the logarithm of the value
+                                                                               (i.e amount MONEY(6) DIST(lognormal, 3.5, 1))
+                                        exponential(rate[, start])             (i.e signup DATE DIST(exponential, 0.02, '2023-01-01'))
+                                        zipf(count, exponent[, start])         Values start to start + count - 1, start is the
+                                                                               most common (i.e views INTEGER DIST(zipf, 1000, 1.1))
+                                        histogram(low:high:weight, ...)        (i.e age INTEGER DIST(histogram, 18:30:60, 30:90:40))
+
+                                        TYPE@locale -> Not a type, but a locale suffix placed right after an attribute's type
+                                        Names, addresses, phone numbers and companies of that column come from the locale
+                                        (i.e name NAME@fr_FR, phone PHONE@de_DE, city GEO_CITY@it_IT)
+                                        Columns without a suffix use the locale set with 'locale [locale]' (en by default)
+                                        Type 'show locales' For The Supported Locales
+
+                                        ## NUMEROUS OTHER TYPES HAVE BEEN ADDED THAT I HAVE YET TO ADD DESCRIPTIONS FOR ##
+
+                                        COMPOUND -> Is a custom DataType that is used to designate a compound attribute
+                                        Compound Attributes Are Attributes That Are Made Up Of Multiple Attributes
+                                        Compound Attributes Must Be Defined In The Following Format:
+                                        [key definition] [attribute name] COMPOUND ([attribute 1]; [attribute 2]; ...; [attribute n])
+                                        Where [key definition] Can Be Null
+
+                                        [*] For More Help With Attributes, Type 'show examples attributes'
+                                        [*] For More Help With Compound Attributes, Type 'show examples compound'
+                                    "
+                                );
+                            }
+                            // Show's The Supported Locales And The Current Default Locale
+                            "locales" => {
+                                let locale_codes: Vec<&str> =
+                                    Locale::ALL.iter().map(|locale| locale.code()).collect();
+                                println!("Supported Locales -> {}", locale_codes.join(", "));
+                                println!("Default Locale -> {}", settings.locale.code());
+                            }
+                            // Show's Examples Of Commands To Aid The User Optional [Specifier] Shows Only Specific Examples
+                            // If Specifier Not Given. All Examples Are Shown
+                            "examples" | "ex" => {
+                                // Show's Examples Of How To Add Tables
+                                if specifier.is_none() || specifier == Some("add".to_owned()) {
+                                    // Examples For Add
+                                    println!("
+                                        Add Example [Adding 'profile' Table To List]:
+                                        [Add Example #1] -> add 100 profile (PK userID INTEGER, name NAME, AK email EMAIL, password PASSWORD(30), dateOfBirth DATE, lastLogin TIMESTAMP)
+                                        [Add Example #2] -> add 300 friend (PK/FK userID1 INTEGER profile(userID), PK/FK userID2 INTEGER profile(userID), friendDate DATE)
+                                        [Add Example #3] -> add 1000 post (PK postID INTEGER, FK userID INTEGER profile(userID), postDate TIMESTAMP, postContent VARCHAR(1000))
+                                        [Add Example #4] -> add 1000 comment (PK commentID INTEGER, FK userID INTEGER profile(userID), FK postID INTEGER post(postID), commentDate TIMESTAMP, commentContent VARCHAR(1000))
+                                        [Add Example #5] -> add 1000 MBR (PK MBR_ID INTEGER, MBR COMPOUND (X_MIN INTEGER, X_MAX INTEGER, Y_MIN INTEGER, Y_MAX INTEGER))
+                                    ");
+                                }
+                                // Show's Examples Of How To Delete Tables
+                                if specifier.is_none() || specifier == Some("del".to_owned()) {
+                                    // Examples For Del
+                                    println!(
+                                        "
+                                        Rm Example [Assuming 'profile' Table Is Defined]:
+                                        [Rm Example #1] -> Rm profile
+                                    "
+                                    );
+                                }
+                                // Show's Examples Of How To Modify Tables
+                                if specifier.is_none()
+                                    || specifier == Some("modify".to_owned())
+                                    || specifier == Some("mod".to_owned())
+                                {
+                                    // Examples For Modify
+                                    println!(
+                                        "
+                                        Modify Example [Assuming 'profile' Table Is Defined From 'Add' Example]:
+                                        [In This Case This Example Converts The PK 'email' Attribute To A AK]
+                                        
+                                        [Current Attribute Definition] -> PK email EMAIL
+                                        [Mod Example #1] -> mod profile email AK email EMAIL
+                                        [New Attribute Definition] -> AK email EMAIL
+
+                                        [NOTE] The Attribute Definition Must Be In The Same Format As The Original
+                                        "
+                                    );
+                                }
+                                // Explains Reference Formatting To Aid In Table Input
+                                if specifier.is_none()
+                                    || specifier == Some("references".to_owned())
+                                    || specifier == Some("refs".to_owned())
+                                {
+                                    println!(
+                                        "
+                                        Attribute Format Explained:
+                                        All Attributes In A Table Definition Must Be Of The Form:
+                                        '[key definition][attribute name][attribute type][foreign table]'
+                                        Where [key definition] And [foreign table] Can Be Null
+
+                                        If [key definition] Is A Foreign Key [FK | PK/FK | AK/FK]:
+                                            Then [foreign table] MUST be defined
+
+                                        The Following Is An Example PK/FK Attribute
+                                        Example References A Table Named 'profile' And Its Attribute 'userID'
+                                        Attribute -> 'PK/FK userID1 INTEGER profile(userID)'
+                                        The Example Would Assign As So:
+                                            [key definition] = 'PK/FK'
+                                                Is A Primary Key That References Primary Key 'userID' From Table 'profile'
+                                            [attribute name] = 'userID1'
+                                                Self Explanatory, It Is The Name Of The Attribute Being Defined
+                                            [attribute type] = 'INTEGER' 
+                                                Type Definitions On Each Attribute Must Match SQL DataTypes, Not Pythons
+                                            [foreign table] = 'profile(userID)' Where:
+                                                [*] 'profile' Is The Table Being Referenced
+                                                [*] 'userID' Is The Referenced Attribute
+                                        [*] For More Help With Attributes, Type 'show examples attributes'
+
+                                        In Tables With More Than One PK, It Will Generate Data As A Composite Pair,
+                                        Hence The AK Attribute May Be Needed, This Is Enforces The Unique Value Without
+                                        Worrying About Checking The Keys As Pairs
+
+                                        A Foreign Key Over Several Attributes Lists Them In () Without Types:
+                                        Attribute -> 'FK (order_id, line_no) order_line(order_id, line_no)'
+                                            [*] Each Row Copies order_id And line_no From The Same Row Of 'order_line'
+                                            [*] The Attributes Take The Types Of The Referenced PK/AK Attributes
+                                            [*] PK/FK And AK/FK Use Each Row Of 'order_line' At Most Once
+
+                                        In Cases With Tables Having Multiple Attributes: 
+                                        They Must Be Placed In () And Separated By Commas.
+                                        'show examples add' Provides Examples
+                                        "
+                                    );
+                                }
+                                // Explains Attribute Formatting To Aid In Table Input
+                                if specifier.is_none()
+                                    || specifier == Some("attributes".to_owned())
+                                    || specifier == Some("attr".to_owned())
+                                {
+                                    println!(
+                                        "
+                                            Basic Introductory To Attribute Types For SQL Tables
+                                        ---------------------------------------------------------------------------
+                                        [*] To See Custom Defined Types Specifically For This Program
+                                        [*] Type 'show examples types'
+
+                                        [Example 1]: 'age INTEGER'
+                                        [key definition] = None
+                                            [*] No Key Definition is Included
+                                        [attribute name] = 'age'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'INTEGER'
+                                            [*] Must Be SQL Data Types Not Rust Data Types
+
+                                        [Example 2]: 'salary DECIMAL(10,2)'
+                                        [key definition] = None
+                                            [*] 'No Key Definition is Included
+                                        [attribute name] = 'salary'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'DECIMAL(10,2)'
+                                            [*] Must Be SQL Data Types Not Rust Data Types
+
+                                        [Example 3]: 'name NAME'
+                                        [key definition] = None
+                                            [*] No Key Definition is Included
+                                        [attribute name] = 'orderID'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'NAME'
+                                            [*] Must Be SQL Data Types Not Rust Data Types
+                                            [*] NAME Is A Custom Type Made Specifically For This Program
+                                            [*] NAME Type Ensures A Realistic Full Name Is Generated
+                                            [*] To See Custom Defined Types Specifically For This Program
+                                            [*] Type 'show examples types'
+
+                                        [Example 4]: 'PK userID INTEGER'
+                                        [*] Is a Primary Key Attribute
+                                        [key definition] = 'PK'
+                                            [*] 'PK' stands for Primary Key
+                                        [attribute name] = 'userID'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'INTEGER'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+
+                                        [Example 5]: 'AK email EMAIL'
+                                        [*] Is a Alternate Key Attribute
+                                        [key definition] = 'AK'
+                                            [*] 'AK' stands for Alternate Key
+                                        [attribute name] = 'userID'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'EMAIL'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+                                            [*] EMAIL Is A Custom Type Made Specifically For This Program
+                                            [*] EMAIL Type Ensures A Realistic Email Is Generated
+                                            [*] If A Name Attribute Is Present In The Same Table And Data Gets Generated For It Before Email
+                                            [*] The Email Generated Will Be Representative Of The Name Generated As Well
+                                            [*] Example If A Name 'Bob Johnson' Is Generated For A Tuple:
+                                            [*] Then A Possible Email Generated Would Be BobJohnson@outlook.com
+                                            [*] To See Custom Defined Types Specifically For This Program
+                                            [*] Type 'show examples types'
+
+                                        [Example 6]: 'FK customerID INTEGER customers(customerID)'
+                                        [*] Is a Foreign Key Attribute Referencing the 'customers' Table's 'customerID' Attribute
+                                        [key definition] = 'FK'
+                                            [*] 'FK' stands for Foreign Key
+                                        [attribute name] = 'customerID'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'INTEGER'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+
+                                        [Example 7]: 'AK/FK customerID INTEGER references customers(customerID)'
+                                        [*] Is an Alternate Key and Foreign Key Attribute References the 'customers' Table's 'customerID' Attribute
+                                        [key definition] = 'AK/FK'
+                                            [*] 'AK/FK' stands for Alternate Key/Foreign Key
+                                        [attribute name] = 'customerID'
+                                            [*] Name of Attribute Being Defined
+                                        [attribute type] = 'INTEGER'
+                                            [*] Must be SQL Data Types, not Rust Data Types
+
+                                        [Example 8]: 'PK/FK bookID INTEGER authors(bookID)'
+                                        [*] Is a Primary Key and Foreign Key Attribute Referencing the 'authors' Table's 'bookID' Attribute
+                                        [key definition] = 'PK/FK'
+                                            [*] 'PK' stands for Primary Key
+                                            [*] 'FK' stands for Foreign Key
+                                            [*] 'PK/FK' stands for Primary Key with a Foreign Reference
+                                        [attribute name] = 'bookID'
+                                            [*] Name of Attribute being Defined
+                                        [attribute type] = 'INTEGER'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+                                        [foreign table] = 'authors(bookID)'
+                                            [*] Where Again, 'authors' Is The Table Being Referenced and 'bookID' Is The Referenced Attribute From The Table
+
+                                        [Example 9]: 'PK userID INTEGER, PK postID INTEGER
+                                        [*] These Primary Key Attributes Create A Composite Key
+                                        [key definition] = 'PK'
+                                            [*] 'PK' stands for Primary Key
+                                        [attribute name 1] = 'userID'
+                                            [*] Name of the First Attribute Being Defined
+                                        [attribute type 1] = 'INTEGER'
+                                            [*] Must be SQL Data Types, Not Rust Data Types
+                                        [attribute name 2] = 'postID'
+                                            [*] Name of the second Attribute Being Defined
+                                        [attribute type 2] = 'INTEGER'
+                                            [*] Must be SQL Data Types, not Rust Data Types
+                                        [*] Both 'userID' and 'postID' are Primary Keys
+                                        [*] This Means Their Combination Will Be Treated As A Composite Key Pair When Generating Random Data For The Table. 
+                                        [*] This Means That Any Two Rows On The Table Cannot Have The Same Combination of 'userID' and 'postID'.
+
+                                        [Example 10]: 'PK/FK bookID INTEGER authors(bookID), PK/FK publisherID INTEGER publishers(publisherID)'
+                                        [*] These Primary Key Attributes Create A Composite Key Where Each Attribute Also References An Exterior Table
+                                        [key definition] = 'PK/FK'
+                                            [*] 'PK' stands for Primary Key
+                                            [*] 'FK' stands for Foreign Key
+                                            [*] 'PK/FK' stands for Primary Key with a Foreign Reference
+                                        [attribute name 1] = 'bookID'
+                                            [*] Name of the First Attribute being Defined
+                                        [attribute type 1] = 'INTEGER'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+                                        [attribute name 2] = 'publisherID'
+                                            [*] Name of the Second Attribute being Defined
+                                        [attribute type 2] = 'INTEGER'
+                                            [*] Must Be SQL Data Types, Not Rust Data Types
+                                        [foreign table] = 'authors(bookID), publishers(publisherID)'
+                                            [*] Where Again, 'authors' Is The Table Being Referenced and 'bookID' Is The Referenced Attribute From The Table
+                                            [*] As Well As, 'publisher' Is The Table Being Referenced and 'publisherID' Is The Referenced Attribute From The Table
+                                            [*] Both 'userID' and 'postID' are Primary Keys
+                                            [*] This Means Their Combination Will Be Treated As A Composite Key Pair When Generating Random Data For The Table. 
+                                            [*] This Means That Any Two Rows On The Table Cannot Have The Same Combination of 'userID' and 'postID'.
+
+                                        [Example 11]: 'PK userID INTEGER, AK email EMAIL'
+                                        [key definition 1] = 'PK'
+                                            [*] 'PK' stands for Primary Key
+                                        [attribute name 1] = 'userID'
+                                            [*] Name of Attribute Being Defined
+                                        [attribute type 1] = 'INTEGER'
+                                            [*] Must be SQL Data Types, not Rust Data Types
+                                        [key definition 2] = 'AK'
+                                            [*] 'AK' stands for Alternate Key
+                                        [attribute name 2] = 'email'
+                                            [*] Name of Attribute Being Defined
+                                        [attribute type 2] = 'EMAIL'
+                                            [*] Must be SQL Data Types, not Rust Data Types
+                                            [*] EMAIL Is A Custom Type Made Specifically For This Program
+                                            [*] EMAIL Type Ensures A Realistic Email Is Generated
+                                            [*] To See Custom Defined Types Specifically For This Program
+                                            [*] Type 'show examples types'
+                                            [*] In This table, The 'userID' Attribute Is A Primary Key And The 'email' Attribute Is An Alternate Key.
+                                            [*] While Having Multiple Primary Keys In A Table Leads To A Composite Key Pair.
+                                            [*] Having A Primary Key And An Alternate Key In The Same Table Doesn't Necessarily Result In A Composite Key.
+                                            [*] The Alternate Key May Still Provide A Unique Constraint, But It Doesn't Have The Same Significance As The Primary Key.
+                                        "
+                                    );
+                                }
+                                // Add examples for compound attributes
+                                if specifier.is_none()
+                                    || specifier == Some("compound".to_owned())
+                                    || specifier == Some("comp".to_owned())
+                                {
+                                    println!(
+                                        "
+                                            Compound Attribute Format Explained:
+
+                                            Compound Attributes Are Attributes That Are Defined As A Group Of Attributes
+                                            That Are All Treated As One Attribute When Generating Data For The Table
+                                            For Example. If You Have A Table User That Stores A Users FulL Name As A Compound Attribute
+                                            You Would Define It As So:
+                                            [Example 1]: 'full_name COMPOUND (FIRST_NAME VARCHAR(20); MIDDLE_INITIAL CHAR(1); LAST_NAME VARCHAR(20))'
+                                            [**IMPORTANT NOTE**]: Compound Attributes Must Be Defined In '()' And Separated By ';'
+                                            Compound Attributes Can Be Keyless, Have A Key Definition, Or Have A Key Definition With A Foreign Key Reference
+                                            Here Are Some Examples:
+                                            [Example 2]: 'AK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER)'
+                                            [Example 3]: 'PK/FK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER) region(MBR)'
+                                            [Example 4]: 'PK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER)'
+                                        "
+                                    )
                                 }
                             }
-                            // Show's All Tables That Reference Another Table [Unless Given A Specific Table]
-                            "references" | "refs" => {
-                                for (ref_table, key) in &reference_dictionary {
-                                    if specifier.is_none()
-                                        || *specifier.as_ref().unwrap()
-                                        == ref_table.split(' ').nth(1).unwrap().to_lowercase()
-                                    {
-                                        for value in key {
-                                            for (r_key, r_value) in value {
-                                                println!("Referencing Table -> {} References {} From Table {}", ref_table, r_value, r_key);
-                                            }
-                                        }
-                                    }
-                                }
+                            _ => {
+                                println!("Invalid Argument For Show Command");
+                                println!("Usage: Show [inserts | keys | references | examples [optional - [add | del | (modify | mod) | (refs | references) | (attributes | attr)]]");
                             }
-                            // Show's All Custom Types Made Specifically For This Program
-                            "types" => {
-                                println!(
-                                    "
-                                        Custom Types Created For This Program
+                        }
+                    }
+                    _ => {
+                        println!("Invalid Command Entered");
+                        println!("Type 'Help' For List of Commands");
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn display_help(display_all: bool) {
+    /*
+     * Displays the help menu
+     * If display_all is true, display the entire help menu
+     * If display_all is false, display the help menu for the generate command
+     */
+    println!(
+        "
+                        ===== MESSAGE =====
+
+            [*]This Program Takes User Input For SQL Table Values And Generates Random Insert Statements For Database Testing
+            [*]All Tables Should Be Defined Using The Following Format:
+                    Format: [Number Of Insert Statements][Table Name][Table Attributes]
+            [*]An Example Of An Accepted Table Definition And An Accepted Add Command:
+            [*]add 100 profile (PK userID INTEGER, name NAME, AK email EMAIL, password PASSWORD(30))
+            [*]Where 'add' Adds The [Number Of Insert Statements][Table Name][Table Attributes] To A List Used For Generation
+            [*]This Program Can Generate Any Number Of Inserts For Any Number Of SQL Tables As Long As User Memory Permits
+            [*]The Commands For This Program Are Not Case Sensitive
+            [*]Type 'Help' To Get A List Of All Commands And To Reprint This Message
+            [*]To See Example Commands For Each Command:
+                    Type 'show examples [specific command (optional - will show all examples if blank)]'
+            [*]Type 'Clear' To Delete This Message
+            \n"
+    );
+
+    if display_all {
+        println!(
+            "
+                        ===== COMMANDS AND DEFINITIONS=====
+
+            Commands Followed By [] Are Required Args Unless Specifically Stated In This Menu
+
+            Generate -> Begins Generating SQL Script Of Inserts [Mut Have At Least One Defined Table]
+                 Before Writing Anything It Lists Every Key, UNIQUE (...) Or FANOUT(...) That Can Not Hold Its Rows
+                 (i.e 'AK flag BOOLEAN' Has 2 Values, 'PK/FK' Pairs Over 4 Profiles Have 6 Combinations)
+            Clear -> Clear Terminal Screen
+            Help -> Show This Help Menu
+            Exit | Quit -> Terminate Program
+
+            Add  [numInserts][tableName][tableAttributes] -> Add Table To Generate Statements For, Where:
+                 [numInserts] -> The Number Of Insert Statements To Generate For The Table
+                    Can Be Relative To The Table's Parent (The First Table Its Foreign Keys Reference):
+                    '3x' -> 3 Rows Per Parent Row, '0-20x' -> 0 To 20 Rows For Every Parent Row
+                    Name The Parent When There Are Several (i.e add 2-5x:profile comment (...))
+                 [tableName] -> The Name of The Table Being Created
+                 [tableAttributes] -> All Defined Columns Of The Table [Format Shown Below]
+                 [tableAttributes] Should Be Input In The Following Format:
+
+                 [key definition][referenced_attr name][referenced_attr type][foreign table]
+
+                 Where [key definition] and [foreign table] can be NULL
+                 Key Definitions:
+                    'PK' - Primary Key
+                    'AK' - Alternate (Unique) Key
+                    'FK' - Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
+                    'PK/FK' - Primary Key And Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
+                    'AK/FK' - Alt (Unique) And Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
+
+                 [Note 1]: In Cases Of An Attribute Being A Foreign Key, [foreign table] CANNOT be NULL
+                 To Ensure A Foreign Key Attribute Is Properly Read And Referenced A Specific Format Is Provided:
+                 [Foreign Table] Proper Format -> referenced_table(referenced_attribute)
+                 For More Info On Referencing Syntax With Examples. Input 'show examples [refs | references]'
+
+                 [Note 2]: In Tables With More Than One PK, It Will Generate Data As A Composite Pair
+                 Hence The AK Attribute May Be Needed, This Enforces The Unique Values Without Worrying About Checking The Keys As Pairs
+
+                 [Note 3]: CHECK (expression) Can Be Placed Among The Attributes To Add A Row Level Constraint
+                 The Expression Uses The Same Syntax As EXPR (See 'show types') Plus IN (...) And BETWEEN ... AND ...
+                 Simple Checks (price > 0, status IN ('a', 'b')) Narrow The Generated Values Directly, Any Other Row
+                 That Fails A Check Is Generated Again (i.e add 50 trip (PK id INTEGER, start_date DATE, end_date DATE,
+                 price MONEY(5), CHECK (price BETWEEN 10 AND 500), CHECK (end_date >= start_date)))
+
+                 [Note 4]: UNIQUE (attribute, ...) Can Be Placed Among The Attributes To Keep The Combined Values Of Those
+                 Attributes Unique In Every Row, On Any Attributes (Keys Or Not) And As Many Times As Needed. Rows With A NULL
+                 In Them Are Never Duplicates (i.e add 100 project (PK id INTEGER, FK org_id INTEGER org(org_id),
+                 slug ONEOF(api, web, docs, app), UNIQUE (org_id, slug)))
+
+                 [Note 5]: [key definition] (attribute, ...) referenced_table(attribute, ...) Is A Foreign Key Over Several
+                 Attributes That Takes Their Values From One Row Of The Referenced Table Together (i.e add 50 shipment
+                 (PK id INTEGER, FK (order_id, line_no) order_line(order_id, line_no)))
+
+                 [Note 6]: PAIRS (rules) Can Be Placed Among The Attributes Of A Table With Several PK Attributes
+                 symmetric (Default) -> (1, 2) And (2, 1) Are The Same Key, directed -> They Are Different Keys
+                 no self (Default) -> A Key Can Not Repeat A Value, self -> Keys Like (1, 1) Are Allowed
+                 (i.e add 500 follows (PK/FK follower INTEGER profile(userID), PK/FK followed INTEGER profile(userID), PAIRS (directed)))
+
+            Rm   [tableName] -> Remove Table From List
+                 [tableName] Must Be The Same As It Was Defined In It's Add Statement
+
+            Scale [multiplier] -> Multiply The Row Count Of Every Table Added With A Number (i.e Scale 10, Scale 0.1)
+                 Relative Row Counts (3x) Follow Their Parent. Starting The Program With --scale 10 Does The Same
+                 Generate Refuses Counts That Unique Or Composite Keys Can Not Hold (i.e 100 Unique AGE Keys)
+
+            Seed [number] -> Generate The Same Inserts Every Time For The Same Tables (i.e Seed 42)
+                 Starting The Program With --seed 42 Does The Same
+
+            Threads [number] -> Generate Chunks Of Rows On Several Threads At Once (i.e Threads 8)
+                 The Inserts Of Every Table Stay In Order And A Seed Gives The Same File For Any Number Of Threads
+                 Tables With FANOUT(...), Several PK Attributes Or A Reference To Themselves Are Generated On One Thread
+                 Starting The Program With --threads 8 Does The Same
+
+            Batch [number] -> Write Several Rows In Each Insert Statement (i.e Batch 500)
+                 INSERT INTO profile VALUES (1, 'a'), (2, 'b'), ...; Loads Much Faster Than A Statement Per Row
+                 The Last Statement Of A Table Holds The Rows Left Over. Starting The Program With --batch 500 Does The Same
+
+            Dialect [name] -> Quote Table And Column Names For A Database (i.e Dialect mysql)
+                 Only Names That Need It, Like order Or first name, Are Quoted. Others Match Tables Created Without Quotes
+                 standard (Default), postgres, sqlite -> \"order\"   mysql, mariadb -> `order`   sqlserver, mssql -> [order]
+                 sqlserver Writes At Most 1000 Rows In Each Insert Statement, The Most SQL Server Takes
+                 Starting The Program With --dialect mysql Does The Same
+
+            Columns [on/off] -> List The Columns In Every Insert, On By Default (i.e Columns off)
+                 INSERT INTO profile (userID, name) VALUES (...); Lets The Database Fill Columns Left Out Of The Add
+                 off Writes INSERT INTO profile VALUES (...); In The Order Of The Attributes. Or Start With --columns off
+
+            Locale [locale] -> Set The Default Locale Of Names, Addresses, Phone Numbers And Companies (i.e Locale fr_FR)
+                 A Single Column Can Use Another Locale With A Suffix On Its Type (i.e name NAME@de_DE)
+
+            Modify | Mod [tableName] [numStatements | tableName | referenced_attr] [newValue] -> Modify Existing Table
+                 [tableName] -> The Table To Modify And Must Be The Same As It Was Defined In It's Add Statement
+                 [numInserts | tableName | referenced_attr] -> The Definition Of The Tuple You Wish To Modify 
+                 Note: You Can Only Modify One Definition At A Time
+                 IMPORTANT REGARDING Modify [numInserts | tableName]:
+                 If User Wishes To Modify The Number of Statements or Change Table Name. The User Should Input 'numStatements'
+                 or 'tableName' in Place of First Arg After Specifying The Table: However, When Modifying [referenced_attr],
+                 The User Should Input the Attribute Name They Wish To Change. Type 'show examples mod' For More Info
+                 [*]For 'Modify [tableName] [referenced_attr]':
+                    The User Must Redefine The Whole Attribute 
+                    As In Redefine [key definition][referenced_attr name][referenced_attr type][foreign table]
+
+            Show [inserts | keys | references | types | locales | examples [Add | Del | (Modify | Mod) | (Refs | References) | (Attributes | Attr)]
+                 [Inserts] -> Show's The Table's The Program Will Be Creating Insert Statements For
+                 [Keys] -> Show's The List Of Keys For Each Table
+                 [References | Refs] -> Show's All Referenced Attributes Between Two Tables In The Form Of:
+                                        ([Referencing Table]:([Referenced Table]:[Referenced Attribute]))
+                 [Types] -> Lists All Custom Defined Types And The Reasoning For Their Creation For This Program 
+                 [Locales] -> Lists The Supported Locales And The Current Default Locale
+                 [Examples] -> Will Print Examples For Most Commands Taken By The Program
+            "
+        );
+    }
+}
+
+#[cfg(test)]
+// The original tests compare booleans and lengths with assert_eq! and build their cases with vec!
+#[allow(clippy::bool_assert_comparison, clippy::len_zero, clippy::useless_vec)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        io::{stdout, Write},
+    };
+
+    use rand::Rng;
+
+    fn generate_to_string(
+        tables: &[String],
+        key_dictionary: &HashMap<String, Vec<String>>,
+        reference_dictionary: &HashMap<String, Vec<HashMap<String, String>>>,
+        row_counts: &[u64],
+        settings: &super::GenerationSettings,
+    ) -> String {
+        /*
+        Generate The Insert Statements Of The Tables Into A String Instead Of A File
+        */
+        let mut buffer: Vec<u8> = Vec::new();
+        {
+            let mut sink = super::WriterSink::new(&mut buffer);
+            super::generate_mock_data(
+                tables,
+                key_dictionary,
+                reference_dictionary,
+                &mut sink,
+                row_counts,
+                settings,
+            )
+            .unwrap();
+        }
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn test_attribute_datatype() {
+        /*
+        Test Valid and Invalid Data Types
+        Assert Valid Data Types Return True
+        Assert Invalid Data Types Return False
+        */
+
+        let valid_datatype = vec!["INTEGER", "VARCHAR(30)", "PASSWORD(30)"];
+        let invalid_datatype = vec!["INVALID!", "VAFCHAR", "EMMAIL"];
+        for valid in valid_datatype {
+            assert_eq!(super::check_data_type(valid), true);
+        }
+        for invalid in invalid_datatype {
+            assert_eq!(super::check_data_type(invalid), false);
+        }
+    }
+
+    #[test]
+    fn test_key_definitions() {
+        /*
+        Test Valid and Invalid Key Definitions
+        For Valid, Test All Possible Key Definitions
+        For Invalid, Test Spelling Errors and Incorrect Ordering
+        */
+        let valid_key_definitions = vec!["PK", "AK", "FK", "PK/FK", "AK/FK"];
+        let invalid_key_definitions = vec![
+            "KP", "KA", "KF", "PK/AK", "AK/PK", "FK/PK", "FK/AK", "PK/AK/FK",
+        ];
+        for valid in valid_key_definitions {
+            assert_eq!(super::check_key_definition(valid), true);
+        }
+        for invalid in invalid_key_definitions {
+            assert_eq!(super::check_key_definition(invalid), false);
+        }
+    }
+
+    #[test]
+    fn test_random_data_generation() {
+        /*
+        Test Data Generation With Types With Given Size
+        PASSWORD(20), USERNAME(10), MONEY(7), VARCHAR(30)
+        Assert Length of Return > 0 and <= Given Size
+        */
+        let statement_data: HashMap<String, String> = HashMap::new(); //Not Needed For This Test (Only Used For Email Generation)
 
-                                        NAME -> Name is a custom type used to replace typical string DataTypes in sql for
-                                        when the user wishes to generate a realistic name, assigning a typical
-                                        VARCHAR(length) to an attribute where you wish to be a name may result in a randomly
-                                        generated string that does not represent a persons full name in real life
+        let password = super::get_random_data(
+            "PASSWORD(20)",
+            Some(vec![20, 0]),
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(password.len() > 0 && password.len() <= 20, true);
 
-                                        EMAIL -> Email is another custom type used to replace string DataTypes in sql. This
-                                        ensures that every value generated for said attribute will follow standard email
-                                        format 'abc@abc.abc' and also excludes symbols that are commonly excluded in
-                                        deployed email domain services (^&% etc) 
+        let username = super::get_random_data(
+            "USERNAME(10)",
+            Some(vec![10, 0]),
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(username.len() > 0 && username.len() <= 10, true);
 
-                                        PASSWORD(N) -> Password is another custom type used to replace string DataTypes in sql.
-                                        While it generates similar strings that would be generated should you just do a
-                                        VARCHAR(length) variable in its place, PASSWORD types have the added step of 
-                                        ensuring common password complexity constraints are enforced such as
-                                            Password Must Include At Least: 
-                                                [1 Capital Letter, 1 Lowercase, 1 Number, 1 Special Char]
-                                                [N = Max Length Of Password And It Generates Length Between 8..N]
-                                                [NOTE] IF YOU DO NOT ADD A LENGTH TO PASSWORD I.E PASSWORD(40)
-                                                [THEN] THE PROGRAM WILL PANIC. THIS INCLUDES STANDARD VARCHAR(N) AND CHAR(N) TYPES
+        let money = super::get_random_data(
+            "MONEY(7)",
+            Some(vec![7, 0]),
+            super::Locale::En,
+            &statement_data,
+        );
+        //Since money is returned as String and .{}{} (Used for cents) takes up 3 chars. The length of the return should be 7 + 3 = 10
+        assert_eq!(money.len() > 0 && money.len() <= 10, true);
 
-                                        GROUP -> Is a custom DataType that technically would replace String SQL types.
-                                        Assigning this type to an attribute will assign either 'mod' or 'member' as a role
+        let varchar = super::get_random_data(
+            "VARCHAR(30)",
+            Some(vec![30, 0]),
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(varchar.len() > 0 && varchar.len() <= 30, true);
+    }
 
-                                        USERNAME(N) -> Is a custom DataType that technically would replace String SQL types.
-                                        Assigning this type to an attribute will assign a randomly generated username
+    #[test]
+    fn test_email_generation_with_names_in_statement_data() {
+        /*
+        Test Email Generation With Names In Statement Data
+        Assert Email Contains Name
+        This Tests 3 Referenced Column Names (name, full name, full_name) Given With FROM(...)
+        */
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("name".to_string(), "Bob Johnson".to_string());
+        let email = super::get_derived_data(
+            "EMAIL",
+            None,
+            &["name".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(email.contains("BobJohnson"), true);
 
-                                        MONEY(N) -> Is a shorthand for designating decimal values of xxx.xx
-                                        All values will only have 2 decimal places between 0..99
-                                        N -> Max figure for the money value (i.e MONEY(7) generates values between 0.00 and 9,999,999.99)
+        //Reset HashMap and generate new name, then try again
+        statement_data = HashMap::new();
+        statement_data.insert("full name".to_string(), "John Smith".to_string());
+        let email = super::get_derived_data(
+            "EMAIL",
+            None,
+            &["full name".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(email.contains("JohnSmith"), true);
 
-                                        ## NUMEROUS OTHER TYPES HAVE BEEN ADDED THAT I HAVE YET TO ADD DESCRIPTIONS FOR ##
+        //Reset HashMap and generate new name, then try again
+        statement_data = HashMap::new();
+        statement_data.insert("full_name".to_string(), "Jane Doe".to_string());
+        let email = super::get_derived_data(
+            "EMAIL",
+            None,
+            &["full_name".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(email.contains("JaneDoe"), true);
+    }
 
-                                        COMPOUND -> Is a custom DataType that is used to designate a compound attribute
-                                        Compound Attributes Are Attributes That Are Made Up Of Multiple Attributes
-                                        Compound Attributes Must Be Defined In The Following Format:
-                                        [key definition] [attribute name] COMPOUND ([attribute 1]; [attribute 2]; ...; [attribute n])
-                                        Where [key definition] Can Be Null
+    #[test]
+    fn test_derived_data_from_referenced_columns() {
+        /*
+        Derive Columns From Other Columns Of The Same Row
+        Assert The Username Comes From The Name, The Email From The Username And Company
+        And That The Date Of Birth Matches The Age Column (And The Other Way Around)
+        */
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("name".to_string(), "Bob Johnson".to_string());
+        statement_data.insert("company".to_string(), "Kuhn, Schmidt and Bode".to_string());
+        statement_data.insert("age".to_string(), "34".to_string());
+
+        let username = super::get_derived_data(
+            "USERNAME(20)",
+            Some(vec![20, 0]),
+            &["name".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert!(username.starts_with("BobJohnson") && username.len() <= 20);
+        statement_data.insert("username".to_string(), username.clone());
+
+        let email = super::get_derived_data(
+            "EMAIL",
+            None,
+            &["username".to_string(), "company".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(email, format!("{}@kuhnschmidtbode.com", username));
+
+        let date_of_birth = super::get_derived_data(
+            "DATE",
+            None,
+            &["age".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        statement_data.insert("dob".to_string(), date_of_birth);
+        let age = super::get_derived_data(
+            "AGE",
+            None,
+            &["dob".to_string()],
+            super::Locale::En,
+            &statement_data,
+        );
+        assert_eq!(age, "34");
+    }
 
-                                        [*] For More Help With Attributes, Type 'show examples attributes'
-                                        [*] For More Help With Compound Attributes, Type 'show examples compound'
-                                    "
-                                );
-                            }
-                            // Show's Examples Of Commands To Aid The User Optional [Specifier] Shows Only Specific Examples
-                            // If Specifier Not Given. All Examples Are Shown
-                            "examples" | "ex" => {
-                                // Show's Examples Of How To Add Tables
-                                if specifier.is_none() || specifier == Some("add".to_owned()) {
-                                    // Examples For Add
-                                    println!("
-                                        Add Example [Adding 'profile' Table To List]:
-                                        [Add Example #1] -> add 100 profile (PK userID INTEGER, name NAME, AK email EMAIL, password PASSWORD(30), dateOfBirth DATE, lastLogin TIMESTAMP)
-                                        [Add Example #2] -> add 300 friend (PK/FK userID1 INTEGER profile(userID), PK/FK userID2 INTEGER profile(userID), friendDate DATE)
-                                        [Add Example #3] -> add 1000 post (PK postID INTEGER, FK userID INTEGER profile(userID), postDate TIMESTAMP, postContent VARCHAR(1000))
-                                        [Add Example #4] -> add 1000 comment (PK commentID INTEGER, FK userID INTEGER profile(userID), FK postID INTEGER post(postID), commentDate TIMESTAMP, commentContent VARCHAR(1000))
-                                        [Add Example #5] -> add 1000 MBR (PK MBR_ID INTEGER, MBR COMPOUND (X_MIN INTEGER, X_MAX INTEGER, Y_MIN INTEGER, Y_MAX INTEGER))
-                                    ");
-                                }
-                                // Show's Examples Of How To Delete Tables
-                                if specifier.is_none() || specifier == Some("del".to_owned()) {
-                                    // Examples For Del
-                                    println!(
-                                        "
-                                        Rm Example [Assuming 'profile' Table Is Defined]:
-                                        [Rm Example #1] -> Rm profile
-                                    "
-                                    );
-                                }
-                                // Show's Examples Of How To Modify Tables
-                                if specifier.is_none()
-                                    || specifier == Some("modify".to_owned())
-                                    || specifier == Some("mod".to_owned())
-                                {
-                                    // Examples For Modify
-                                    println!(
-                                        "
-                                        Modify Example [Assuming 'profile' Table Is Defined From 'Add' Example]:
-                                        [In This Case This Example Converts The PK 'email' Attribute To A AK]
-                                        
-                                        [Current Attribute Definition] -> PK email EMAIL
-                                        [Mod Example #1] -> mod profile email AK email EMAIL
-                                        [New Attribute Definition] -> AK email EMAIL
+    #[test]
+    fn test_address_data_generation() {
+        /*
+        Test The Address Types Listed In check_data_type
+        STREET_ADDRESS, FULL_ADDRESS, STATE, ZIP
+        Assert Each Type Is Valid And Generates Data In The Expected Shape
+        */
+        let statement_data: HashMap<String, String> = HashMap::new();
+        for data_type in ["STREET_ADDRESS", "FULL_ADDRESS", "STATE", "ZIP"] {
+            assert!(super::check_data_type(data_type));
+        }
 
-                                        [NOTE] The Attribute Definition Must Be In The Same Format As The Original
-                                        "
-                                    );
-                                }
-                                // Explains Reference Formatting To Aid In Table Input
-                                if specifier.is_none()
-                                    || specifier == Some("references".to_owned())
-                                    || specifier == Some("refs".to_owned())
-                                {
-                                    println!(
-                                        "
-                                        Attribute Format Explained:
-                                        All Attributes In A Table Definition Must Be Of The Form:
-                                        '[key definition][attribute name][attribute type][foreign table]'
-                                        Where [key definition] And [foreign table] Can Be Null
+        let street_address =
+            super::get_random_data("STREET_ADDRESS", None, super::Locale::En, &statement_data);
+        let building_number = street_address.split(' ').next().unwrap();
+        assert!(building_number.parse::<u32>().is_ok());
 
-                                        If [key definition] Is A Foreign Key [FK | PK/FK | AK/FK]:
-                                            Then [foreign table] MUST be defined
+        let full_address =
+            super::get_random_data("FULL_ADDRESS", None, super::Locale::En, &statement_data);
+        assert_eq!(full_address.split(", ").count(), 3);
 
-                                        The Following Is An Example PK/FK Attribute
-                                        Example References A Table Named 'profile' And Its Attribute 'userID'
-                                        Attribute -> 'PK/FK userID1 INTEGER profile(userID)'
-                                        The Example Would Assign As So:
-                                            [key definition] = 'PK/FK'
-                                                Is A Primary Key That References Primary Key 'userID' From Table 'profile'
-                                            [attribute name] = 'userID1'
-                                                Self Explanatory, It Is The Name Of The Attribute Being Defined
-                                            [attribute type] = 'INTEGER' 
-                                                Type Definitions On Each Attribute Must Match SQL DataTypes, Not Pythons
-                                            [foreign table] = 'profile(userID)' Where:
-                                                [*] 'profile' Is The Table Being Referenced
-                                                [*] 'userID' Is The Referenced Attribute
-                                        [*] For More Help With Attributes, Type 'show examples attributes'
+        let state = super::get_random_data("STATE", None, super::Locale::En, &statement_data);
+        assert!(!state.is_empty());
 
-                                        In Tables With More Than One PK, It Will Generate Data As A Composite Pair,
-                                        Hence The AK Attribute May Be Needed, This Is Enforces The Unique Value Without
-                                        Worrying About Checking The Keys As Pairs
+        let zip = super::get_random_data("ZIP", None, super::Locale::En, &statement_data);
+        assert!(zip.chars().all(|c| c.is_ascii_digit() || c == '-'));
+    }
 
-                                        In Cases With Tables Having Multiple Attributes: 
-                                        They Must Be Placed In () And Separated By Commas.
-                                        'show examples add' Provides Examples
-                                        "
-                                    );
-                                }
-                                // Explains Attribute Formatting To Aid In Table Input
-                                if specifier.is_none()
-                                    || specifier == Some("attributes".to_owned())
-                                    || specifier == Some("attr".to_owned())
-                                {
-                                    println!(
-                                        "
-                                            Basic Introductory To Attribute Types For SQL Tables
-                                        ---------------------------------------------------------------------------
-                                        [*] To See Custom Defined Types Specifically For This Program
-                                        [*] Type 'show examples types'
+    #[test]
+    fn test_geo_group_consistency() {
+        /*
+        Pick Row Groups For A Table With Two Address Groups
+        Assert Every GEO_* Column Of A Group Comes From The Same Reference Record
+        */
+        let table_attributes: Vec<String> = vec![
+            "city GEO_CITY".to_string(),
+            "state GEO_STATE_ABBR".to_string(),
+            "zip GEO_ZIP".to_string(),
+            "ship COMPOUND (ship_city GEO_CITY(shipping); ship_zip GEO_ZIP(shipping))".to_string(),
+        ];
+        for _ in 0..100 {
+            let mut statement_data: HashMap<String, String> = HashMap::new();
+            super::set_row_groups(&table_attributes, super::Locale::En, &mut statement_data);
+            assert!(statement_data.contains_key("__geo()"));
+            assert!(statement_data.contains_key("__geo(SHIPPING)"));
+
+            let city = super::get_random_data("GEO_CITY", None, super::Locale::En, &statement_data);
+            let state =
+                super::get_random_data("GEO_STATE_ABBR", None, super::Locale::En, &statement_data);
+            let zip = super::get_random_data("GEO_ZIP", None, super::Locale::En, &statement_data);
+            assert!(super::get_geo_records()
+                .iter()
+                .any(|record| record[0] == city && record[2] == state && record[3] == zip));
 
-                                        [Example 1]: 'age INTEGER'
-                                        [key definition] = None
-                                            [*] No Key Definition is Included
-                                        [attribute name] = 'age'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'INTEGER'
-                                            [*] Must Be SQL Data Types Not Rust Data Types
+            let ship_city = super::get_random_data(
+                "GEO_CITY(SHIPPING)",
+                None,
+                super::Locale::En,
+                &statement_data,
+            );
+            let ship_zip = super::get_random_data(
+                "GEO_ZIP(SHIPPING)",
+                None,
+                super::Locale::En,
+                &statement_data,
+            );
+            assert!(super::get_geo_records()
+                .iter()
+                .any(|record| record[0] == ship_city && record[3] == ship_zip));
+        }
+    }
 
-                                        [Example 2]: 'salary DECIMAL(10,2)'
-                                        [key definition] = None
-                                            [*] 'No Key Definition is Included
-                                        [attribute name] = 'salary'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'DECIMAL(10,2)'
-                                            [*] Must Be SQL Data Types Not Rust Data Types
+    #[test]
+    fn test_name_parts_share_one_person() {
+        /*
+        Pick The Row Person For A Table With Split Name Columns
+        Assert NAME, FIRST_NAME, LAST_NAME, MIDDLE_INITIAL And EMAIL All Agree
+        */
+        let table_attributes: Vec<String> = vec![
+            "first_name FIRST_NAME".to_string(),
+            "middle_initial MIDDLE_INITIAL".to_string(),
+            "last_name LAST_NAME".to_string(),
+            "full_name NAME".to_string(),
+            "AK email EMAIL".to_string(),
+        ];
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        super::set_row_groups(&table_attributes, super::Locale::En, &mut statement_data);
+
+        let first_name =
+            super::get_random_data("FIRST_NAME", None, super::Locale::En, &statement_data);
+        let middle_name =
+            super::get_random_data("MIDDLE_NAME", None, super::Locale::En, &statement_data);
+        let middle_initial =
+            super::get_random_data("MIDDLE_INITIAL", None, super::Locale::En, &statement_data);
+        let last_name =
+            super::get_random_data("LAST_NAME", None, super::Locale::En, &statement_data);
+        let full_name = super::get_random_data("NAME", None, super::Locale::En, &statement_data);
+        let email = super::get_random_data("EMAIL", None, super::Locale::En, &statement_data);
+
+        assert_eq!(full_name, format!("{} {}", first_name, last_name));
+        assert!(middle_name.starts_with(&middle_initial));
+        assert!(email.starts_with(&format!("{}{}", first_name, last_name)));
+    }
 
-                                        [Example 3]: 'name NAME'
-                                        [key definition] = None
-                                            [*] No Key Definition is Included
-                                        [attribute name] = 'orderID'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'NAME'
-                                            [*] Must Be SQL Data Types Not Rust Data Types
-                                            [*] NAME Is A Custom Type Made Specifically For This Program
-                                            [*] NAME Type Ensures A Realistic Full Name Is Generated
-                                            [*] To See Custom Defined Types Specifically For This Program
-                                            [*] Type 'show examples types'
+    #[test]
+    fn test_unique_name_keys() {
+        /*
+        Generate More AK FIRST_NAME Rows Than Can Go Without Two Rows Drawing The Same Person
+        Assert A Repeated Name Draws A New Person For The Row, So Every Row Gets Its Own Name
+        */
+        let tables: Vec<String> =
+            vec!["300 t (PK id INTEGER, AK first FIRST_NAME, city GEO_CITY)".to_string()];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            seed: Some(7),
+            positional: true,
+            ..Default::default()
+        };
+        let file = generate_to_string(
+            &tables,
+            &key_dictionary,
+            &reference_dictionary,
+            &[300],
+            &settings,
+        );
+        let first_names: HashSet<&str> = file
+            .lines()
+            .map(|row| row.split(", ").nth(1).unwrap())
+            .collect();
+        assert_eq!(first_names.len(), 300);
 
-                                        [Example 4]: 'PK userID INTEGER'
-                                        [*] Is a Primary Key Attribute
-                                        [key definition] = 'PK'
-                                            [*] 'PK' stands for Primary Key
-                                        [attribute name] = 'userID'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'INTEGER'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
+        // Only the records of the repeated attribute are drawn again
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        let table_attributes: Vec<String> = vec![
+            "AK first FIRST_NAME".to_string(),
+            "city GEO_CITY".to_string(),
+        ];
+        super::set_row_groups(&table_attributes, super::Locale::En, &mut statement_data);
+        let city = statement_data["__geo()"].clone();
+        let people: HashSet<String> = (0..20)
+            .map(|_| {
+                super::redraw_row_groups(
+                    &table_attributes[0],
+                    super::Locale::En,
+                    &mut statement_data,
+                );
+                statement_data["__person(en).first"].clone() + &statement_data["__person(en).last"]
+            })
+            .collect();
+        assert!(people.len() > 1);
+        assert_eq!(statement_data["__geo()"], city);
+    }
+
+    #[test]
+    fn test_unique_derived_keys() {
+        /*
+        Derive An AK Email From A Column With Only Two Values
+        Assert A Repeated Email Gets A Number Added, So Every Row Gets Its Own Email
+        */
+        let tables: Vec<String> = vec![
+            "200 t (PK id INTEGER, handle ONEOF(bob, ann), AK email EMAIL FROM(handle))"
+                .to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            seed: Some(7),
+            positional: true,
+            ..Default::default()
+        };
+        let file = generate_to_string(
+            &tables,
+            &key_dictionary,
+            &reference_dictionary,
+            &[200],
+            &settings,
+        );
+        let emails: HashSet<&str> = file
+            .lines()
+            .map(|row| row.split(", ").nth(2).unwrap())
+            .collect();
+        assert_eq!(emails.len(), 200);
+
+        assert_eq!(super::get_suffixed_data("AGE", None, "34"), "34");
+        let username = super::get_suffixed_data("USERNAME(8)", Some(vec![8, 0]), "BobJohnson");
+        assert!(username.starts_with("Bob") && username.len() == 8);
+        let email = super::get_suffixed_data("EMAIL", None, "bob@mail.com");
+        assert!(
+            email.starts_with("bob") && email.ends_with("@mail.com") && email != "bob@mail.com"
+        );
+    }
+
+    #[test]
+    fn test_locale_suffix_and_localized_data() {
+        /*
+        Parse A Locale Suffix Off A Column Type And Generate Localized Data
+        Assert The Suffix Becomes The Column's Locale, Unknown Suffixes Are Left On The Type
+        And That Localized Rows Use Cities And Post Codes Of That Locale
+        */
+        let mut attribute_definition: Vec<String> =
+            vec!["name".to_string(), "NAME@fr_FR".to_string()];
+        let options = super::extract_column_options(&mut attribute_definition);
+        assert_eq!(attribute_definition, vec!["name", "NAME"]);
+        assert_eq!(options.locale, Some(super::Locale::FrFr));
+
+        assert_eq!(super::parse_locale("DE-de"), Some(super::Locale::DeDe));
+        assert_eq!(
+            super::split_locale("NAME@xx_XX"),
+            ("NAME@xx_XX".to_string(), None)
+        );
 
-                                        [Example 5]: 'AK email EMAIL'
-                                        [*] Is a Alternate Key Attribute
-                                        [key definition] = 'AK'
-                                            [*] 'AK' stands for Alternate Key
-                                        [attribute name] = 'userID'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'EMAIL'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
-                                            [*] EMAIL Is A Custom Type Made Specifically For This Program
-                                            [*] EMAIL Type Ensures A Realistic Email Is Generated
-                                            [*] If A Name Attribute Is Present In The Same Table And Data Gets Generated For It Before Email
-                                            [*] The Email Generated Will Be Representative Of The Name Generated As Well
-                                            [*] Example If A Name 'Bob Johnson' Is Generated For A Tuple:
-                                            [*] Then A Possible Email Generated Would Be BobJohnson@outlook.com
-                                            [*] To See Custom Defined Types Specifically For This Program
-                                            [*] Type 'show examples types'
+        let table_attributes: Vec<String> = vec![
+            "name NAME@de_DE".to_string(),
+            "city GEO_CITY@it_IT".to_string(),
+            "zip GEO_ZIP".to_string(),
+        ];
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        super::set_row_groups(&table_attributes, super::Locale::En, &mut statement_data);
 
-                                        [Example 6]: 'FK customerID INTEGER customers(customerID)'
-                                        [*] Is a Foreign Key Attribute Referencing the 'customers' Table's 'customerID' Attribute
-                                        [key definition] = 'FK'
-                                            [*] 'FK' stands for Foreign Key
-                                        [attribute name] = 'customerID'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'INTEGER'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
+        let city = super::get_random_data("GEO_CITY", None, super::Locale::En, &statement_data);
+        let country =
+            super::get_random_data("GEO_COUNTRY", None, super::Locale::En, &statement_data);
+        assert_eq!(country, "Italia");
+        assert!(super::get_geo_records()
+            .iter()
+            .any(|record| record[0] == city && record[6] == "Italia"));
+
+        let zip = super::get_random_data("ZIP", None, super::Locale::PtBr, &statement_data);
+        assert!(zip.len() == 9 && zip.chars().nth(5) == Some('-'));
+
+        // Names without latin letters still give ascii usernames
+        statement_data.clear();
+        statement_data.insert("__person(ja_JP).first".to_string(), "結衣".to_string());
+        statement_data.insert("__person(ja_JP).last".to_string(), "佐藤".to_string());
+        let username =
+            super::get_random_data("USERNAME", None, super::Locale::JaJp, &statement_data);
+        assert!(username.is_ascii() && username.starts_with("user"));
+    }
 
-                                        [Example 7]: 'AK/FK customerID INTEGER references customers(customerID)'
-                                        [*] Is an Alternate Key and Foreign Key Attribute References the 'customers' Table's 'customerID' Attribute
-                                        [key definition] = 'AK/FK'
-                                            [*] 'AK/FK' stands for Alternate Key/Foreign Key
-                                        [attribute name] = 'customerID'
-                                            [*] Name of Attribute Being Defined
-                                        [attribute type] = 'INTEGER'
-                                            [*] Must be SQL Data Types, not Rust Data Types
+    #[test]
+    fn test_value_types() {
+        /*
+        Read The Values Of CONST / ONEOF Types And Generate Them
+        Assert Quotes Are Removed (And Escaped Again In The Insert), The Case Is Kept
+        And DEFAULT Is Written As The SQL Keyword
+        */
+        assert_eq!(
+            super::normalize_type("oneof('Mod', 'Member')"),
+            "ONEOF('Mod', 'Member')"
+        );
+        assert_eq!(
+            super::get_value_list("ONEOF('New York', 'O''Brien', 42)"),
+            vec!["New York", "O'Brien", "42"]
+        );
+        assert!(super::get_value_list("CONST()").is_empty());
+
+        let statement_data: HashMap<String, String> = HashMap::new();
+        let country =
+            super::get_random_data("CONST('us')", None, super::Locale::En, &statement_data);
+        assert_eq!(country, "us");
+        let status = super::get_random_data(
+            "ONEOF(active, 'banned, for now')",
+            None,
+            super::Locale::En,
+            &statement_data,
+        );
+        assert!(status == "active" || status == "banned, for now");
 
-                                        [Example 8]: 'PK/FK bookID INTEGER authors(bookID)'
-                                        [*] Is a Primary Key and Foreign Key Attribute Referencing the 'authors' Table's 'bookID' Attribute
-                                        [key definition] = 'PK/FK'
-                                            [*] 'PK' stands for Primary Key
-                                            [*] 'FK' stands for Foreign Key
-                                            [*] 'PK/FK' stands for Primary Key with a Foreign Reference
-                                        [attribute name] = 'bookID'
-                                            [*] Name of Attribute being Defined
-                                        [attribute type] = 'INTEGER'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
-                                        [foreign table] = 'authors(bookID)'
-                                            [*] Where Again, 'authors' Is The Table Being Referenced and 'bookID' Is The Referenced Attribute From The Table
+        assert_eq!(
+            super::format_sql_value("O'Brien", "VARCHAR(20)"),
+            "'O''Brien'"
+        );
+        assert_eq!(super::format_sql_value("DEFAULT", "DEFAULT"), "DEFAULT");
 
-                                        [Example 9]: 'PK userID INTEGER, PK postID INTEGER
-                                        [*] These Primary Key Attributes Create A Composite Key
-                                        [key definition] = 'PK'
-                                            [*] 'PK' stands for Primary Key
-                                        [attribute name 1] = 'userID'
-                                            [*] Name of the First Attribute Being Defined
-                                        [attribute type 1] = 'INTEGER'
-                                            [*] Must be SQL Data Types, Not Rust Data Types
-                                        [attribute name 2] = 'postID'
-                                            [*] Name of the second Attribute Being Defined
-                                        [attribute type 2] = 'INTEGER'
-                                            [*] Must be SQL Data Types, not Rust Data Types
-                                        [*] Both 'userID' and 'postID' are Primary Keys
-                                        [*] This Means Their Combination Will Be Treated As A Composite Key Pair When Generating Random Data For The Table. 
-                                        [*] This Means That Any Two Rows On The Table Cannot Have The Same Combination of 'userID' and 'postID'.
+        // Only the DEFAULT type is the keyword, quoted values stay text
+        assert_eq!(
+            super::format_sql_value("default", "CONST('default')"),
+            "'default'"
+        );
+        assert_eq!(
+            super::format_sql_value("default", "ONEOF(default, custom)"),
+            "'default'"
+        );
+        assert_eq!(
+            super::format_sql_value("default", "VARCHAR(10)"),
+            "'default'"
+        );
+        assert_eq!(super::format_sql_value("42", "CONST('42')"), "'42'");
+        assert_eq!(super::format_sql_value("42", "CONST(42)"), "42");
+        assert_eq!(super::format_sql_value("42", "ONEOF('41', 42)"), "42");
+        assert_eq!(super::format_sql_value("NULL", "CONST('NULL')"), "'NULL'");
+        assert_eq!(super::format_sql_value("NULL", "CONST(NULL)"), "NULL");
+        assert_eq!(super::format_sql_value("NULL", "ONEOF('a', 'b')"), "NULL");
+
+        // Leading zeros and other number formats are only text for ZIP / GEO_* values
+        assert_eq!(
+            super::format_sql_value("02134", "GEO_ZIP(billing)"),
+            "'02134'"
+        );
+        assert_eq!(super::format_sql_value("02134", "ZIP"), "'02134'");
+        assert_eq!(super::format_sql_value("0.5", "GEO_LAT"), "0.5");
+        assert_eq!(super::format_sql_value("007", "INTEGER"), "007");
+        assert_eq!(super::format_sql_value("1e5", "CONST(1e5)"), "1e5");
+        assert_eq!(super::format_sql_value("inf", "VARCHAR(5)"), "'inf'");
+    }
 
-                                        [Example 10]: 'PK/FK bookID INTEGER authors(bookID), PK/FK publisherID INTEGER publishers(publisherID)'
-                                        [*] These Primary Key Attributes Create A Composite Key Where Each Attribute Also References An Exterior Table
-                                        [key definition] = 'PK/FK'
-                                            [*] 'PK' stands for Primary Key
-                                            [*] 'FK' stands for Foreign Key
-                                            [*] 'PK/FK' stands for Primary Key with a Foreign Reference
-                                        [attribute name 1] = 'bookID'
-                                            [*] Name of the First Attribute being Defined
-                                        [attribute type 1] = 'INTEGER'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
-                                        [attribute name 2] = 'publisherID'
-                                            [*] Name of the Second Attribute being Defined
-                                        [attribute type 2] = 'INTEGER'
-                                            [*] Must Be SQL Data Types, Not Rust Data Types
-                                        [foreign table] = 'authors(bookID), publishers(publisherID)'
-                                            [*] Where Again, 'authors' Is The Table Being Referenced and 'bookID' Is The Referenced Attribute From The Table
-                                            [*] As Well As, 'publisher' Is The Table Being Referenced and 'publisherID' Is The Referenced Attribute From The Table
-                                            [*] Both 'userID' and 'postID' are Primary Keys
-                                            [*] This Means Their Combination Will Be Treated As A Composite Key Pair When Generating Random Data For The Table. 
-                                            [*] This Means That Any Two Rows On The Table Cannot Have The Same Combination of 'userID' and 'postID'.
+    #[test]
+    fn test_expression_columns() {
+        /*
+        Parse EXPR(...) Types And Evaluate Them Against Earlier Columns Of The Row
+        Assert Arithmetic, Date Math, String Functions And Conditionals Work
+        And That A NULL Column Or An Unknown Function Is Handled
+        */
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("quantity".to_string(), "3".to_string());
+        statement_data.insert("unit_price".to_string(), "2.50".to_string());
+        statement_data.insert("start_date".to_string(), "2024-01-31".to_string());
+        statement_data.insert("title".to_string(), "Hello, World!".to_string());
+        statement_data.insert("phone".to_string(), "NULL".to_string());
+
+        let expressions = [
+            ("EXPR(quantity * unit_price)", "7.5"),
+            ("EXPR((quantity + 1) % 3 - -1)", "2"),
+            ("EXPR(start_date + 30)", "2024-03-01"),
+            ("EXPR(date_add(start_date, 1, 'month'))", "2024-02-29"),
+            ("EXPR(date_diff('2024-12-25', start_date))", "329"),
+            (
+                "EXPR(slug(title) || '-' || lower(substr(title, 8)))",
+                "hello-world-world!",
+            ),
+            (
+                "EXPR(IF(quantity * unit_price > 5 AND NOT quantity = 1, 'big', 'small'))",
+                "big",
+            ),
+            ("EXPR(coalesce(phone, 'n/a'))", "n/a"),
+            ("EXPR(round(unit_price / quantity, 2))", "0.83"),
+            ("EXPR(upper(phone))", "NULL"),
+        ];
+        for (expression, expected) in expressions {
+            let parsed = super::parse_expression(&expression[5..expression.len() - 1]).unwrap();
+            assert_eq!(
+                super::evaluate_expression(&parsed, &statement_data).to_string(),
+                expected,
+                "{}",
+                expression
+            );
+        }
 
-                                        [Example 11]: 'PK userID INTEGER, AK email EMAIL'
-                                        [key definition 1] = 'PK'
-                                            [*] 'PK' stands for Primary Key
-                                        [attribute name 1] = 'userID'
-                                            [*] Name of Attribute Being Defined
-                                        [attribute type 1] = 'INTEGER'
-                                            [*] Must be SQL Data Types, not Rust Data Types
-                                        [key definition 2] = 'AK'
-                                            [*] 'AK' stands for Alternate Key
-                                        [attribute name 2] = 'email'
-                                            [*] Name of Attribute Being Defined
-                                        [attribute type 2] = 'EMAIL'
-                                            [*] Must be SQL Data Types, not Rust Data Types
-                                            [*] EMAIL Is A Custom Type Made Specifically For This Program
-                                            [*] EMAIL Type Ensures A Realistic Email Is Generated
-                                            [*] To See Custom Defined Types Specifically For This Program
-                                            [*] Type 'show examples types'
-                                            [*] In This table, The 'userID' Attribute Is A Primary Key And The 'email' Attribute Is An Alternate Key.
-                                            [*] While Having Multiple Primary Keys In A Table Leads To A Composite Key Pair.
-                                            [*] Having A Primary Key And An Alternate Key In The Same Table Doesn't Necessarily Result In A Composite Key.
-                                            [*] The Alternate Key May Still Provide A Unique Constraint, But It Doesn't Have The Same Significance As The Primary Key.
-                                        "
-                                    );
-                                }
-                                // Add examples for compound attributes
-                                if specifier.is_none()
-                                    || specifier == Some("compound".to_owned())
-                                    || specifier == Some("comp".to_owned())
-                                {
-                                    println!(
-                                        "
-                                            Compound Attribute Format Explained:
+        let mut columns: Vec<String> = Vec::new();
+        let parsed = super::parse_expression("IF(\"unit price\" > 2, quantity, 0)").unwrap();
+        super::get_expression_columns(&parsed, &mut columns);
+        assert_eq!(columns, vec!["unit price", "quantity"]);
 
-                                            Compound Attributes Are Attributes That Are Defined As A Group Of Attributes
-                                            That Are All Treated As One Attribute When Generating Data For The Table
-                                            For Example. If You Have A Table User That Stores A Users FulL Name As A Compound Attribute
-                                            You Would Define It As So:
-                                            [Example 1]: 'full_name COMPOUND (FIRST_NAME VARCHAR(20); MIDDLE_INITIAL CHAR(1); LAST_NAME VARCHAR(20))'
-                                            [**IMPORTANT NOTE**]: Compound Attributes Must Be Defined In '()' And Separated By ';'
-                                            Compound Attributes Can Be Keyless, Have A Key Definition, Or Have A Key Definition With A Foreign Key Reference
-                                            Here Are Some Examples:
-                                            [Example 2]: 'AK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER)'
-                                            [Example 3]: 'PK/FK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER) region(MBR)'
-                                            [Example 4]: 'PK MBR COMPOUND (X_MIN INTEGER; X_MAX INTEGER; Y_MIN INTEGER; Y_MAX INTEGER)'
-                                        "
-                                    )
-                                }
-                            }
-                            _ => {
-                                println!("Invalid Argument For Show Command");
-                                println!("Usage: Show [inserts | keys | references | examples [optional - [add | del | (modify | mod) | (refs | references) | (attributes | attr)]]");
-                            }
-                        }
-                    }
-                    _ => {
-                        println!("Invalid Command Entered");
-                        println!("Type 'Help' For List of Commands");
-                    }
-                }
-            }
+        assert!(super::parse_expression("foo(quantity)").is_err());
+        assert!(super::parse_expression("quantity +").is_err());
+        assert!(super::parse_expression("'unclosed").is_err());
+
+        // The expressions are parsed once for the table, one that does not parse stops the generation
+        let tables: Vec<String> = vec![
+            "3 t (PK id INTEGER, next EXPR(id + 1), pair COMPOUND (a EXPR(id * 2); b INTEGER))"
+                .to_string(),
+            "3 u (PK id INTEGER, pair COMPOUND (a EXPR(id +); b INTEGER))".to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            seed: Some(7),
+            positional: true,
+            ..Default::default()
+        };
+        let mut buffer: Vec<u8> = Vec::new();
+        let result = {
+            let mut sink = super::WriterSink::new(&mut buffer);
+            super::generate_mock_data(
+                &tables,
+                &key_dictionary,
+                &reference_dictionary,
+                &mut sink,
+                &[3, 3],
+                &settings,
+            )
+        };
+        assert!(
+            matches!(result, Err(super::GenerationError::Rows(reason)) if reason.contains("EXPR(id +)"))
+        );
+        let file = String::from_utf8(buffer).unwrap();
+        assert_eq!(file.lines().count(), 3);
+        for row in file.lines() {
+            let values: Vec<i64> = row
+                .trim_start_matches("INSERT INTO t VALUES (")
+                .trim_end_matches(");")
+                .split([',', '(', ')', ' '])
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse().unwrap())
+                .collect();
+            assert_eq!(values[1], values[0] + 1);
+            assert_eq!(values[2], values[0] * 2);
         }
     }
-}
 
-fn display_help(display_all: bool) {
-    /*
-     * Displays the help menu
-     * If display_all is true, display the entire help menu
-     * If display_all is false, display the help menu for the generate command
-     */
-    println!(
-        "
-                        ===== MESSAGE =====
+    #[test]
+    fn test_check_constraints() {
+        /*
+        Read CHECK Constraints Off The Table Attributes And Infer Column Ranges From Them
+        Assert Numbers Are Moved Inside Of The Bounds And Keep Their Decimal Places
+        And That IN / BETWEEN Are Evaluated Like SQL
+        */
+        assert_eq!(
+            super::get_table_check("check (price > 0)"),
+            Some("price > 0")
+        );
+        assert_eq!(super::get_table_check("check_in DATE"), None);
+
+        let checks: Vec<super::Expression> = [
+            "price BETWEEN 10 AND 500 AND 0 < price",
+            "qty > 5 AND qty <= 20",
+            "status IN ('open', 'closed') AND status = 'open'",
+            "end_date >= start_date OR qty > 100",
+        ]
+        .iter()
+        .map(|check| super::parse_expression(check).unwrap())
+        .collect();
+        let ranges = super::get_check_ranges(&checks);
+        assert_eq!(ranges["price"].minimum, Some((10.0, true)));
+        assert_eq!(ranges["price"].maximum, Some((500.0, true)));
+        assert_eq!(ranges["qty"].minimum, Some((5.0, false)));
+        assert_eq!(ranges["status"].allowed_values, vec!["open"]);
+        assert!(!ranges.contains_key("end_date"));
+
+        let positive = super::ColumnOptions {
+            minimum: Some((0.0, true)),
+            ..Default::default()
+        };
+        assert_eq!(
+            super::get_ranged_data("-25.50".to_string(), &positive),
+            "25.50"
+        );
+        assert_eq!(
+            super::get_ranged_data("12".to_string(), &ranges["qty"]),
+            "12"
+        );
+        assert_eq!(
+            super::get_ranged_data("abc".to_string(), &ranges["qty"]),
+            "abc"
+        );
+        let qty: i32 = super::get_ranged_data("5".to_string(), &ranges["qty"])
+            .parse()
+            .unwrap();
+        assert!((6..=20).contains(&qty));
+        for _ in 0..100 {
+            let price: f64 = super::get_ranged_data("9000.25".to_string(), &ranges["price"])
+                .parse()
+                .unwrap();
+            assert!((10.0..=500.0).contains(&price));
+        }
 
-            [*]This Program Takes User Input For SQL Table Values And Generates Random Insert Statements For Database Testing
-            [*]All Tables Should Be Defined Using The Following Format:
-                    Format: [Number Of Insert Statements][Table Name][Table Attributes]
-            [*]An Example Of An Accepted Table Definition And An Accepted Add Command:
-            [*]add 100 profile (PK userID INTEGER, name NAME, AK email EMAIL, password PASSWORD(30))
-            [*]Where 'add' Adds The [Number Of Insert Statements][Table Name][Table Attributes] To A List Used For Generation
-            [*]This Program Can Generate Any Number Of Inserts For Any Number Of SQL Tables As Long As User Memory Permits
-            [*]The Commands For This Program Are Not Case Sensitive
-            [*]Type 'Help' To Get A List Of All Commands And To Reprint This Message
-            [*]To See Example Commands For Each Command:
-                    Type 'show examples [specific command (optional - will show all examples if blank)]'
-            [*]Type 'Clear' To Delete This Message
-            \n"
-    );
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("status".to_string(), "open".to_string());
+        statement_data.insert("qty".to_string(), "7".to_string());
+        for (expression, expected) in [
+            (
+                "status NOT IN ('closed', 'void')",
+                super::ExpressionValue::Bool(true),
+            ),
+            (
+                "qty NOT BETWEEN 1 AND 10",
+                super::ExpressionValue::Bool(false),
+            ),
+            ("missing IN (1, 2)", super::ExpressionValue::Null),
+        ] {
+            let parsed = super::parse_expression(expression).unwrap();
+            assert_eq!(
+                super::evaluate_expression(&parsed, &statement_data),
+                expected
+            );
+        }
+    }
 
-    if display_all {
-        println!(
-            "
-                        ===== COMMANDS AND DEFINITIONS=====
+    #[test]
+    fn test_unsatisfiable_check() {
+        /*
+        Generate A Table Whose CHECK No Row Can Pass After A Table That Is Fine
+        Assert Generation Returns An Error Naming The Table And Keeps The Rows Written Before
+        */
+        let tables: Vec<String> = vec![
+            "5 profile (PK userID INTEGER)".to_string(),
+            "20 post (PK postID INTEGER, a INTEGER, CHECK (a * 0 = 1))".to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::from([
+            ("profile".to_string(), vec!["PK USERID INTEGER".to_string()]),
+            ("post".to_string(), vec!["PK POSTID INTEGER".to_string()]),
+        ]);
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            positional: true,
+            ..Default::default()
+        };
+        let mut buffer: Vec<u8> = Vec::new();
+        let result = {
+            let mut sink = super::WriterSink::new(&mut buffer);
+            super::generate_mock_data(
+                &tables,
+                &key_dictionary,
+                &reference_dictionary,
+                &mut sink,
+                &[5, 20],
+                &settings,
+            )
+        };
+        match result {
+            Err(super::GenerationError::Rows(reason)) => {
+                assert!(
+                    reason.contains("post") && reason.contains("CHECK (a * 0 = 1)"),
+                    "{}",
+                    reason
+                )
+            }
+            other => panic!("Expected The CHECK To Fail, Got {:?}", other),
+        }
+        let file = String::from_utf8(buffer).unwrap();
+        assert_eq!(file.lines().count(), 5);
+        assert!(file
+            .lines()
+            .all(|statement| statement.starts_with("INSERT INTO profile VALUES")));
+    }
 
-            Commands Followed By [] Are Required Args Unless Specifically Stated In This Menu
+    #[test]
+    fn test_value_distributions() {
+        /*
+        Parse DIST(...) Modifiers And Draw Values From Them
+        Assert Dates Are Read As Days, Drawn Values Stay In The Distribution's Range
+        And Invalid Distributions Give A Reason
+        */
+        let mut attribute_definition: Vec<String> = "signup DATE DIST(normal, '2021-06-01', 30)"
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .collect();
+        let options = super::extract_column_options(&mut attribute_definition);
+        assert_eq!(attribute_definition, vec!["signup", "DATE"]);
+        assert_eq!(
+            options.distribution,
+            Some(Ok(super::ValueDistribution::Normal(18779.0, 30.0)))
+        );
 
-            Generate -> Begins Generating SQL Script Of Inserts [Mut Have At Least One Defined Table]
-            Clear -> Clear Terminal Screen
-            Help -> Show This Help Menu
-            Exit | Quit -> Terminate Program
+        assert_eq!(
+            super::parse_distribution("histogram, 1:3:10, 4:5:90"),
+            Ok(super::ValueDistribution::Histogram(vec![
+                (1.0, 3.0, 10.0),
+                (4.0, 5.0, 90.0)
+            ]))
+        );
+        let invalid_distributions = [
+            "",
+            "uniform, 1, 2",
+            "normal, 1",
+            "normal, 1, -1",
+            "zipf, 0.5, 1",
+            "histogram, 5:1:1",
+        ];
+        for invalid in invalid_distributions {
+            assert!(super::parse_distribution(invalid).is_err(), "{}", invalid);
+        }
 
-            Add  [numInserts][tableName][tableAttributes] -> Add Table To Generate Statements For, Where:
-                 [numInserts] -> The Number Of Insert Statements To Generate For The Table
-                 [tableName] -> The Name of The Table Being Created
-                 [tableAttributes] -> All Defined Columns Of The Table [Format Shown Below]
-                 [tableAttributes] Should Be Input In The Following Format:
+        let signup_distribution = options.distribution.unwrap().unwrap();
+        let zipf = super::parse_distribution("zipf, 10, 1.2, 100").unwrap();
+        let histogram = super::parse_distribution("histogram, 18:30:0, 30:40:1").unwrap();
+        let mut total = 0.0;
+        for _ in 0..2000 {
+            let views: i64 = super::get_distributed_data("INTEGER", None, &zipf)
+                .parse()
+                .unwrap();
+            assert!((100..=109).contains(&views));
+            let age: f64 =
+                super::get_distributed_data("DECIMAL(4, 1)", Some(vec![4, 1]), &histogram)
+                    .parse()
+                    .unwrap();
+            assert!((30.0..=40.0).contains(&age));
+
+            let signup = super::get_distributed_data("DATE", None, &signup_distribution);
+            total += (chrono::NaiveDate::parse_from_str(&signup, "%Y-%m-%d").unwrap()
+                - chrono::NaiveDate::from_ymd_opt(2021, 6, 1).unwrap())
+            .num_days() as f64;
+        }
+        // The mean of 2000 draws with a deviation of 30 days lands well within 5 days of the mean date
+        assert!((total / 2000.0).abs() < 5.0);
+
+        // Values past what the type holds are moved to its limit
+        let large = super::parse_distribution("normal, 5000000000, 1").unwrap();
+        let small = super::parse_distribution("normal, -500, 1").unwrap();
+        assert_eq!(
+            super::get_distributed_data("INTEGER", None, &large),
+            i32::MAX.to_string()
+        );
+        assert_eq!(
+            super::get_distributed_data("DECIMAL(4,2)", Some(vec![4, 2]), &large),
+            "99.99"
+        );
+        assert_eq!(
+            super::get_distributed_data("DECIMAL(4,2)", Some(vec![4, 2]), &small),
+            "-99.99"
+        );
+        assert_eq!(
+            super::get_distributed_data("MONEY(3)", Some(vec![3]), &large),
+            "999.99"
+        );
+        let near_limit = super::parse_distribution("histogram, 99.996:99.999:1").unwrap();
+        assert_eq!(
+            super::get_distributed_data("DECIMAL(4,2)", Some(vec![4, 2]), &near_limit),
+            "99.99"
+        );
+
+        // Long tails stay between 0001-01-01 and 9999-12-31
+        let long_tails = [
+            super::parse_distribution("lognormal, 30, 5").unwrap(),
+            super::parse_distribution("exponential, 0.00000000001").unwrap(),
+            super::parse_distribution("normal, '2020-01-01', 100000000").unwrap(),
+        ];
+        for distribution in &long_tails {
+            for _ in 0..200 {
+                let date = super::get_distributed_data("DATE", None, distribution);
+                assert!(
+                    chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d").is_ok(),
+                    "{}",
+                    date
+                );
+                assert!(
+                    ("0001-01-01"..="9999-12-31").contains(&date.as_str()),
+                    "{}",
+                    date
+                );
+                let timestamp = super::get_distributed_data("TIMESTAMP", None, distribution);
+                assert!(
+                    chrono::NaiveDateTime::parse_from_str(&timestamp, "%Y-%m-%d %H:%M:%S").is_ok(),
+                    "{}",
+                    timestamp
+                );
+            }
+        }
+        assert_eq!(
+            super::get_distributed_data("DATE", None, &large),
+            "9999-12-31"
+        );
+        assert_eq!(
+            super::get_distributed_data("TIMESTAMP", None, &large),
+            "9999-12-31 23:59:59"
+        );
+    }
 
-                 [key definition][referenced_attr name][referenced_attr type][foreign table]
+    #[test]
+    fn test_foreign_key_fanout() {
+        /*
+        Parse FANOUT(...) Modifiers And Pick Parents Through A FanOutPicker
+        Assert Parents Below min Are Picked First, Parents At max Are Never Picked (An Error Once All Are Full)
+        And That Taking A Child Back Opens The Parent Again
+        */
+        assert_eq!(
+            super::parse_fanout("zipf, 1.2, min 1, max 20"),
+            Ok(super::FanOut {
+                shape: super::FanOutShape::Zipf(1.2),
+                minimum: 1,
+                maximum: Some(20),
+            })
+        );
+        for invalid in [
+            "zipf",
+            "hot, 0.1",
+            "hot, 2, 0.5",
+            "max 0",
+            "min 3, max 2",
+            "skewed",
+        ] {
+            assert!(super::parse_fanout(invalid).is_err(), "{}", invalid);
+        }
 
-                 Where [key definition] and [foreign table] can be NULL
-                 Key Definitions:
-                    'PK' - Primary Key
-                    'AK' - Alternate (Unique) Key
-                    'FK' - Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
-                    'PK/FK' - Primary Key And Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
-                    'AK/FK' - Alt (Unique) And Foreign Key -> [foreign table] - Table Being Referenced By The Attribute
+        let parents: Vec<String> = (1..=5).map(|parent| parent.to_string()).collect();
+        let fanout = super::parse_fanout("min 1, max 2").unwrap();
+        let mut picker = super::FanOutPicker::new(fanout, parents);
+        let mut first_picks: Vec<String> = Vec::new();
+        for _ in 0..5 {
+            let parent = picker.pick().unwrap();
+            assert!(!first_picks.contains(&parent));
+            picker.add_child(&parent);
+            first_picks.push(parent);
+        }
+        for _ in 0..5 {
+            let parent = picker.pick().unwrap();
+            picker.add_child(&parent);
+        }
+        assert!(picker.children.iter().all(|children| *children == 2));
+        assert_eq!(picker.pick(), None);
+        let full = super::get_parent_data("userID", Some(&picker), &super::ValueStore::default());
+        assert!(
+            matches!(full, Err(super::GenerationError::Rows(reason)) if reason.contains("userID"))
+        );
 
-                 [Note 1]: In Cases Of An Attribute Being A Foreign Key, [foreign table] CANNOT be NULL
-                 To Ensure A Foreign Key Attribute Is Properly Read And Referenced A Specific Format Is Provided:
-                 [Foreign Table] Proper Format -> referenced_table(referenced_attribute)
-                 For More Info On Referencing Syntax With Examples. Input 'show examples [refs | references]'
+        picker.remove_child("3");
+        assert_eq!(picker.pick(), Some("3".to_string()));
+        picker.remove_child("3");
+        picker.remove_child("3");
+        assert_eq!(picker.pick(), Some("3".to_string()));
+    }
 
-                 [Note 2]: In Tables With More Than One PK, It Will Generate Data As A Composite Pair
-                 Hence The AK Attribute May Be Needed, This Enforces The Unique Values Without Worrying About Checking The Keys As Pairs
+    #[test]
+    fn test_relative_row_counts() {
+        /*
+        Parse Absolute And Relative Row Counts And Work Out The Rows Of Every Table
+        Assert Relative Counts Follow The Rows Of Their Parent Table
+        */
+        assert_eq!(
+            super::parse_row_count("100"),
+            Some(super::RowCount::Absolute(100))
+        );
+        assert_eq!(
+            super::parse_row_count("1.5X"),
+            Some(super::RowCount::PerParent(1.5, None))
+        );
+        assert_eq!(
+            super::parse_row_count("2-5x:profile"),
+            Some(super::RowCount::PerParentRange(
+                2,
+                5,
+                Some("profile".to_string())
+            ))
+        );
+        for invalid in ["x", "5-2x", "100:profile", "3x:", "-1x"] {
+            assert_eq!(super::parse_row_count(invalid), None, "{}", invalid);
+        }
 
-            Rm   [tableName] -> Remove Table From List
-                 [tableName] Must Be The Same As It Was Defined In It's Add Statement
+        let tables: Vec<String> = vec![
+            "10 profile (PK userID INTEGER)".to_string(),
+            "3x post (PK postID INTEGER, FK userID INTEGER profile(userID))".to_string(),
+            "2-4x:post comment (PK id INTEGER, FK postID INTEGER post(postID))".to_string(),
+        ];
+        let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+            HashMap::new();
+        reference_dictionary.insert(
+            "post".to_string(),
+            vec![HashMap::from([(
+                "profile".to_string(),
+                "userID".to_string(),
+            )])],
+        );
+        let row_counts = super::get_row_counts(&tables, &reference_dictionary, 1.0);
+        assert_eq!(row_counts[..2], [10, 30]);
+        assert!((60..=120).contains(&row_counts[2]));
+    }
 
-            Modify | Mod [tableName] [numStatements | tableName | referenced_attr] [newValue] -> Modify Existing Table
-                 [tableName] -> The Table To Modify And Must Be The Same As It Was Defined In It's Add Statement
-                 [numInserts | tableName | referenced_attr] -> The Definition Of The Tuple You Wish To Modify 
-                 Note: You Can Only Modify One Definition At A Time
-                 IMPORTANT REGARDING Modify [numInserts | tableName]:
-                 If User Wishes To Modify The Number of Statements or Change Table Name. The User Should Input 'numStatements'
-                 or 'tableName' in Place of First Arg After Specifying The Table: However, When Modifying [referenced_attr],
-                 The User Should Input the Attribute Name They Wish To Change. Type 'show examples mod' For More Info
-                 [*]For 'Modify [tableName] [referenced_attr]':
-                    The User Must Redefine The Whole Attribute 
-                    As In Redefine [key definition][referenced_attr name][referenced_attr type][foreign table]
+    #[test]
+    fn test_large_row_counts() {
+        /*
+        Count Millions Of Rows Per Table Across A Parent And Its Children
+        Assert Counts Past 65535 Are Accepted, Kept Exact And An INTEGER Key Can Hold Them
+        */
+        assert_eq!(
+            super::parse_row_count("70000"),
+            Some(super::RowCount::Absolute(70_000))
+        );
+        assert_eq!(
+            super::parse_row_count("9007199254740993"),
+            Some(super::RowCount::Absolute(9_007_199_254_740_993))
+        );
 
-            Show [inserts | keys | references | types | examples [Add | Del | (Modify | Mod) | (Refs | References) | (Attributes | Attr)]
-                 [Inserts] -> Show's The Table's The Program Will Be Creating Insert Statements For
-                 [Keys] -> Show's The List Of Keys For Each Table
-                 [References | Refs] -> Show's All Referenced Attributes Between Two Tables In The Form Of:
-                                        ([Referencing Table]:([Referenced Table]:[Referenced Attribute]))
-                 [Types] -> Lists All Custom Defined Types And The Reasoning For Their Creation For This Program 
-                 [Examples] -> Will Print Examples For Most Commands Taken By The Program
-            "
+        let tables: Vec<String> = vec![
+            "10000000 profile (PK userID INTEGER)".to_string(),
+            "3x post (PK postID INTEGER, FK userID INTEGER profile(userID))".to_string(),
+        ];
+        let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+            HashMap::new();
+        reference_dictionary.insert(
+            "post".to_string(),
+            vec![HashMap::from([(
+                "profile".to_string(),
+                "userID".to_string(),
+            )])],
         );
+        let row_counts = super::get_row_counts(&tables, &reference_dictionary, 1.0);
+        assert_eq!(row_counts, vec![10_000_000, 30_000_000]);
+        assert_eq!(row_counts.iter().sum::<u64>(), 40_000_000);
+        assert!(super::check_key_spaces(
+            &tables,
+            &row_counts,
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::{
-        collections::HashMap,
-        io::{stdout, Write},
-    };
+    #[test]
+    fn test_scale_and_key_spaces() {
+        /*
+        Scale The Row Counts Of A Schema And Check Its Unique And Composite Keys Can Hold Them
+        Assert Only Counts Added As A Number Are Scaled And Too Small Key Spaces Are Refused
+        */
+        assert_eq!(super::parse_scale("2.5"), Some(2.5));
+        for invalid in ["0", "-3", "abc", "inf"] {
+            assert_eq!(super::parse_scale(invalid), None, "{}", invalid);
+        }
+        assert_eq!(
+            super::get_value_space("ONEOF(a, b, a)", None, super::Locale::En),
+            Some(2)
+        );
+        assert_eq!(
+            super::get_value_space("CHAR", Some(vec![2]), super::Locale::En),
+            Some(62 * 62)
+        );
+        assert_eq!(
+            super::get_value_space("EMAIL", None, super::Locale::En),
+            None
+        );
 
-    use rand::Rng;
+        let mut tables: Vec<String> = vec![
+            "10 profile (PK userID INTEGER, AK flag BOOLEAN)".to_string(),
+            "2x post (PK postID INTEGER, FK userID INTEGER profile(userID))".to_string(),
+        ];
+        let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+            HashMap::new();
+        reference_dictionary.insert(
+            "post".to_string(),
+            vec![HashMap::from([(
+                "profile".to_string(),
+                "userID".to_string(),
+            )])],
+        );
+        let row_counts = super::get_row_counts(&tables, &reference_dictionary, 0.2);
+        assert_eq!(row_counts, [2, 4]);
+        assert!(super::check_key_spaces(
+            &tables,
+            &row_counts,
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+        let row_counts = super::get_row_counts(&tables, &reference_dictionary, 1.0);
+        assert!(super::check_key_spaces(
+            &tables,
+            &row_counts,
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_err());
+
+        // 5 profiles make 10 follows at most, (1, 2) and (2, 1) are the same pair
+        tables[0] = "5 profile (PK userID INTEGER)".to_string();
+        tables[1] =
+            "10 follows (PK a INTEGER profile(userID), PK b INTEGER profile(userID))".to_string();
+        reference_dictionary.insert("follows".to_string(), reference_dictionary["post"].clone());
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 10],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 11],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_err());
+    }
 
     #[test]
-    fn test_attribute_datatype() {
+    fn test_column_value_spaces() {
         /*
-        Test Valid and Invalid Data Types
-        Assert Valid Data Types Return True
-        Assert Invalid Data Types Return False
+        Estimate The Values Of Columns Narrowed By CHECK Constraints And DIST(...)
+        Assert Every Problem Of A Schema Is Reported Before Generating
         */
+        let mut options = super::ColumnOptions {
+            minimum: Some((1.0, true)),
+            maximum: Some((5.0, false)),
+            ..Default::default()
+        };
+        assert_eq!(
+            super::get_column_space("INTEGER", &options, super::Locale::En),
+            Some(4)
+        );
+        options.maximum = None;
+        assert_eq!(
+            super::get_column_space("INTEGER", &options, super::Locale::En),
+            Some(1 << 31)
+        );
+        options.allowed_values = vec!["'a'".to_string(), "'b'".to_string()];
+        assert_eq!(
+            super::get_column_space("VARCHAR(10)", &options, super::Locale::En),
+            Some(2)
+        );
 
-        let valid_datatype = vec!["INTEGER", "VARCHAR(30)", "PASSWORD(30)"];
-        let invalid_datatype = vec!["INVALID!", "VAFCHAR", "EMMAIL"];
-        for valid in valid_datatype {
-            assert_eq!(super::check_data_type(valid), true);
-        }
-        for invalid in invalid_datatype {
-            assert_eq!(super::check_data_type(invalid), false);
-        }
+        let options = super::ColumnOptions {
+            distribution: Some(super::parse_distribution("zipf, 10, 1.1")),
+            ..Default::default()
+        };
+        assert_eq!(
+            super::get_column_space("INTEGER", &options, super::Locale::En),
+            Some(10)
+        );
+        assert_eq!(
+            super::get_column_space("DECIMAL", &options, super::Locale::En),
+            None
+        );
+
+        let tables: Vec<String> = vec![
+            "10 t (PK id INTEGER, AK flag BOOLEAN, AK level INTEGER, CHECK (level BETWEEN 1 AND 5))".to_string(),
+        ];
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let problems =
+            super::check_key_spaces(&tables, &[10], &reference_dictionary, super::Locale::En)
+                .unwrap_err();
+        assert_eq!(problems.len(), 2);
+        assert!(problems[1].contains("level"));
+
+        // Types picked from the locale's lists and cities
+        assert_eq!(
+            super::get_value_space("STATE", None, super::Locale::En),
+            Some(50)
+        );
+        assert!(super::get_value_space("PREFIX", None, super::Locale::FrFr).is_some());
+        assert!(super::get_value_space("SUFFIX", None, super::Locale::En).is_some());
+        assert_eq!(
+            super::get_value_space("GEO_COUNTRY", None, super::Locale::En),
+            Some(1)
+        );
+        assert!(super::get_value_space("GEO_ZIP(billing)", None, super::Locale::En).is_some());
+        let tables: Vec<String> = vec!["300 s (PK id INTEGER, AK st STATE)".to_string()];
+        assert!(
+            super::check_key_spaces(&tables, &[300], &reference_dictionary, super::Locale::En)
+                .is_err()
+        );
+        assert!(
+            super::check_key_spaces(&tables, &[50], &reference_dictionary, super::Locale::En)
+                .is_ok()
+        );
+        let tables: Vec<String> = vec!["30 s (PK id INTEGER, AK title PREFIX)".to_string()];
+        assert!(
+            super::check_key_spaces(&tables, &[30], &reference_dictionary, super::Locale::En)
+                .is_err()
+        );
     }
 
     #[test]
-    fn test_key_definitions() {
+    fn test_key_retry_budget() {
         /*
-        Test Valid and Invalid Key Definitions
-        For Valid, Test All Possible Key Definitions
-        For Invalid, Test Spelling Errors and Incorrect Ordering
+        Generate More Key Rows Than Their Types Have Values, Skipping The Check Done Before Generating
+        Assert Generation Stops With An Error Naming The Attribute Instead Of Drawing Forever
         */
-        let valid_key_definitions = vec!["PK", "AK", "FK", "PK/FK", "AK/FK"];
-        let invalid_key_definitions = vec![
-            "KP", "KA", "KF", "PK/AK", "AK/PK", "FK/PK", "FK/AK", "PK/AK/FK",
-        ];
-        for valid in valid_key_definitions {
-            assert_eq!(super::check_key_definition(valid), true);
-        }
-        for invalid in invalid_key_definitions {
-            assert_eq!(super::check_key_definition(invalid), false);
+        for (table, key) in [
+            ("5 flags (AK flag BOOLEAN)", "flag"),
+            ("5 grid (PK cell COMPOUND (x BOOLEAN; y BOOLEAN))", "cell"),
+        ] {
+            let tables: Vec<String> = vec![table.to_string()];
+            let key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+            let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+                HashMap::new();
+            let mut sink = super::WriterSink::new(Vec::new());
+            let result = super::generate_mock_data(
+                &tables,
+                &key_dictionary,
+                &reference_dictionary,
+                &mut sink,
+                &[5],
+                &super::GenerationSettings::default(),
+            );
+            match result {
+                Err(super::GenerationError::Rows(reason)) => {
+                    assert!(reason.contains(key), "{}", reason)
+                }
+                other => panic!("Expected {} To Run Out Of Values, Got {:?}", key, other),
+            }
         }
     }
 
     #[test]
-    fn test_random_data_generation() {
+    fn test_unique_constraints() {
         /*
-        Test Data Generation With Types With Given Size
-        PASSWORD(20), USERNAME(10), MONEY(7), VARCHAR(30)
-        Assert Length of Return > 0 and <= Given Size
+        Read UNIQUE (...) Constraints Off The Attributes Of A Table
+        Assert Their Combined Values Must Fit The Rows And A Nullable Attribute Never Runs Out
         */
-        let statement_data: HashMap<String, String> = HashMap::new(); //Not Needed For This Test (Only Used For Email Generation)
+        assert_eq!(
+            super::get_table_unique("unique ( org_id,slug )"),
+            Some(vec!["org_id".to_string(), "slug".to_string()])
+        );
+        assert_eq!(super::get_table_unique("unique_code VARCHAR(8)"), None);
 
-        let password = super::get_random_data("PASSWORD(20)", Some(vec![20, 0]), &statement_data);
-        assert_eq!(password.len() > 0 && password.len() <= 20, true);
+        let mut tables: Vec<String> = vec![
+            "5 org (PK org_id INTEGER)".to_string(),
+            "20 project (PK id INTEGER, FK org_id INTEGER org(org_id), slug ONEOF(api, web, docs, app), UNIQUE (org_id, slug))".to_string(),
+        ];
+        let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+            HashMap::new();
+        reference_dictionary.insert(
+            "project".to_string(),
+            vec![HashMap::from([("org".to_string(), "org_id".to_string())])],
+        );
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 20],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 21],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_err());
+
+        tables[1] = tables[1].replace("app)", "app) NULL(0.1)");
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 21],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+
+        // The attributes of one composite reference only have the parent rows together
+        let tables: Vec<String> = vec![
+            "5 ol (PK order_id INTEGER, PK line_no INTEGER)".to_string(),
+            "5 s (PK id INTEGER, FK (oid, lno) ol(order_id, line_no), UNIQUE (oid, lno))"
+                .to_string(),
+        ];
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 5],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+        assert!(super::check_key_spaces(
+            &tables,
+            &[5, 6],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_err());
+    }
 
-        let username = super::get_random_data("USERNAME(10)", Some(vec![10, 0]), &statement_data);
-        assert_eq!(username.len() > 0 && username.len() <= 10, true);
+    #[test]
+    fn test_composite_references() {
+        /*
+        Read Foreign Keys Over Several Attributes And Write One Value For Each Of Them
+        Assert Unique Composite Keys Can Not Have More Rows Than Their Parent
+        */
+        let reference = super::get_composite_reference(
+            "ak/fk (order_id, line_no) order_line( order_id,line_no )",
+        )
+        .unwrap();
+        assert!(reference.is_unique());
+        assert_eq!(reference.columns, ["order_id", "line_no"]);
+        assert_eq!(reference.parent_key(), "order_line(order_id, line_no)");
+        assert!(
+            !super::get_composite_reference("FK (a, b) order_line(order_id, line_no)")
+                .unwrap()
+                .is_unique()
+        );
+        for attribute in [
+            "FK userID INTEGER profile(userID)",
+            "CHECK ((a) > ABS(b))",
+            "UNIQUE (a, b)",
+        ] {
+            assert_eq!(
+                super::get_composite_reference(attribute),
+                None,
+                "{}",
+                attribute
+            );
+        }
 
-        let money = super::get_random_data("MONEY(7)", Some(vec![7, 0]), &statement_data);
-        //Since money is returned as String and .{}{} (Used for cents) takes up 3 chars. The length of the return should be 7 + 3 = 10
-        assert_eq!(money.len() > 0 && money.len() <= 10, true);
+        let table_attributes: Vec<String> = vec![
+            "PK id INTEGER".to_string(),
+            "FK (order_id, line_no) order_line(order_id, line_no)".to_string(),
+        ];
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("id".to_string(), "1".to_string());
+        statement_data.insert("order_id".to_string(), "7".to_string());
+        statement_data.insert("line_no".to_string(), "2".to_string());
+        assert_eq!(
+            super::create_insert_statement(
+                "shipment",
+                Some(&super::get_column_names(&table_attributes)),
+                &[super::create_insert_values(
+                    &table_attributes,
+                    &statement_data
+                )],
+                super::Dialect::Standard,
+            ),
+            "INSERT INTO shipment (id, order_id, line_no) VALUES (1, 7, 2);"
+        );
 
-        let varchar = super::get_random_data("VARCHAR(30)", Some(vec![30, 0]), &statement_data);
-        assert_eq!(varchar.len() > 0 && varchar.len() <= 30, true);
+        let tables: Vec<String> = vec![
+            "3 order_line (PK order_id INTEGER, PK line_no INTEGER)".to_string(),
+            "4 shipment (PK/FK (order_id, line_no) order_line(order_id, line_no))".to_string(),
+        ];
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        assert!(super::check_key_spaces(
+            &tables,
+            &[3, 3],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_ok());
+        assert!(super::check_key_spaces(
+            &tables,
+            &[3, 4],
+            &reference_dictionary,
+            super::Locale::En
+        )
+        .is_err());
+
+        // The referenced attributes can be named in another case than the parent gave them
+        let tables: Vec<String> = vec![
+            "3 order_line (PK order_id INTEGER, PK line_no INTEGER)".to_string(),
+            "4 shipment (PK id INTEGER, FK (oid, lno) order_line(ORDER_ID, LINE_NO))".to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::from([
+            (
+                "order_line".to_string(),
+                vec![
+                    "PK ORDER_ID INTEGER".to_string(),
+                    "PK LINE_NO INTEGER".to_string(),
+                ],
+            ),
+            ("shipment".to_string(), vec!["PK ID INTEGER".to_string()]),
+        ]);
+        let settings = super::GenerationSettings {
+            positional: true,
+            ..Default::default()
+        };
+        let file = generate_to_string(
+            &tables,
+            &key_dictionary,
+            &reference_dictionary,
+            &[3, 4],
+            &settings,
+        );
+        let parent_rows: Vec<&str> = file
+            .lines()
+            .filter_map(|statement| statement.strip_prefix("INSERT INTO order_line VALUES ("))
+            .map(|values| values.trim_end_matches(");"))
+            .collect();
+        let child_rows: Vec<&str> = file
+            .lines()
+            .filter_map(|statement| statement.strip_prefix("INSERT INTO shipment VALUES ("))
+            .map(|values| values.trim_end_matches(");").split_once(", ").unwrap().1)
+            .collect();
+        assert_eq!(child_rows.len(), 4);
+        assert!(
+            child_rows.iter().all(|row| parent_rows.contains(row)),
+            "{}",
+            file
+        );
     }
 
     #[test]
-    fn test_email_generation_with_names_in_statement_data() {
+    fn test_null_probability_modifier() {
         /*
-        Test Email Generation With Names In Statement Data
-        Assert Email Contains Name
-        This Tests The 3 Given Key Possibilities (name, full name, full_name)
+        Parse NULL(p) Off Plain, Foreign Key And Compound Attributes
+        Assert The Probability Is Read, Invalid Probabilities Are Returned As An Error
+        And That A NULL Compound Is Written As A Single NULL
         */
-        let mut statement_data: HashMap<String, String> = HashMap::new();
-        statement_data.insert("name".to_string(), "Bob Johnson".to_string());
-        let email = super::get_random_data("EMAIL", None, &statement_data);
-        assert_eq!(email.contains("BobJohnson"), true);
+        let mut attribute_definition: Vec<String> = "FK userID INTEGER profile(userID) NULL(0.3)"
+            .split_whitespace()
+            .map(|s| s.to_owned())
+            .collect();
+        let options = super::extract_column_options(&mut attribute_definition);
+        assert_eq!(attribute_definition.len(), 4);
+        assert_eq!(options.null_probability, Some(Ok(0.3)));
+
+        let mut attribute_definition: Vec<String> = vec![
+            "phone".to_string(),
+            "PHONE".to_string(),
+            "NULL(abc)".to_string(),
+        ];
+        let options = super::extract_column_options(&mut attribute_definition);
+        assert!(options.null_probability.unwrap().is_err());
+        assert!(super::parse_null_probability("1.5").is_err());
+        assert_eq!(super::parse_null_probability(" 1 "), Ok(1.0));
 
-        //Reset HashMap and generate new name, then try again
-        statement_data = HashMap::new();
-        statement_data.insert("full name".to_string(), "John Smith".to_string());
-        let email = super::get_random_data("EMAIL", None, &statement_data);
-        assert_eq!(email.contains("JohnSmith"), true);
+        let table_attributes: Vec<String> = vec![
+            "PK id INTEGER".to_string(),
+            "loc COMPOUND (x INTEGER; y INTEGER) NULL(0.5)".to_string(),
+        ];
+        let mut statement_data: HashMap<String, String> = HashMap::new();
+        statement_data.insert("id".to_string(), "7".to_string());
+        statement_data.insert("loc".to_string(), "NULL".to_string());
+        assert_eq!(
+            super::create_insert_statement(
+                "point",
+                Some(&super::get_column_names(&table_attributes)),
+                &[super::create_insert_values(
+                    &table_attributes,
+                    &statement_data
+                )],
+                super::Dialect::Standard,
+            ),
+            "INSERT INTO point (id, loc) VALUES (7, NULL);"
+        );
 
-        //Reset HashMap and generate new name, then try again
-        statement_data = HashMap::new();
-        statement_data.insert("full_name".to_string(), "Jane Doe".to_string());
-        let email = super::get_random_data("EMAIL", None, &statement_data);
-        assert_eq!(email.contains("JaneDoe"), true);
+        // About p of the rows are NULL
+        let tables: Vec<String> = vec!["2000 t (PK id INTEGER, phone PHONE NULL(0.3))".to_string()];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::new();
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            seed: Some(7),
+            positional: true,
+            ..Default::default()
+        };
+        let file = generate_to_string(
+            &tables,
+            &key_dictionary,
+            &reference_dictionary,
+            &[2000],
+            &settings,
+        );
+        let nulls = file.lines().filter(|row| row.ends_with(", NULL);")).count();
+        assert!((500..700).contains(&nulls));
     }
 
     #[test]
@@ -2453,136 +8739,422 @@ mod tests {
     fn test_create_insert_statement() {
         /*
         Create vars for function and test returned insert statement
+        Assert The Columns Are Listed And Quoted Per Dialect, And Left Out When Positional
         */
         let target_insert_statement =
-            "INSERT INTO profile VALUES (1, 'Bob Johnson', 'BobJohnson@pitt.edu');";
+            "INSERT INTO profile (userID, name, email) VALUES (1, 'Bob Johnson', 'BobJohnson@pitt.edu');";
         let table_name = "profile";
         let table_attributes: Vec<String> = vec![
-            "userID".to_string(),
-            "name".to_string(),
-            "email".to_string(),
+            "PK userID INTEGER".to_string(),
+            "name NAME".to_string(),
+            "AK email EMAIL FROM(name)".to_string(),
         ];
         let mut statement_data: HashMap<String, String> = HashMap::new();
         statement_data.insert("userID".to_string(), "1".to_string());
         statement_data.insert("name".to_string(), "Bob Johnson".to_string());
         statement_data.insert("email".to_string(), "BobJohnson@pitt.edu".to_string());
+        let columns = super::get_column_names(&table_attributes);
+        let rows = [super::create_insert_values(&table_attributes, &statement_data)];
 
-        let generated_insert =
-            super::create_insert_statement(table_name, &table_attributes, &statement_data);
+        let generated_insert = super::create_insert_statement(table_name, Some(&columns), &rows, super::Dialect::Standard);
         assert_eq!(generated_insert, target_insert_statement);
+        assert_eq!(
+            super::create_insert_statement(table_name, None, &rows, super::Dialect::Standard),
+            "INSERT INTO profile VALUES (1, 'Bob Johnson', 'BobJohnson@pitt.edu');"
+        );
+        // Names that do not need quotes are written as they are in every dialect
+        for dialect in super::Dialect::ALL {
+            assert_eq!(super::create_insert_statement(table_name, Some(&columns), &rows, dialect), target_insert_statement);
+        }
+
+        // Only reserved words and names that are not letters, digits and '_' are quoted, each dialect its own way
+        for (identifier, quoted) in [
+            ("order", "\"order\""),
+            ("first name", "\"first name\""),
+            ("2fa", "\"2fa\""),
+            ("a\"b", "\"a\"\"b\""),
+            ("line_no", "line_no"),
+        ] {
+            assert_eq!(super::Dialect::Standard.quote(identifier), quoted);
+            assert_eq!(super::Dialect::Postgres.quote(identifier), quoted);
+        }
+        assert_eq!(super::Dialect::MySql.quote("order"), "`order`");
+        assert_eq!(super::Dialect::SqlServer.quote("first name"), "[first name]");
+
+        // SQL Server takes at most 1000 rows in one statement
+        let rows: Vec<String> = (0..2500).map(|row| format!("({})", row)).collect();
+        let statements = super::create_insert_statement("t", None, &rows, super::Dialect::SqlServer);
+        let statements: Vec<&str> = statements.lines().collect();
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].matches('(').count(), 1000);
+        assert!(statements[2].starts_with("INSERT INTO t VALUES (2000), ") && statements[2].ends_with("(2499);"));
+        assert_eq!(super::create_insert_statement("t", None, &rows, super::Dialect::MySql).lines().count(), 1);
+        assert_eq!(super::parse_dialect("PostgreSQL"), Some(super::Dialect::Postgres));
+        assert_eq!(super::parse_dialect("mariadb"), Some(super::Dialect::MySql));
+        assert_eq!(super::parse_dialect("oracle"), None);
+        assert_eq!(super::parse_columns("OFF"), Some(false));
     }
 
     #[test]
-    fn test_check_pair_with_unique_pair_passed() {
+    fn test_composite_key_with_unique_key_passed() {
         /*
-        Test check_pair with a unique pair
-        pair_changed should return false
-        Generate values for
-                generated_pair_vector: &Vec<String>,
-                previous_pairs: &Vec<Vec<String>>,
-                table_attributes: &Vec<String>,
-                uq_attributes: &HashMap<String, Vec<String>>,
-                count: usize,
+        Test CompositeKeyPicker with an unused key
+        The key should be given back as it is
         */
-        let generated_pair_vector: Vec<String> = vec!["1".to_string(), "Bob Johnson".to_string()];
-        let previous_pairs: Vec<Vec<String>> = vec![
-            vec!["2".to_string(), "John Smith".to_string()],
-            vec!["3".to_string(), "Jane Doe".to_string()],
-            vec!["4".to_string(), "Steven Even".to_string()],
-        ];
-        let table_attributes: Vec<String> = vec!["userID".to_string(), "name".to_string()];
-        let mut uq_attributes: HashMap<String, Vec<String>> = HashMap::new();
-        uq_attributes.insert(
-            "userID".to_string(),
-            vec![
-                "1".to_string(),
-                "2".to_string(),
-                "3".to_string(),
-                "4".to_string(),
-            ],
+        let mut picker =
+            super::CompositeKeyPicker::new(vec![None, None], super::PairRules::default());
+        picker.add(&["2".to_string(), "John Smith".to_string()]);
+        picker.add(&["3".to_string(), "Jane Doe".to_string()]);
+        let key: Vec<String> = vec!["1".to_string(), "Bob Johnson".to_string()];
+        assert_eq!(picker.pick(key.clone()), Some(key));
+    }
+
+    #[test]
+    fn test_composite_key_with_used_key_passed() {
+        /*
+        Test CompositeKeyPicker with keys that were used before, forwards and backwards
+        A new key should be drawn from the values of profile(userID) only
+        */
+        let profiles: Vec<String> = ["1", "2", "3"]
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+        let mut picker = super::CompositeKeyPicker::new(
+            vec![Some(profiles.clone()), Some(profiles.clone())],
+            super::PairRules::default(),
         );
-        uq_attributes.insert(
-            "name".to_string(),
-            vec![
-                "Bob Johnson".to_string(),
-                "John Smith".to_string(),
-                "Jane Doe".to_string(),
-                "Steven Even".to_string(),
-            ],
+        picker.add(&["1".to_string(), "2".to_string()]);
+
+        let key = picker.pick(vec!["2".to_string(), "1".to_string()]).unwrap();
+        assert!(key.contains(&"3".to_string()));
+        assert!(key.iter().all(|value| profiles.contains(value)));
+        assert_eq!(
+            picker
+                .pick(vec!["1".to_string(), "1".to_string()])
+                .map(|key| key.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_composite_key_enumerates_last_keys() {
+        /*
+        Test CompositeKeyPicker until every pair of 30 profiles is used
+        Every one of the 435 pairs should be given once, then None
+        */
+        let profiles: Vec<String> = (0..30).map(|value| value.to_string()).collect();
+        let mut picker = super::CompositeKeyPicker::new(
+            vec![Some(profiles.clone()), Some(profiles.clone())],
+            super::PairRules::default(),
+        );
+        let mut keys: HashSet<Vec<String>> = HashSet::new();
+        for _ in 0..435 {
+            let mut key = picker.pick(vec!["0".to_string(), "1".to_string()]).unwrap();
+            picker.add(&key);
+            key.sort();
+            assert!(keys.insert(key));
+        }
+        assert_eq!(picker.pick(vec!["0".to_string(), "1".to_string()]), None);
+    }
+
+    #[test]
+    fn test_pair_rules() {
+        /*
+        Parse PAIRS (...) And Use Every Key A Directed Table With Self Pairs Allows
+        Assert 4 Profiles Give 16 Keys And The Key Spaces Follow The Rules
+        */
+        assert_eq!(super::get_table_pairs("pairs (directed)"), Some("directed"));
+        assert_eq!(super::get_table_pairs("pairs_count INTEGER"), None);
+        assert_eq!(
+            super::parse_pair_rules("Directed,  no  self"),
+            Ok(super::PairRules {
+                directed: true,
+                self_pairs: false
+            })
+        );
+        assert!(super::parse_pair_rules("directed, symmetric").is_err());
+        assert!(super::parse_pair_rules("loops").is_err());
+
+        let profiles: Vec<String> = (0..4).map(|value| value.to_string()).collect();
+        let rules = super::PairRules {
+            directed: true,
+            self_pairs: true,
+        };
+        let mut picker = super::CompositeKeyPicker::new(
+            vec![Some(profiles.clone()), Some(profiles.clone())],
+            rules,
         );
-        let count: usize = 0;
-        let (pair_changed, _new_pair) = super::check_pair(
-            &generated_pair_vector,
-            &previous_pairs,
-            &table_attributes,
-            &uq_attributes,
-            count,
+        let mut keys: HashSet<Vec<String>> = HashSet::new();
+        for _ in 0..16 {
+            let key = picker.pick(vec!["0".to_string(), "0".to_string()]).unwrap();
+            picker.add(&key);
+            assert!(keys.insert(key));
+        }
+        assert_eq!(picker.pick(vec!["0".to_string(), "0".to_string()]), None);
+
+        let mut reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> =
+            HashMap::new();
+        reference_dictionary.insert(
+            "follows".to_string(),
+            vec![HashMap::from([(
+                "profile".to_string(),
+                "userID".to_string(),
+            )])],
         );
-        assert_eq!(pair_changed, false);
+        let keys = "PK/FK a INTEGER profile(userID), PK/FK b INTEGER profile(userID)";
+        for (rules, pairs) in [
+            ("symmetric", 6),
+            ("directed", 12),
+            ("self", 10),
+            ("directed, self", 16),
+        ] {
+            let tables: Vec<String> = vec![
+                "4 profile (PK userID INTEGER)".to_string(),
+                format!("{} follows ({}, PAIRS ({}))", pairs, keys, rules),
+            ];
+            assert!(super::check_key_spaces(
+                &tables,
+                &[4, pairs],
+                &reference_dictionary,
+                super::Locale::En
+            )
+            .is_ok());
+            assert!(super::check_key_spaces(
+                &tables,
+                &[4, pairs + 1],
+                &reference_dictionary,
+                super::Locale::En
+            )
+            .is_err());
+        }
     }
 
     #[test]
-    fn test_check_pair_with_prev_pair_passed() {
+    fn test_value_store() {
         /*
-        Test check_pair with a prev generated pair
-        pair_changed should return true
-        Then check_pair with new values should return false
-        Generate values for
-                generated_pair_vector: &Vec<String>,
-                previous_pairs: &Vec<Vec<String>>,
-                table_attributes: &Vec<String>,
-                uq_attributes: &HashMap<String, Vec<String>>,
-                count: usize,
+        Add Values To A Key Attribute's Store And Take Back The Ones Of A Failed Row
+        Assert Repeated Values Are Kept Once, The Order Stays For Picking Parents And Truncated Values Are Free Again
         */
-        let generated_pair_vector: Vec<String> = vec!["1".to_string(), "Bob Johnson".to_string()];
-        let previous_pairs: Vec<Vec<String>> = vec![
-            vec!["2".to_string(), "John Smith".to_string()],
-            vec!["1".to_string(), "Bob Johnson".to_string()],
-            vec!["4".to_string(), "Steven Even".to_string()],
+        let mut store = super::ValueStore::default();
+        for value in 0..100_000 {
+            store.push(&value.to_string());
+        }
+        store.push("7");
+        assert_eq!(store.len(), 100_000);
+        assert!(store.contains("99999"));
+        assert_eq!(store.get(42), "42");
+
+        store.truncate(10);
+        assert_eq!(store.len(), 10);
+        assert!(store.contains("9"));
+        assert!(!store.contains("10"));
+        store.push("10");
+        assert_eq!(store.get(store.len() - 1), "10");
+
+        let mut parents = vec!["1".to_string(), "2".to_string()];
+        let mut taken = vec![
+            super::take_unused_parent(&mut parents, "userID", "account").unwrap(),
+            super::take_unused_parent(&mut parents, "userID", "account").unwrap(),
         ];
-        let table_attributes: Vec<String> = vec!["userID".to_string(), "name".to_string()];
-        let mut uq_attributes: HashMap<String, Vec<String>> = HashMap::new();
+        taken.sort();
+        assert_eq!(taken, vec!["1", "2"]);
+        assert!(parents.is_empty());
+        assert!(super::take_unused_parent(&mut parents, "userID", "account").is_err());
+    }
 
-        //Add extra padding to both attributes to allow function to generate new pair. Run check pair twice
-        uq_attributes.insert(
-            "userID".to_string(),
-            vec![
-                "1".to_string(),
-                "2".to_string(),
-                "3".to_string(),
-                "4".to_string(),
-                "5".to_string(),
-                "6".to_string(),
-            ],
+    #[test]
+    fn test_statement_sink() {
+        /*
+        Write Statements Through A Buffered Sink Into Memory
+        Assert Nothing Is Lost Before finish And Every Statement Ends Up On Its Own Line
+        */
+        use super::StatementSink;
+        let mut buffer: Vec<u8> = Vec::new();
+        {
+            let mut sink = super::WriterSink::new(&mut buffer);
+            for row in 0..3 {
+                sink.write_statement(&format!("INSERT INTO profile VALUES ({});", row))
+                    .unwrap();
+            }
+            sink.finish().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "INSERT INTO profile VALUES (0);\nINSERT INTO profile VALUES (1);\nINSERT INTO profile VALUES (2);\n"
         );
-        uq_attributes.insert(
-            "name".to_string(),
+        assert!(super::open_file_sink("/nonexistent-directory/sample-data.sql").is_err());
+    }
+
+    #[test]
+    fn test_seeded_chunks() {
+        /*
+        Generate A Table Of Several Chunks With The Same Seed On One And On Three Threads
+        Assert Both Files Are The Same And The Keys Stay Unique Across Chunks
+        */
+        let tables: Vec<String> =
+            vec!["25000 profile (PK userID INTEGER, AK email VARCHAR(12))".to_string()];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::from([(
+            "profile".to_string(),
             vec![
-                "Bob Johnson".to_string(),
-                "John Smith".to_string(),
-                "Jane Doe".to_string(),
-                "Steven Even".to_string(),
-                "Zoe Tae".to_string(),
-                "Jenny Doe".to_string(),
+                "PK USERID INTEGER".to_string(),
+                "AK EMAIL VARCHAR(12)".to_string(),
             ],
+        )]);
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let mut files: Vec<String> = Vec::new();
+        for threads in [1, 3] {
+            let settings = super::GenerationSettings {
+                seed: Some(42),
+                threads: Some(threads),
+                ..Default::default()
+            };
+            files.push(generate_to_string(
+                &tables,
+                &key_dictionary,
+                &reference_dictionary,
+                &[25_000],
+                &settings,
+            ));
+        }
+        assert_eq!(files[0], files[1]);
+
+        let rows: Vec<&str> = files[0].lines().collect();
+        assert_eq!(rows.len(), 25_000);
+        let values: Vec<&str> = rows
+            .iter()
+            .map(|row| row.split(" VALUES (").nth(1).unwrap())
+            .collect();
+        let user_ids: HashSet<&str> = values
+            .iter()
+            .map(|row| row.split(", ").next().unwrap())
+            .collect();
+        let emails: HashSet<&str> = values
+            .iter()
+            .map(|row| row.split(", ").nth(1).unwrap())
+            .collect();
+        assert_eq!(user_ids.len(), 25_000);
+        assert_eq!(emails.len(), 25_000);
+    }
+
+    #[test]
+    fn test_seeded_whole_tables() {
+        /*
+        Generate Tables That Are Not Split Into Chunks (FANOUT, Composite Keys, Self References) On One And Four Threads
+        Assert The Same Seed Gives The Same File, Ages Included, Whatever The Number Of Threads
+        */
+        let tables: Vec<String> = vec![
+            "200 profile (PK userID INTEGER, born DATE, age AGE FROM(born))".to_string(),
+            "600 post (PK postID INTEGER, FK userID INTEGER profile(userID) FANOUT(zipf, 1.2))"
+                .to_string(),
+            "300 follows (PK/FK a INTEGER profile(userID), PK/FK b INTEGER profile(userID))"
+                .to_string(),
+            "500 emp (PK empID INTEGER, FK boss INTEGER emp(empID) NULL(0.1))".to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::from([
+            ("profile".to_string(), vec!["PK USERID INTEGER".to_string()]),
+            ("post".to_string(), vec!["PK POSTID INTEGER".to_string()]),
+            (
+                "follows".to_string(),
+                vec![
+                    "PK/FK a INTEGER profile(userID)".to_string(),
+                    "PK/FK b INTEGER profile(userID)".to_string(),
+                ],
+            ),
+            ("emp".to_string(), vec!["PK EMPID INTEGER".to_string()]),
+        ]);
+        let profile = HashMap::from([("profile".to_string(), "userID".to_string())]);
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::from([
+            ("post".to_string(), vec![profile.clone()]),
+            ("follows".to_string(), vec![profile.clone(), profile]),
+            (
+                "emp".to_string(),
+                vec![HashMap::from([("emp".to_string(), "empID".to_string())])],
+            ),
+        ]);
+        let mut files: Vec<String> = Vec::new();
+        for threads in [1, 4] {
+            let settings = super::GenerationSettings {
+                seed: Some(42),
+                threads: Some(threads),
+                positional: true,
+                ..Default::default()
+            };
+            files.push(generate_to_string(
+                &tables,
+                &key_dictionary,
+                &reference_dictionary,
+                &[200, 600, 300, 500],
+                &settings,
+            ));
+        }
+        assert_eq!(files[0], files[1]);
+        assert_eq!(files[0].lines().count(), 1600);
+
+        // A born date and its age agree, both are taken from the same day
+        let today = super::row_now().date();
+        for row in files[0]
+            .lines()
+            .filter_map(|row| row.strip_prefix("INSERT INTO profile VALUES ("))
+        {
+            let values: Vec<&str> = row.trim_end_matches(");").split(", ").collect();
+            let born = chrono::NaiveDate::parse_from_str(values[1].trim_matches('\''), "%Y-%m-%d")
+                .unwrap();
+            assert_eq!(values[2], today.years_since(born).unwrap_or(0).to_string());
+        }
+    }
+
+    #[test]
+    fn test_batched_inserts() {
+        /*
+        Write The Rows Of Two Tables Three At A Time
+        Assert Every Statement Holds A Full Batch Except The Last Of Each Table And No Row Is Lost
+        */
+        assert_eq!(super::parse_batch("500"), Some(500));
+        assert_eq!(super::parse_batch("0"), None);
+        assert_eq!(
+            super::create_insert_statement(
+                "profile",
+                None,
+                &["(1, 'a')".to_string(), "(2, 'b')".to_string()],
+                super::Dialect::Standard
+            ),
+            "INSERT INTO profile VALUES (1, 'a'), (2, 'b');"
         );
-        let count: usize = 0;
-        let (pair_changed, new_pair) = super::check_pair(
-            &generated_pair_vector,
-            &previous_pairs,
-            &table_attributes,
-            &uq_attributes,
-            count,
+
+        let tables: Vec<String> = vec![
+            "7 profile (PK userID INTEGER, name NAME)".to_string(),
+            "3 post (PK postID INTEGER)".to_string(),
+        ];
+        let key_dictionary: HashMap<String, Vec<String>> = HashMap::from([
+            ("profile".to_string(), vec!["PK USERID INTEGER".to_string()]),
+            ("post".to_string(), vec!["PK POSTID INTEGER".to_string()]),
+        ]);
+        let reference_dictionary: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+        let settings = super::GenerationSettings {
+            batch: Some(3),
+            ..Default::default()
+        };
+        let file = generate_to_string(
+            &tables,
+            &key_dictionary,
+            &reference_dictionary,
+            &[7, 3],
+            &settings,
         );
-        assert_eq!(pair_changed, true);
-        let (pair_changed, _new_pair) = super::check_pair(
-            &new_pair,
-            &previous_pairs,
-            &table_attributes,
-            &uq_attributes,
-            count,
+        let rows_per_statement: Vec<(&str, usize)> = file
+            .lines()
+            .map(|statement| {
+                (
+                    statement.split(' ').nth(2).unwrap(),
+                    statement.matches("), (").count() + 1,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows_per_statement,
+            vec![("profile", 3), ("profile", 3), ("profile", 1), ("post", 3)]
         );
-        assert_eq!(pair_changed, false);
+        assert!(file.lines().all(|statement| statement.ends_with(");")));
     }
 
     #[test]
@@ -2613,7 +9185,12 @@ mod tests {
                 vec![m, n],
                 "Size not equal to vec![15, 10]"
             );
-            let generated_value = super::get_random_data(data_type, size, &statement_data);
+            let generated_value = super::get_random_data(
+                data_type,
+                size,
+                super::Locale::En,
+                &statement_data,
+            );
             assert_eq!(
                 generated_value.parse::<f64>().is_ok(),
                 true,